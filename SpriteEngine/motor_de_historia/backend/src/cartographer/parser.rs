@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// Datos geográficos extraídos de un personaje
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,7 +51,7 @@ pub enum PointType {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CitySize { Metropolis, Large, Medium, Small, Hamlet }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum EmotionalTone {
     Home,       // Lugar de origen - cálido
     Fear,       // Trauma - marcas rojas
@@ -88,9 +88,9 @@ pub struct DangerZone {
 
 /// El parser que extrae geografía del trasfondo
 pub struct BackstoryParser {
-    biome_keywords: HashMap<String, Biome>,
-    point_keywords: HashMap<String, PointType>,
-    emotion_keywords: HashMap<String, EmotionalTone>,
+    biome_keywords: BTreeMap<String, Biome>,
+    point_keywords: BTreeMap<String, PointType>,
+    emotion_keywords: BTreeMap<String, EmotionalTone>,
 }
 
 impl BackstoryParser {
@@ -102,8 +102,8 @@ impl BackstoryParser {
         }
     }
 
-    fn init_biome_keywords() -> HashMap<String, Biome> {
-        let mut map = HashMap::new();
+    fn init_biome_keywords() -> BTreeMap<String, Biome> {
+        let mut map = BTreeMap::new();
         
         // Montañas
         for word in ["montaña", "monte", "pico", "cumbre", "cordillera", "sierra", "cima"] {
@@ -173,8 +173,8 @@ impl BackstoryParser {
         map
     }
 
-    fn init_point_keywords() -> HashMap<String, PointType> {
-        let mut map = HashMap::new();
+    fn init_point_keywords() -> BTreeMap<String, PointType> {
+        let mut map = BTreeMap::new();
         
         // Ciudades
         map.insert("ciudad".into(), PointType::City { size: CitySize::Medium });
@@ -212,8 +212,8 @@ impl BackstoryParser {
         map
     }
 
-    fn init_emotion_keywords() -> HashMap<String, EmotionalTone> {
-        let mut map = HashMap::new();
+    fn init_emotion_keywords() -> BTreeMap<String, EmotionalTone> {
+        let mut map = BTreeMap::new();
         
         // Home
         for word in ["nací", "hogar", "casa", "familia", "creció", "infancia", "madre", "padre"] {
@@ -251,7 +251,14 @@ impl BackstoryParser {
     /// Parsear trasfondo completo de un personaje
     pub fn parse_character(&self, character_json: &serde_json::Value) -> CharacterGeography {
         let name = character_json["identidad"]["nombre"].as_str().unwrap_or("Unknown").to_string(); // Ajustado para coincidir con la estructura real del JSON del usuario (identidad.nombre)
-        let id = character_json["id"].as_str().unwrap_or(&uuid::Uuid::new_v4().to_string()).to_string();
+        // Sin id, se deriva de la semilla del alma (o del nombre) para que el mapa sea reproducible
+        let id = match character_json["id"].as_str() {
+            Some(id) => id.to_string(),
+            None => {
+                let semilla = character_json["semilla"].as_u64().unwrap_or_else(|| crate::core::fnv1a(name.as_bytes()));
+                crate::core::uuid_desde_semilla(semilla).to_string()
+            }
+        };
         
         // Extraer texto del trasfondo/biografía
         let mut backstory = String::new();
@@ -371,7 +378,7 @@ impl BackstoryParser {
 
     fn detect_emotion(&self, text: &str) -> EmotionalTone {
         let text_lower = text.to_lowercase();
-        let mut emotion_counts: HashMap<EmotionalTone, u32> = HashMap::new();
+        let mut emotion_counts: BTreeMap<EmotionalTone, u32> = BTreeMap::new();
         
         for (keyword, emotion) in &self.emotion_keywords {
             if text_lower.contains(keyword) {
@@ -16,6 +16,9 @@ use crate::core::{
 };
 use crate::relaciones::Vinculo;
 
+/// Flujo de ChaCha de las partes narrativas, distinto del que genera las almas
+const FLUJO_NARRATIVA: u64 = 1;
+
 
 
 /// Una constelación de personajes interconectados
@@ -85,6 +88,7 @@ impl Constelacion {
     ) -> Self {
         // Usar RNG determinístico basado en la semilla de la constelación
         let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(semilla);
+        rng.set_stream(FLUJO_NARRATIVA);
     
         let tensiones = Self::detectar_tensiones(&almas, &vinculos);
         let triangulos = Self::detectar_triangulos(&almas, &vinculos);
@@ -123,7 +127,7 @@ impl Constelacion {
         let evento_ancla = Self::generar_evento_ancla(&mut rng, &mundo);
 
         Self {
-            id: Uuid::new_v4(),
+            id: crate::core::uuid_desde_semilla(semilla),
             semilla,
             nombre: Self::generar_nombre(&mut rng, &mundo),
             evento_ancla,
@@ -140,7 +144,7 @@ impl Constelacion {
     }
     
     fn generar_evento_ancla(rng: &mut impl Rng, mundo: &Mundo) -> EventoAncla {
-        let (nombres, fechas, descripciones, impactos) = match mundo {
+        let (nombres, fechas, descripciones, impactos) = match mundo.base() {
             Mundo::FantasiaMedieval | Mundo::FantasiaOscura => (
                 vec!["La Masacre del Valle Gris", "La Noche de los Tres Soles", "El Asedio de Bastión Roto", "La Traición del Príncipe Ciego", "El Cisma de la Rosa", "La Caída de la Casa Vane"],
                 vec!["Año 402", "Era de las Cenizas", "Ciclo 7", "El Invierno Rojo", "Hace una década"],
@@ -209,7 +213,7 @@ impl Constelacion {
         
         let formas = vec!["El Círculo", "La Orden", "Los Hijos", "La Hermandad", "El Pacto", "Los Errantes", "La Vanguardia", "El Fragmento", "La Sombra", "El Eco"];
         
-        let adjetivos = match mundo {
+        let adjetivos = match mundo.base() {
             Mundo::FantasiaMedieval | Mundo::FantasiaOscura => vec![
                 "del Crepúsculo", "de Hierro", "Silencioso", "Olvidado", "Eterno", "Roto", 
                 "de Ceniza", "de Sangre", "Nocturno", "Sagrado", "Maldito", "de Cristal",
@@ -228,7 +232,7 @@ impl Constelacion {
         
         // 30% de probabilidad de tener un sufijo extra
         let sufijo_extra = if rng.gen_bool(0.3) {
-            match mundo {
+            match mundo.base() {
                 Mundo::FantasiaMedieval => Some(*["del Norte", "de la Llama", "del Bosque Viejo", "de los Reyes Muertos"].choose(rng).unwrap()),
                 Mundo::SciFiCyberpunk => Some(*["v2.0", "Protocolo Sigma", "del Sector 7", "Null_Pointer"].choose(rng).unwrap()),
                 _ => None
@@ -431,9 +435,13 @@ impl ConstelacionBuilder {
         self
     }
     
+    /// Con `params.semilla` la constelación es función pura de la semilla;
+    /// sin ella, la semilla se toma de `rng` y queda en `Constelacion::semilla`
     pub fn construir(self, rng: &mut impl Rng) -> Constelacion {
         let params = self.params.unwrap_or_default();
         let config = ForgeConfig::default();
+        let semilla = params.semilla.unwrap_or_else(|| rng.gen());
+        let rng = &mut rand_chacha::ChaCha8Rng::seed_from_u64(semilla);
         
         // Generar almas según la estructura
         let mut almas = Vec::new();
@@ -459,6 +467,7 @@ impl ConstelacionBuilder {
                     rol,
                     tono_moral: Some(tono),
                     mundo: Some(params.mundo),
+                    idioma: params.idioma,
                     nombre_fijo: nombre_fijo.clone(),
                     genero: genero_fijo,
                     edad_fija,
@@ -493,19 +502,16 @@ impl ConstelacionBuilder {
              use crate::core::gramatica;
              for alma in &mut almas {
                  if alma.rol == Rol::Sombra || alma.rol == Rol::Villano {
-                     let motivacion = gramatica::generar_motivacion_antagonista(tipo_villano, &params.mundo);
+                     let motivacion = gramatica::generar_motivacion_antagonista(rng, tipo_villano, &params.mundo, &params.idioma.unwrap_or(crate::core::Language::Espanol));
                      alma.capas.mentira.la_mentira = format!("{} (Raíz: {})", motivacion, tipo_villano);
                  }
              }
         }
         
         // Calcular vínculos según densidad
-    let vinculos = Self::generar_vinculos(&almas, &params);
-    
-    // Semilla para la constelación (para poder regenerar sus partes narrativas)
-    let semilla_constelacion = rng.next_u64();
+    let vinculos = Self::generar_vinculos(rng, &almas, &params);
     
-    let mut constelacion = Constelacion::new(semilla_constelacion, params.mundo, almas, vinculos);
+    let mut constelacion = Constelacion::new(semilla, params.mundo, almas, vinculos);
 
     // Post-procesamiento: Romance Forzado
     if let Some(modo_romance) = &params.modo_romance {
@@ -588,12 +594,14 @@ impl ConstelacionBuilder {
     fn determinar_tono(rng: &mut impl Rng, index: usize, params: &ParametrosConstelacion) -> TonoMoral {
         if params.incluir_antagonista && index == params.cantidad - 1 {
             TonoMoral::Oscuro
+        } else if let Some(tono) = params.tono {
+            tono
         } else {
             *[TonoMoral::Claro, TonoMoral::Gris, TonoMoral::Claro].choose(rng).unwrap()
         }
     }
     
-    fn generar_vinculos(almas: &[Alma], params: &ParametrosConstelacion) -> Vec<Vinculo> {
+    fn generar_vinculos(rng: &mut impl Rng, almas: &[Alma], params: &ParametrosConstelacion) -> Vec<Vinculo> {
         let mut vinculos = Vec::new();
         
         let probabilidad = match params.densidad_relaciones {
@@ -605,7 +613,7 @@ impl ConstelacionBuilder {
         
         for i in 0..almas.len() {
             for j in (i+1)..almas.len() {
-                if rand::random::<f32>() < probabilidad {
+                if rng.gen::<f32>() < probabilidad {
                     vinculos.push(Vinculo::crear(&almas[i], &almas[j]));
                 }
             }
@@ -12,6 +12,9 @@ use super::{
 use super::identidad::Identidad;
 use super::arco::ArcoNarrativo;
 use super::biografia::{Biografia, MotorBiografia};
+use super::gramatica::minuscula_inicial;
+use super::narrativa::BancoNarrativo;
+use super::paquete_idioma::Seccion;
 use super::skills::{Skill, SoulTier, SkillForge};
 
 /// Un personaje completo con alma
@@ -70,7 +73,7 @@ impl Alma {
         let capas = SietCapas::generar(&mut rng, &mundo, &idioma);
         
         // Generar identidad
-        let mut identidad = Identidad::generar(&mut rng, &mundo, params.genero, params.edad_fija);
+        let mut identidad = Identidad::generar(&mut rng, &mundo, params.genero, params.edad_fija, &idioma);
         if let Some(fijo) = &params.nombre_fijo {
             if !fijo.trim().is_empty() {
                 identidad.nombre = fijo.clone();
@@ -92,42 +95,11 @@ impl Alma {
              });
              ficha_tecnica = Some(Self::generar_stats(&mut rng, raza));
              
-             // Update Identity with Race info if needed, e.g. append to description
-             identidad.rasgo_distintivo = format!("{} (Raza: {:?})", identidad.rasgo_distintivo, raza);
+             identidad.anotar_raza(raza, &idioma);
         }
-    
-    if idioma != Language::Espanol {
-        let lang_code = match idioma { Language::English => "en", Language::Japanese => "jp", _ => "es" };
-        let seed = rng.next_u64();
-        
-        let vest = identidad.vestimenta.clone();
-        identidad.vestimenta = super::adapter::adapt_text(&vest, lang_code, seed.wrapping_add(50));
-        let rasgo = identidad.rasgo_distintivo.clone();
-        identidad.rasgo_distintivo = super::adapter::adapt_text(&rasgo, lang_code, seed.wrapping_add(51));
-        let voz = identidad.voz.clone();
-        identidad.voz = super::adapter::adapt_text(&voz, lang_code, seed.wrapping_add(52));
-        let mani = identidad.manierismo.clone();
-        identidad.manierismo = super::adapter::adapt_text(&mani, lang_code, seed.wrapping_add(53));
-        
-        if let Some(t) = identidad.titulo.take() {
-             identidad.titulo = Some(super::adapter::adapt_text(&t, lang_code, seed.wrapping_add(54)));
-        }
-    }
         
         // Generar arco narrativo
-        let mut arco = ArcoNarrativo::generar(&mut rng, &rol, &tono, &capas.mentira);
-        
-        if idioma != Language::Espanol {
-            let lang_code = match idioma { Language::English => "en", Language::Japanese => "jp", _ => "es" };
-            let seed = rng.next_u64();
-            
-            let ei = arco.estado_inicial.clone();
-            arco.estado_inicial = super::adapter::adapt_text(&ei, lang_code, seed.wrapping_add(60));
-            let pq = arco.punto_de_quiebre.clone();
-            arco.punto_de_quiebre = super::adapter::adapt_text(&pq, lang_code, seed.wrapping_add(61));
-            let rp = arco.resolucion_positiva.clone();
-            arco.resolucion_positiva = super::adapter::adapt_text(&rp, lang_code, seed.wrapping_add(62));
-        }
+        let arco = ArcoNarrativo::generar(&mut rng, &rol, &tono, &capas.mentira, &idioma);
         
         // Generar hooks with world-specific content
         let ganchos = Self::generar_ganchos(&mut rng, &capas, &idioma, &mundo, &identidad.nombre);
@@ -162,34 +134,16 @@ impl Alma {
                            else if tier_roll < 70.0 { SoulTier::Murmullo }   // 25%
                            else { SoulTier::Eco };                           // 30%
                            
-            // 2. Determinar Clase
-            let clase = match rol {
-                Rol::Heroe | Rol::Guardian | Rol::Mercenario | Rol::Jugador => "Guerrero",
-                Rol::Villano | Rol::Sombra | Rol::Embaucador => "Pícaro",
-                Rol::Mentor | Rol::Profeta => "Mago",
-                Rol::Lider | Rol::Rebelde => "Paladín",
-                _ => "Aventurero"
-            }.to_string();
-            
-            // 3. Generar Habilidades
-            // Extraer un trauma de la herida para la Signature Skill
-            let trauma = format!("{} {}", capas.herida.causante, capas.herida.circunstancia);
-            
-            let skills = SkillForge::generate(
-                &mut rng, 
-                &clase, 
-                soul_tier, 
-                identidad.edad, 
-                Some(&trauma)
-            );
+            // 2-3. Clase y habilidades
+            let skills = Self::generar_habilidades(&mut rng, &rol, soul_tier, &capas, identidad.edad);
             
             (Some(soul_tier), skills)
         } else {
             (None, vec![])
         };
 
-        Self {
-            id: Uuid::new_v4(),
+        let mut alma = Self {
+            id: Uuid::nil(),
             semilla,
             rol,
             tono_moral: tono,
@@ -204,9 +158,27 @@ impl Alma {
             ficha_tecnica,
             soul_tier,
             skills,
-        }
+        };
+        alma.id = super::uuid_de_alma(&alma, &idioma);
+        alma
     }
 
+    pub(super) fn generar_habilidades(rng: &mut impl Rng, rol: &Rol, soul_tier: SoulTier, capas: &SietCapas, edad: u32) -> Vec<Skill> {
+        // Determinar Clase
+        let clase = match rol {
+            Rol::Heroe | Rol::Guardian | Rol::Mercenario | Rol::Jugador => "Guerrero",
+            Rol::Villano | Rol::Sombra | Rol::Embaucador => "Pícaro",
+            Rol::Mentor | Rol::Profeta => "Mago",
+            Rol::Lider | Rol::Rebelde => "Paladín",
+            _ => "Aventurero"
+        }.to_string();
+        
+        // Extraer un trauma de la herida para la Signature Skill
+        let trauma = format!("{} {}", capas.herida.causante, capas.herida.circunstancia);
+        
+        SkillForge::generate(rng, &clase, soul_tier, edad, Some(&trauma))
+    }
+    
     fn generar_stats(rng: &mut impl Rng, raza: super::Raza) -> super::DndStats {
         use super::Raza;
         
@@ -241,45 +213,26 @@ impl Alma {
         }
     }
     
-    fn generar_ganchos(rng: &mut impl Rng, capas: &SietCapas, lang: &Language, mundo: &super::Mundo, nombre: &str) -> Vec<String> {
+    pub(super) fn generar_ganchos(rng: &mut impl Rng, capas: &SietCapas, lang: &Language, mundo: &super::Mundo, nombre: &str) -> Vec<String> {
         // Get world-specific hooks
-        let mut ganchos = super::mundo_narrativo::ganchos_narrativos_mundo(rng, mundo, nombre);
+        let mut ganchos = super::mundo_narrativo::ganchos_narrativos_mundo(rng, mundo, nombre, lang);
         
         // Add some generic hooks based on character psychology
-        ganchos.push(format!("Alguien del pasado reaparece con noticias sobre {}", capas.herida.causante));
-        ganchos.push(format!("Se ve forzado a confrontar: {}", capas.mentira.catalizador_potencial));
-        
-        // Adaptar si es necesario
-        if *lang != Language::Espanol {
-             let lang_code = match lang { Language::English => "en", Language::Japanese => "jp", _ => "es" };
-             let seed = rng.next_u64();
-             ganchos = ganchos.iter().enumerate().map(|(i, g)| {
-                 super::adapter::adapt_text(g, lang_code, seed.wrapping_add(i as u64))
-             }).collect();
-        }
+        ganchos.push(BancoNarrativo::obtener("gancho_pasado", lang, &[&minuscula_inicial(&capas.herida.causante)], rng));
+        ganchos.push(BancoNarrativo::obtener("gancho_confrontacion", lang, &[&capas.mentira.catalizador_potencial], rng));
 
         ganchos.shuffle(rng);
         ganchos.truncate(3);
         ganchos
     }
     
-    fn generar_momentos(rng: &mut impl Rng, capas: &SietCapas, lang: &Language) -> Vec<String> {
+    pub(super) fn generar_momentos(rng: &mut impl Rng, capas: &SietCapas, lang: &Language) -> Vec<String> {
+        let paquete = lang.paquete();
         let mut momentos = vec![
-            format!("Cuando {}", capas.mascara.trigger_que_la_rompe.to_lowercase()),
-            format!("Cuando confronta que {}", capas.sombra.rasgo_negado.to_lowercase()),
-            "El silencio antes de la confesión más difícil".to_string(),
-            "Cuando elige ser vulnerable ante quien podría destruirlo".to_string(),
-            "El instante donde decide quién quiere ser".to_string(),
+            capas.mascara.trigger_que_la_rompe.clone(),
+            paquete.texto(Seccion::Gramatica, "momento_sombra", &[&minuscula_inicial(&capas.sombra.rasgo_negado)]),
         ];
-        
-        // Adaptar si es necesario
-        if *lang != Language::Espanol {
-             let lang_code = match lang { Language::English => "en", Language::Japanese => "jp", _ => "es" };
-             let seed = rng.next_u64();
-             momentos = momentos.iter().enumerate().map(|(i, m)| {
-                 super::adapter::adapt_text(m, lang_code, seed.wrapping_add(100 + i as u64))
-             }).collect();
-        }
+        momentos.extend(paquete.campos_fijos(Seccion::Gramatica, "momentos_definitorios", &[]));
 
         momentos.shuffle(rng);
         momentos.truncate(3);
@@ -403,4 +356,19 @@ mod tests {
         assert!(alma.soul_tier.is_some(), "El SoulTier debería generarse");
         assert!(!alma.skills.is_empty(), "Debería tener habilidades");
     }
+
+    #[test]
+    fn test_id_depende_de_semilla_y_parametros() {
+        let config = ForgeConfig::default();
+        let forjar = |mundo| Alma::generar(&mut StdRng::seed_from_u64(0), ParametrosGeneracion {
+            semilla: Some(7),
+            mundo,
+            ..Default::default()
+        }, &config);
+
+        assert_eq!(forjar(Some(Mundo::Noir)).id, forjar(Some(Mundo::Noir)).id);
+        assert_ne!(forjar(Some(Mundo::Noir)).id, forjar(Some(Mundo::Western)).id);
+        // Pedir el mundo por defecto es lo mismo que no pedirlo
+        assert_eq!(forjar(None).id, forjar(Some(config.mundo_default)).id);
+    }
 }
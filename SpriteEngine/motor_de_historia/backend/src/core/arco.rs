@@ -2,8 +2,10 @@
 
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
-use super::{Rol, TonoMoral};
+use super::{Language, Rol, TonoMoral};
 use super::capas::Mentira;
+use super::gramatica::minuscula_inicial;
+use super::paquete_idioma::Seccion;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArcoNarrativo {
@@ -26,7 +28,7 @@ pub enum TipoArco {
 }
 
 impl ArcoNarrativo {
-    pub fn generar(rng: &mut impl Rng, rol: &Rol, tono: &TonoMoral, mentira: &Mentira) -> Self {
+    pub fn generar(rng: &mut impl Rng, rol: &Rol, tono: &TonoMoral, mentira: &Mentira, lang: &Language) -> Self {
         let tipo = match (rol, tono) {
             (Rol::Heroe, TonoMoral::Luminoso | TonoMoral::Claro) => TipoArco::Ascenso,
             (Rol::Villano, TonoMoral::Oscuro | TonoMoral::Abismal) => TipoArco::Caida,
@@ -38,31 +40,16 @@ impl ArcoNarrativo {
             _ => TipoArco::Transformacion,
         };
         
-        let estado_inicial = match tipo {
-            TipoArco::Ascenso => "Perdido, incompleto, sin saber su potencial",
-            TipoArco::Caida => "En la cima, arrogante, ciego a sus debilidades",
-            TipoArco::Plano => "Formado, resistente, existe para forjar a otros",
-            TipoArco::Redencion => "Manchado, perseguido por su pasado",
-            TipoArco::Corrupcion => "Puro de corazón pero ingenuo",
-            TipoArco::Transformacion => "En conflicto, en el umbral entre dos mundos",
-        };
+        let paquete = lang.paquete();
+        let texto = |clave: &str, params: &[&str]| paquete.texto(Seccion::Gramatica, clave, params);
         
         Self {
             tipo,
-            estado_inicial: estado_inicial.to_string(),
-            punto_de_quiebre: format!(
-                "El momento donde {} y debe elegir", 
-                mentira.catalizador_potencial.to_lowercase()
-            ),
-            climax_potencial: "La prueba final que definirá quién decide ser".to_string(),
-            resolucion_positiva: format!(
-                "Aprende que {}. Finalmente en paz, aunque con cicatrices.",
-                mentira.verdad_necesaria.to_lowercase()
-            ),
-            resolucion_tragica: format!(
-                "Nunca supera su creencia de que '{}'. Se pierde a sí mismo.",
-                mentira.la_mentira.to_lowercase()
-            ),
+            estado_inicial: paquete.texto_de_variante(Seccion::Gramatica, "arco_estado_inicial", &format!("{:?}", tipo), &[]),
+            punto_de_quiebre: texto("arco_punto_de_quiebre", &[&minuscula_inicial(&mentira.catalizador_potencial)]),
+            climax_potencial: texto("arco_climax", &[]),
+            resolucion_positiva: texto("arco_resolucion_positiva", &[&minuscula_inicial(&mentira.verdad_necesaria)]),
+            resolucion_tragica: texto("arco_resolucion_tragica", &[&mentira.la_mentira]),
         }
     }
 }
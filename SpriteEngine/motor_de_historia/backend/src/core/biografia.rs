@@ -7,6 +7,9 @@ use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use super::capas::{SietCapas, TipoHerida};
+use super::narrativa::BancoNarrativo;
+use super::paquete_idioma::Seccion;
+use super::paquete_mundo;
 use super::{Mundo, Rol, TonoMoral, Language};
 
 // ============================================================
@@ -75,7 +78,7 @@ pub struct MomentoGracia {
     pub eco: String,     // Cómo resuena en el presente
 }
 
-fn generar_momentos_gracia(rng: &mut impl Rng, nombre: &str, herida: &TipoHerida) -> Vec<MomentoGracia> {
+fn generar_momentos_gracia(rng: &mut impl Rng, nombre: &str, herida: &TipoHerida, lang: &Language) -> Vec<MomentoGracia> {
     use super::gramatica::*;
     
     let mut momentos = Vec::new();
@@ -88,24 +91,18 @@ fn generar_momentos_gracia(rng: &mut impl Rng, nombre: &str, herida: &TipoHerida
         _ => "general",
     };
     
-    let (nom, desc, regalo, eco) = generar_momento_gracia(rng, nombre, categoria_principal);
+    let (nom, desc, regalo, eco) = generar_momento_gracia(rng, nombre, categoria_principal, lang);
     momentos.push(MomentoGracia { nombre: nom, descripcion: desc, regalo, eco });
     
     // Segundo momento de categoría diferente
     let categorias = ["conexion", "logro", "bondad", "general"];
     let segunda_cat = categorias.choose(rng).unwrap();
-    let (nom2, desc2, regalo2, eco2) = generar_momento_gracia(rng, nombre, segunda_cat);
+    let (nom2, desc2, regalo2, eco2) = generar_momento_gracia(rng, nombre, segunda_cat, lang);
     momentos.push(MomentoGracia { nombre: nom2, descripcion: desc2, regalo: regalo2, eco: eco2 });
     
     // Tercer momento: pequeña alegría convertida en momento
-    let alegria = generar_pequena_alegria(rng);
-    let esperanza = generar_esperanza(rng);
-    momentos.push(MomentoGracia {
-        nombre: "Un Destello de Luz".to_string(),
-        descripcion: format!("{} encuentra paz en {}", nombre, alegria),
-        regalo: "Un recordatorio de que hay belleza en lo simple".to_string(),
-        eco: format!("Lo impulsa a {}", esperanza),
-    });
+    let (nom3, desc3, regalo3, eco3) = generar_destello(rng, nombre, lang);
+    momentos.push(MomentoGracia { nombre: nom3, descripcion: desc3, regalo: regalo3, eco: eco3 });
     
     momentos
 }
@@ -123,125 +120,22 @@ pub struct ConflictoInterno {
     pub origen: String,
 }
 
-fn generar_conflictos(rng: &mut impl Rng, nombre: &str, capas: &SietCapas) -> Vec<ConflictoInterno> {
-    let mut pool = Vec::new();
-
-    // 1. Deber vs Deseo
-    let desc_deber = if rng.gen_bool(0.5) {
-        format!("{} vive en la tensión entre cumplir y querer. Cada día es una negociación silenciosa.", nombre)
-    } else {
-        format!("Para {}, el deber es un ancla pesada; el deseo, una marea que intenta arrastrarlo.", nombre)
-    };
-    pool.push(ConflictoInterno {
-        nombre: "Deber vs Deseo".into(),
-        polo_a: "lo que debe a otros".into(),
-        polo_b: "lo que anhela para sí".into(),
-        descripcion: desc_deber,
-        origen: "Aprendió temprano que sus deseos venían después".into(),
-    });
-
-    // 2. Pasado vs Futuro
-    let desc_pasado = if rng.gen_bool(0.5) {
-        format!("El pasado de {} tiene gravedad propia. Cada paso hacia adelante requiere soltar algo.", nombre)
-    } else {
-        format!("{} camina hacia adelante mirando hacia atrás. El ayer nunca termina de irse.", nombre)
-    };
-    pool.push(ConflictoInterno {
-        nombre: "Pasado vs Futuro".into(),
-        polo_a: "quien fue formado para ser".into(),
-        polo_b: "quien elige convertirse".into(),
-        descripcion: desc_pasado,
-        origen: "Las expectativas de otros aún resuenan".into(),
-    });
-
-    // 3. Confianza vs Protección
-    let desc_conf = if rng.gen_bool(0.5) {
-        format!("{} oscila entre la necesidad de conexión y el instinto visceral de protegerse.", nombre)
-    } else {
-        format!("Bajar la guardia es peligroso. Mantenerla arriba es solitario. {} conoce ambos dolores.", nombre)
-    };
-    pool.push(ConflictoInterno {
-        nombre: "Confianza vs Protección".into(),
-        polo_a: "abrirse a otros".into(),
-        polo_b: "mantener las murallas".into(),
-        descripcion: desc_conf,
-        origen: "La vulnerabilidad tuvo consecuencias antes".into(),
-    });
-
-    // 4. Máscara vs Yo
-    let desc_mask = if rng.gen_bool(0.5) {
-        format!("{} ha perfeccionado su máscara. Funciona tan bien que a veces olvida qué hay debajo.", nombre)
-    } else {
-        format!("Hay dos versiones de {}: la que todos ven y la que nadie conoce. La brecha entre ambas crece.", nombre)
-    };
-    pool.push(ConflictoInterno {
-        nombre: "Máscara vs Yo".into(),
-        polo_a: "la persona que muestra al mundo".into(),
-        polo_b: "quien realmente es".into(),
-        descripcion: desc_mask,
-        origen: "El yo real fue rechazado alguna vez".into(),
-    });
-
-    // 5. Seguridad vs Crecimiento (Variaciones para evitar 'jaula de oro' siempre)
-    let desc_seg = match rng.gen_range(0..3) {
-        0 => format!("{} conoce los límites de su zona segura. A veces la jaula es dorada, pero sigue siendo jaula.", nombre),
-        1 => format!("Crecer duele. Quedarse quieto asfixia. {} está decidiendo qué dolor prefiere soportar.", nombre),
-        _ => format!("El horizonte llama a {}, pero el suelo conocido sujeta sus pies con fuerza.", nombre),
-    };
-    pool.push(ConflictoInterno {
-        nombre: "Seguridad vs Crecimiento".into(),
-        polo_a: "la comodidad de lo conocido".into(),
-        polo_b: "el riesgo necesario para cambiar".into(),
-        descripcion: desc_seg,
-        origen: "El cambio trajo dolor antes".into(),
-    });
-
-    // 6. Aferrarse vs Soltar
-    let desc_soltar = if rng.gen_bool(0.5) {
-        format!("Hay cosas que {} no puede soltar aún. El agarre es doloroso, pero soltar parece peor.", nombre)
-    } else {
-        format!("{} lleva equipaje extra. Recuerdos, rencores, objetos. Soltarlos se siente como perderse.", nombre)
-    };
-    pool.push(ConflictoInterno {
-        nombre: "Aferrarse vs Soltar".into(),
-        polo_a: "lo que fue".into(),
-        polo_b: "lo que podría ser".into(),
-        descripcion: desc_soltar,
-        origen: "Perder algo importante enseñó a no soltar nada".into(),
-    });
-    
-    // 7. Justicia vs Piedad (NUEVO)
-    pool.push(ConflictoInterno {
-        nombre: "Justicia vs Piedad".into(),
-        polo_a: "el castigo merecido".into(),
-        polo_b: "la compasión humana".into(),
-        descripcion: format!("{} ve el mundo en blanco y negro, pero su corazón a veces ve matices que la ley ignora.", nombre),
-        origen: "Vio una injusticia quedar impune".into(),
-    });
-    
-    // 8. Libertad vs Pertenencia (NUEVO)
-    pool.push(ConflictoInterno {
-        nombre: "Libertad vs Pertenencia".into(),
-        polo_a: "ser libre sin ataduras".into(),
-        polo_b: "tener un lugar y gente".into(),
-        descripcion: format!("La soledad es el precio de la libertad de {}. ¿Vale la pena pagarlo?", nombre),
-        origen: "Pertenecer significó perderse a sí mismo".into(),
-    });
-
-    // Elegir 2 conflictos (ahora de un pool de 8 con textos variables)
-    let mut seleccionados: Vec<ConflictoInterno> = pool.choose_multiple(rng, 2).cloned().collect();
-
-    // Personalizar el origen según la herida
-    for conflicto in &mut seleccionados {
-        conflicto.origen = match &capas.herida.tipo {
-            TipoHerida::Abandono => format!("{} porque alguien importante se fue", conflicto.origen),
-            TipoHerida::Traicion => format!("{} cuando la confianza fue rota", conflicto.origen),
-            TipoHerida::Humillacion => format!("{} después de ser expuesto", conflicto.origen),
-            _ => conflicto.origen.clone(),
-        };
-    }
-
-    seleccionados
+fn generar_conflictos(rng: &mut impl Rng, nombre: &str, capas: &SietCapas, lang: &Language) -> Vec<ConflictoInterno> {
+    // Dos conflictos del banco del idioma; cada descripción tiene sus variantes
+    let paquete = lang.paquete();
+    let herida = format!("{:?}", capas.herida.tipo);
+    paquete
+        .varias(Seccion::Narrativa, "conflictos_internos", 2, &[nombre], rng)
+        .into_iter()
+        .map(|c| ConflictoInterno {
+            // El origen se personaliza según la herida
+            origen: paquete.texto_de_variante(Seccion::Narrativa, "conflicto_origen", &herida, &[&c[4]]),
+            nombre: c[0].clone(),
+            polo_a: c[1].clone(),
+            polo_b: c[2].clone(),
+            descripcion: c[3].clone(),
+        })
+        .collect()
 }
 
 // ============================================================
@@ -310,8 +204,8 @@ impl MotorBiografia {
         let tonalidad_climax = Self::seleccionar_tonalidad(rng, &balance, 3);
         let tonalidad_actual = Self::seleccionar_tonalidad(rng, &balance, 4);
 
-        let momentos_gracia = generar_momentos_gracia(rng, nombre, &capas.herida.tipo);
-        let conflictos = generar_conflictos(rng, nombre, capas);
+        let momentos_gracia = generar_momentos_gracia(rng, nombre, &capas.herida.tipo, lang);
+        let conflictos = generar_conflictos(rng, nombre, capas, lang);
         
         let txt_origen = Self::origen(rng, nombre, mundo, tonalidad_origen, lang);
         let txt_catalizador = Self::catalizador(rng, nombre, capas, Tonalidad::Sombrio, lang);
@@ -321,53 +215,54 @@ impl MotorBiografia {
 
         // Generar fases extra por edad
         let edad = edad_opt.unwrap_or(25);
+        let t = |key: &str| BancoNarrativo::texto(key, lang, &[]);
         let mut fases_extra = Vec::new();
         
         if edad >= 30 {
             let txt_madurez = Self::madurez(rng, nombre, edad, lang);
-            fases_extra.push(FragmentoBiografia { fase: FaseVida::Madurez, titulo: "V. La Madurez".into(), contenido: txt_madurez, tonalidad: Tonalidad::Balanceado });
+            fases_extra.push(FragmentoBiografia { fase: FaseVida::Madurez, titulo: t("titulo_madurez"), contenido: txt_madurez, tonalidad: Tonalidad::Balanceado });
         }
         if edad >= 50 {
             let txt_legado = Self::legado(rng, nombre, edad, lang);
-            fases_extra.push(FragmentoBiografia { fase: FaseVida::Legado, titulo: "VI. El Legado".into(), contenido: txt_legado, tonalidad: Tonalidad::Calido });
+            fases_extra.push(FragmentoBiografia { fase: FaseVida::Legado, titulo: t("titulo_legado"), contenido: txt_legado, tonalidad: Tonalidad::Calido });
         }
         if edad >= 70 {
             let txt_crepusculo = Self::crepusculo(rng, nombre, edad, lang);
-            fases_extra.push(FragmentoBiografia { fase: FaseVida::Crepusculo, titulo: "VII. El Crepúsculo".into(), contenido: txt_crepusculo, tonalidad: Tonalidad::Melancolico });
+            fases_extra.push(FragmentoBiografia { fase: FaseVida::Crepusculo, titulo: t("titulo_crepusculo"), contenido: txt_crepusculo, tonalidad: Tonalidad::Melancolico });
         }
 
         // 3. Ensamblar Fases
         let fases = match estilo {
             EstiloNarrativo::Cronista => {
                 let mut f = vec![
-                    FragmentoBiografia { fase: FaseVida::Origen, titulo: "I. El Origen".into(), contenido: txt_origen, tonalidad: tonalidad_origen },
-                    FragmentoBiografia { fase: FaseVida::Infancia, titulo: "II. Años Formativos".into(), contenido: txt_infancia, tonalidad: Tonalidad::Melancolico },
-                    FragmentoBiografia { fase: FaseVida::Catalizador, titulo: "III. El Quiebre".into(), contenido: txt_catalizador, tonalidad: Tonalidad::Sombrio },
-                    FragmentoBiografia { fase: FaseVida::Transformacion, titulo: "IV. La Metamorfosis".into(), contenido: txt_transformacion, tonalidad: tonalidad_climax },
+                    FragmentoBiografia { fase: FaseVida::Origen, titulo: t("titulo_origen"), contenido: txt_origen, tonalidad: tonalidad_origen },
+                    FragmentoBiografia { fase: FaseVida::Infancia, titulo: t("titulo_infancia"), contenido: txt_infancia, tonalidad: Tonalidad::Melancolico },
+                    FragmentoBiografia { fase: FaseVida::Catalizador, titulo: t("titulo_quiebre"), contenido: txt_catalizador, tonalidad: Tonalidad::Sombrio },
+                    FragmentoBiografia { fase: FaseVida::Transformacion, titulo: t("titulo_metamorfosis"), contenido: txt_transformacion, tonalidad: tonalidad_climax },
                 ];
                 // Insertar fases extra antes del presente
                 f.append(&mut fases_extra);
-                f.push(FragmentoBiografia { fase: FaseVida::Presente, titulo: "El Ahora".into(), contenido: txt_presente, tonalidad: tonalidad_actual });
+                f.push(FragmentoBiografia { fase: FaseVida::Presente, titulo: t("titulo_ahora"), contenido: txt_presente, tonalidad: tonalidad_actual });
                 f
             },
             EstiloNarrativo::InMediaRes => {
                 let mut f = vec![
-                    FragmentoBiografia { fase: FaseVida::Catalizador, titulo: "⚡ EL MOMENTO CERO".into(), contenido: format!("{}. Antes de eso, la vida de {} era otra historia.", txt_catalizador, nombre), tonalidad: Tonalidad::Sombrio },
-                    FragmentoBiografia { fase: FaseVida::Origen, titulo: "⏪ Flashback: El Origen".into(), contenido: format!("Para entender el dolor, hay que mirar al principio. {}", txt_origen), tonalidad: tonalidad_origen },
-                    FragmentoBiografia { fase: FaseVida::Transformacion, titulo: "▶️ La Secuela".into(), contenido: txt_transformacion, tonalidad: tonalidad_climax },
+                    FragmentoBiografia { fase: FaseVida::Catalizador, titulo: t("titulo_momento_cero"), contenido: BancoNarrativo::texto("marco_momento_cero", lang, &[&txt_catalizador, nombre]), tonalidad: Tonalidad::Sombrio },
+                    FragmentoBiografia { fase: FaseVida::Origen, titulo: t("titulo_flashback"), contenido: BancoNarrativo::texto("marco_flashback", lang, &[&txt_origen]), tonalidad: tonalidad_origen },
+                    FragmentoBiografia { fase: FaseVida::Transformacion, titulo: t("titulo_secuela"), contenido: txt_transformacion, tonalidad: tonalidad_climax },
                 ];
                 // En in media res añadimos solo si es muy viejo para dar contexto
                 if edad >= 50 {
                     f.append(&mut fases_extra);
                 }
-                f.push(FragmentoBiografia { fase: FaseVida::Presente, titulo: "El Presente".into(), contenido: txt_presente, tonalidad: tonalidad_actual });
+                f.push(FragmentoBiografia { fase: FaseVida::Presente, titulo: t("titulo_presente"), contenido: txt_presente, tonalidad: tonalidad_actual });
                 f
             },
             EstiloNarrativo::Psicologico => vec![
-                FragmentoBiografia { fase: FaseVida::Presente, titulo: "🧠 Estado Mental".into(), contenido: format!("{} vive atrapado en una premisa: '{}'.", nombre, capas.mentira.la_mentira), tonalidad: Tonalidad::Melancolico },
-                FragmentoBiografia { fase: FaseVida::Catalizador, titulo: "La Herida Primaria".into(), contenido: format!("La raíz no está en los hechos, sino en el impacto. {}", txt_catalizador), tonalidad: Tonalidad::Sombrio },
-                FragmentoBiografia { fase: FaseVida::Transformacion, titulo: "Mecanismos de Defensa".into(), contenido: format!("Para sobrevivir, {} construyó una armadura. {}", nombre, txt_transformacion), tonalidad: tonalidad_climax },
-                FragmentoBiografia { fase: FaseVida::Presente, titulo: "La Realidad Externa".into(), contenido: txt_presente, tonalidad: tonalidad_actual },
+                FragmentoBiografia { fase: FaseVida::Presente, titulo: t("titulo_estado_mental"), contenido: BancoNarrativo::texto("marco_estado_mental", lang, &[nombre, &capas.mentira.la_mentira]), tonalidad: Tonalidad::Melancolico },
+                FragmentoBiografia { fase: FaseVida::Catalizador, titulo: t("titulo_herida_primaria"), contenido: BancoNarrativo::texto("marco_herida_primaria", lang, &[&txt_catalizador]), tonalidad: Tonalidad::Sombrio },
+                FragmentoBiografia { fase: FaseVida::Transformacion, titulo: t("titulo_defensas"), contenido: BancoNarrativo::texto("marco_defensas", lang, &[nombre, &txt_transformacion]), tonalidad: tonalidad_climax },
+                FragmentoBiografia { fase: FaseVida::Presente, titulo: t("titulo_realidad"), contenido: txt_presente, tonalidad: tonalidad_actual },
             ],
         };
         
@@ -377,13 +272,13 @@ impl MotorBiografia {
             .collect();
             
         let estilo_str = match estilo {
-            EstiloNarrativo::Cronista => "Estilo: Crónica Lineal",
-            EstiloNarrativo::InMediaRes => "Estilo: In Media Res (Fracturado)",
-            EstiloNarrativo::Psicologico => "Estilo: Psicológico (Introspectivo)",
+            EstiloNarrativo::Cronista => t("estilo_cronista"),
+            EstiloNarrativo::InMediaRes => t("estilo_in_media_res"),
+            EstiloNarrativo::Psicologico => t("estilo_psicologico"),
         };
         
         if !conflictos.is_empty() {
-             let mut seccion_conflictos = String::from("\n**Conflictos Latentes:**\n");
+             let mut seccion_conflictos = format!("\n{}\n", t("conflictos_latentes"));
              for c in &conflictos {
                  seccion_conflictos.push_str(&format!("\n• *{}*: {}\n", c.nombre, c.descripcion));
              }
@@ -392,43 +287,10 @@ impl MotorBiografia {
 
         let texto_completo = format!("_{}_\n\n{}", estilo_str, partes.join("\n\n---\n\n"));
         
-        // 4. ADAPTACIÓN DE IDIOMA
-        let mut fases_finales = fases;
-        let mut momentos_finales = momentos_gracia;
-        let mut conflictos_finales = conflictos;
-        
-        if *lang != Language::Espanol {
-            let lang_code = match lang { Language::English => "en", Language::Japanese => "jp", _ => "es" };
-            let seed = rng.next_u64();
-            
-            // Adaptar Fases
-            for (i, fase) in fases_finales.iter_mut().enumerate() {
-                fase.titulo = super::adapter::adapt_text(&fase.titulo, lang_code, seed.wrapping_add(i as u64));
-                fase.contenido = super::adapter::adapt_text(&fase.contenido, lang_code, seed.wrapping_add(100 + i as u64));
-            }
-            
-            // Adaptar Momentos
-            for (i, m) in momentos_finales.iter_mut().enumerate() {
-                m.nombre = super::adapter::adapt_text(&m.nombre, lang_code, seed.wrapping_add(200 + i as u64));
-                m.descripcion = super::adapter::adapt_text(&m.descripcion, lang_code, seed.wrapping_add(300 + i as u64));
-                m.regalo = super::adapter::adapt_text(&m.regalo, lang_code, seed.wrapping_add(400 + i as u64));
-                m.eco = super::adapter::adapt_text(&m.eco, lang_code, seed.wrapping_add(500 + i as u64));
-            }
-
-            // Adaptar Conflictos
-            for (i, c) in conflictos_finales.iter_mut().enumerate() {
-                c.nombre = super::adapter::adapt_text(&c.nombre, lang_code, seed.wrapping_add(600 + i as u64));
-                c.polo_a = super::adapter::adapt_text(&c.polo_a, lang_code, seed.wrapping_add(700 + i as u64));
-                c.polo_b = super::adapter::adapt_text(&c.polo_b, lang_code, seed.wrapping_add(800 + i as u64));
-                c.descripcion = super::adapter::adapt_text(&c.descripcion, lang_code, seed.wrapping_add(900 + i as u64));
-                c.origen = super::adapter::adapt_text(&c.origen, lang_code, seed.wrapping_add(1000 + i as u64));
-            }
-        }
-
         Biografia {
-            fases: fases_finales,
-            momentos_gracia: momentos_finales,
-            conflictos: conflictos_finales,
+            fases,
+            momentos_gracia,
+            conflictos,
             texto_completo,
         }
     }
@@ -507,7 +369,7 @@ impl MotorBiografia {
     }
     
     fn origen(rng: &mut impl Rng, nombre: &str, mundo: &Mundo, tonalidad: Tonalidad, lang: &Language) -> String {
-        let lugares = Self::lugares_origen(mundo);
+        let lugares = Self::lugares_origen(mundo, lang);
         let lugar = lugares.choose(rng).unwrap();
         
         let sufijo = match tonalidad {
@@ -518,12 +380,11 @@ impl MotorBiografia {
             Tonalidad::Sombrio => "sombrio",
         };
         let key = format!("origen_{}", sufijo);
-        crate::core::narrativa::BancoNarrativo::obtener(&key, lang, &[nombre, lugar], rng)
+        BancoNarrativo::obtener(&key, lang, &[nombre, lugar], rng)
     }
     
     fn infancia(rng: &mut impl Rng, nombre: &str, _capas: &SietCapas, tonalidad: Tonalidad, _momentos_gracia: &[MomentoGracia], lang: &Language) -> String {
-        let refugios = vec!["los libros", "la música", "un refugio secreto", "la soledad", "la naturaleza"];
-        let refugio = refugios.choose(rng).unwrap();
+        let refugio = BancoNarrativo::obtener("refugios", lang, &[], rng);
         
         let sufijo = match tonalidad {
             Tonalidad::Radiante => "radiante",
@@ -533,13 +394,13 @@ impl MotorBiografia {
             Tonalidad::Sombrio => "sombrio",
         };
         let key = format!("infancia_{}", sufijo);
-        crate::core::narrativa::BancoNarrativo::obtener(&key, lang, &[nombre, refugio], rng)
+        BancoNarrativo::obtener(&key, lang, &[nombre, &refugio], rng)
     }
     
     fn catalizador(rng: &mut impl Rng, nombre: &str, capas: &SietCapas, _tonalidad: Tonalidad, lang: &Language) -> String {
         let evento = format!("{} {}", capas.herida.causante, capas.herida.circunstancia);
         let key = "catalizador_sombrio";
-        crate::core::narrativa::BancoNarrativo::obtener(key, lang, &[nombre, &evento], rng)
+        BancoNarrativo::obtener(key, lang, &[nombre, &evento], rng)
     }
     
     fn transformacion(rng: &mut impl Rng, nombre: &str, _capas: &SietCapas, _rol: &Rol, tonalidad: Tonalidad, _momentos_gracia: &[MomentoGracia], lang: &Language) -> String {
@@ -549,150 +410,30 @@ impl MotorBiografia {
              _ => "radiante", 
         };
         let key = format!("transformacion_{}", sufijo);
-        crate::core::narrativa::BancoNarrativo::obtener(&key, lang, &[nombre], rng)
+        BancoNarrativo::obtener(&key, lang, &[nombre], rng)
     }
     
     fn presente(rng: &mut impl Rng, nombre: &str, _capas: &SietCapas, _tonalidad: Tonalidad, lang: &Language) -> String {
         // Usamos una clave genérica del banco (reutilizando transformación por ahora para asegurar traducción)
-        crate::core::narrativa::BancoNarrativo::obtener("transformacion_radiante", lang, &[nombre], rng)
+        BancoNarrativo::obtener("transformacion_radiante", lang, &[nombre], rng)
     }
     
-    fn lugares_origen(mundo: &Mundo) -> Vec<&'static str> {
-        match mundo {
-            Mundo::FantasiaMedieval | Mundo::FantasiaOscura => vec![
-                "un pueblo olvidado por los mapas",
-                "las sombras de una fortaleza en ruinas",
-                "los márgenes de un reino indiferente",
-                "una aldea donde el tiempo parecía detenido",
-                "las tierras salvajes más allá de la frontera",
-                "un feudo donde la ley era capricho del señor",
-                "un monasterio aislado entre montañas",
-                "una ciudad portuaria llena de secretos",
-            ],
-            Mundo::SciFiCyberpunk => vec![
-                "los niveles inferiores de la megaciudad",
-                "un sector donde la luz del sol era un rumor",
-                "las zonas libres donde las corporaciones no llegaban",
-                "un complejo residencial donde miles vivían hacinados",
-                "los márgenes digitales de una sociedad hiperconectada",
-                "una estación orbital en declive",
-            ],
-            Mundo::SciFiSpace => vec![
-                "una estación orbital en los confines del sistema",
-                "una colonia minera donde nada crecía naturalmente",
-                "una nave generacional que nadie recordaba de dónde partió",
-                "un mundo terraformado a medias",
-                "los asteroides del cinturón exterior",
-            ],
-            // ═══ MUNDOS ASIÁTICOS ═══
-            Mundo::JaponFeudal => vec![
-                "una aldea de montaña donde los samurai rara vez pasaban",
-                "las tierras de un daimyō caído en desgracia",
-                "un templo sintoísta olvidado entre bambúes",
-                "los barrios bajos de una ciudad castillo",
-                "una isla pesquera donde llegaban rumores de guerra",
-                "un camino de la seda interior recorrido por ronin",
-                "las sombras del monte sagrado",
-                "un dojo abandonado que guardaba secretos antiguos",
-            ],
-            Mundo::ChinaImperial | Mundo::Wuxia => vec![
-                "una provincia remota donde el emperador era solo un nombre",
-                "los muelles del gran río, entre comerciantes y contrabandistas",
-                "un monasterio de artes marciales en las montañas de niebla",
-                "las calles de una ciudad amurallada llena de intriga",
-                "una villa de eruditos donde la poesía pesaba más que el oro",
-                "los bosques de bambú donde vivían los proscritos",
-                "una casa de té que escondía una secta secreta",
-                "las rutas de la seda, entre caravanas y bandidos",
-            ],
-            Mundo::CoreaHistorica => vec![
-                "una aldea de campesinos bajo la sombra de la nobleza yangban",
-                "los salones de una familia aristocrática en declive",
-                "un templo budista en las montañas del norte",
-                "las calles de la capital, donde intrigas y poesía se mezclaban",
-                "una costa azotada por piratas japoneses",
-                "un pueblo de artesanos de cerámica",
-            ],
-            Mundo::MitologiaAsiatica | Mundo::AnimeFantasia => vec![
-                "un mundo entre mundos, donde los espíritus caminaban",
-                "una academia de habilidades extraordinarias",
-                "un reino flotante sobre las nubes",
-                "las ruinas de una civilización olvidada",
-                "un santuario donde lo imposible era cotidiano",
-                "el cruce de caminos entre lo mortal y lo divino",
-                "una isla que aparecía solo en noches de luna llena",
-            ],
-            // ═══ OTROS MUNDOS ═══
-            Mundo::Victoriano => vec![
-                "los barrios industriales de una ciudad de humo y niebla",
-                "una mansión decadente en las afueras",
-                "los callejones donde la ley no llegaba",
-                "un orfanato donde se forjaban destinos duros",
-                "los salones de una sociedad obsesionada con las apariencias",
-                "un puerto donde llegaban secretos de las colonias",
-            ],
-            Mundo::MitologiaGriega => vec![
-                "una isla del mar Egeo bendecida por los dioses",
-                "las laderas del monte Olimpo, donde lo divino rozaba lo mortal",
-                "una polis donde la filosofía y la guerra eran gemelas",
-                "un templo consagrado a una deidad caprichosa",
-                "los caminos que conectaban el mundo conocido",
-            ],
-            Mundo::MitologiaNordica => vec![
-                "un fiordo donde el hielo nunca terminaba de derretirse",
-                "una aldea de vikingos que soñaban con gloria",
-                "las raíces del gran fresno que sostenía los mundos",
-                "un bosque donde los lobos hablaban con ancianos",
-                "las costas que veían partir a los drakkars",
-            ],
-            Mundo::PiratasCaribe => vec![
-                "una isla sin ley donde los proscritos eran reyes",
-                "un puerto donde cada barco traía historias y peligros",
-                "las bodegas de un galeón mercante",
-                "una plantación donde la justicia era un sueño lejano",
-                "las aguas turquesas que escondían naufragios y tesoros",
-            ],
-            Mundo::Western => vec![
-                "un pueblo fronterizo donde la ley era el más rápido",
-                "una granja solitaria en las praderas infinitas",
-                "las minas de plata donde los sueños morían",
-                "un campamento de colonos que avanzaba hacia lo desconocido",
-                "los territorios indios, donde dos mundos colisionaban",
-            ],
-            Mundo::Noir => vec![
-                "los callejones de una ciudad que nunca dormía",
-                "un barrio donde la policía era otro tipo de crimen",
-                "las oficinas de un edificio que había visto días mejores",
-                "un club nocturno donde se cerraban tratos oscuros",
-                "los muelles donde los secretos se hundían con los cuerpos",
-            ],
-            Mundo::Steampunk => vec![
-                "una ciudad de engranajes y humo perpetuo",
-                "los talleres de un inventor marginado",
-                "un dirigible que era ciudad flotante y prisión",
-                "las calles de bronce donde la aristocracia mecánica reinaba",
-                "los subterráneos donde vivían los olvidados de la revolución industrial",
-            ],
-            _ => vec![
-                "un lugar que ya no existe como era",
-                "circunstancias que nadie podría haber predicho",
-                "un hogar que guardaba secretos",
-                "una ciudad que nunca dormía",
-                "las afueras de un mundo en transición",
-            ],
-        }
+    /// Lugares de origen del mundo: los de su paquete de mundo si los trae, si
+    /// no los de la sección `mundo` del paquete de idioma
+    fn lugares_origen(mundo: &Mundo, lang: &Language) -> Vec<String> {
+        paquete_mundo::propia(mundo, |p| &p.lugares, lang.paquete().textos_de_mundo(Seccion::Mundo, "lugares", mundo))
     }
 
     fn madurez(rng: &mut impl Rng, nombre: &str, _edad: u32, lang: &Language) -> String {
-        crate::core::narrativa::BancoNarrativo::obtener("transformacion_radiante", lang, &[nombre], rng) // Fallback seguro
+        BancoNarrativo::obtener("transformacion_radiante", lang, &[nombre], rng) // Fallback seguro
     }
 
     fn legado(rng: &mut impl Rng, nombre: &str, _edad: u32, lang: &Language) -> String {
-        crate::core::narrativa::BancoNarrativo::obtener("transformacion_radiante", lang, &[nombre], rng) // Fallback seguro
+        BancoNarrativo::obtener("transformacion_radiante", lang, &[nombre], rng) // Fallback seguro
     }
     
     fn crepusculo(rng: &mut impl Rng, nombre: &str, _edad: u32, lang: &Language) -> String {
-        crate::core::narrativa::BancoNarrativo::obtener("transformacion_radiante", lang, &[nombre], rng) // Fallback seguro
+        BancoNarrativo::obtener("transformacion_radiante", lang, &[nombre], rng) // Fallback seguro
     }
 }
 
@@ -721,7 +462,7 @@ pub fn generar_historia_entrelazada(
     mundo: &Mundo,
 ) -> HistoriaEntrelazada {
     // Cómo se conocieron
-    let encuentros = match mundo {
+    let encuentros = match mundo.base() {
         Mundo::FantasiaMedieval | Mundo::FantasiaOscura => vec![
             format!("{} y {} se encontraron en el peor momento posible. O quizás el único momento posible. El destino tiene ese sentido del humor.", nombre_a, nombre_b),
             format!("{} y {} se cruzaron en una posada donde ambos buscaban escapar de algo. Ni uno ni otro esperaba encontrar a alguien.", nombre_a, nombre_b),
@@ -794,7 +535,7 @@ pub fn generar_historia_conjunta(
     }
     
     // 1. Contexto Temporal (El "Cuándo")
-    let contextos = match mundo {
+    let contextos = match mundo.base() {
         Mundo::FantasiaMedieval | Mundo::FantasiaOscura => vec![
             "En el invierno más largo que los ancianos recuerdan",
             "Justo después de la Caída del Último Bastión",
@@ -819,7 +560,7 @@ pub fn generar_historia_conjunta(
     };
     
     // 2. El Incidente (El "Qué los unió")
-    let incidentes = match mundo {
+    let incidentes = match mundo.base() {
         Mundo::FantasiaMedieval | Mundo::FantasiaOscura => vec![
             "un contrato que nadie más quiso aceptar los ató al mismo destino",
             "una tormenta antinatural los encerró en el mismo refugio olvidado",
@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 // CAPA 1: ARQUETIPO JUNGUIANO
 // ============================================================================
 
+use super::gramatica::minuscula_inicial;
+use super::paquete_idioma::Seccion;
 use super::Language;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,7 +57,7 @@ impl Arquetipo {
         }
     }
 
-    fn get_text(tipo: &TipoArquetipo, lang: &Language) -> (&'static str, &'static str, &'static str, &'static str) {
+    pub(super) fn get_text(tipo: &TipoArquetipo, lang: &Language) -> (&'static str, &'static str, &'static str, &'static str) {
         match lang {
             Language::English => match tipo {
                 TipoArquetipo::Guerrero => ("Courage to defend the weak", "Violence as first response", "Acting under extreme pressure", "Solving everything with confrontation"),
@@ -85,7 +87,7 @@ impl Arquetipo {
                 TipoArquetipo::Bufon => ("ユーモアで真実を暴く", "逃避と真剣さの欠如", "緊張を緩和する", "笑いの裏に痛みを隠す"),
                 TipoArquetipo::Inocente => ("信じる力を与える純粋さ", "現実逃避", "最悪の中に善を見る", "信じるべきでない時も信じる"),
             },
-            Language::Espanol => match tipo {
+            Language::Espanol | Language::Paquete(_) => match tipo {
                 TipoArquetipo::Guerrero => ("Coraje para defender a los débiles", "Violencia como primera respuesta", "Actuar bajo presión extrema", "Resolver todo con confrontación"),
                 TipoArquetipo::Cuidador => ("Compasión genuina y sacrificio", "Martirio y codependencia", "Intuir necesidades ajenas", "Descuidar las propias necesidades"),
                 TipoArquetipo::Huerfano => ("Empatía con los marginados", "Victimismo e incapacidad de confiar", "Sobrevivir donde otros se rinden", "Esperar siempre el abandono"),
@@ -154,25 +156,7 @@ impl Herida {
                       EdadHerida::Adolescencia, EdadHerida::AdultezTemprana];
         let edad = *edades.choose(rng).unwrap();
         
-        let (mut causante, mut circunstancia, mut cambio, mut gatillo, mut defensa) = Self::generar_detalles(rng, &tipo);
-        
-        // Adaptar textos si no es español
-        if *lang != Language::Espanol {
-            let lang_code = match lang {
-                Language::English => "en",
-                Language::Japanese => "jp",
-                _ => "es",
-            };
-            
-            // Usar una semilla derivada para determinismo
-            let seed = rng.next_u64();
-            
-            causante = super::adapter::adapt_text(&causante, lang_code, seed);
-            circunstancia = super::adapter::adapt_text(&circunstancia, lang_code, seed.wrapping_add(1));
-            cambio = super::adapter::adapt_text(&cambio, lang_code, seed.wrapping_add(2));
-            gatillo = super::adapter::adapt_text(&gatillo, lang_code, seed.wrapping_add(3));
-            defensa = super::adapter::adapt_text(&defensa, lang_code, seed.wrapping_add(4));
-        }
+        let (causante, circunstancia, cambio, gatillo, defensa) = Self::generar_detalles(rng, &tipo, lang);
 
         Self {
             tipo,
@@ -185,7 +169,7 @@ impl Herida {
         }
     }
     
-    fn generar_detalles(rng: &mut impl Rng, tipo: &TipoHerida) -> (String, String, String, String, String) {
+    fn generar_detalles(rng: &mut impl Rng, tipo: &TipoHerida, lang: &Language) -> (String, String, String, String, String) {
         use super::gramatica::*;
         
         let tipo_str = match tipo {
@@ -196,12 +180,12 @@ impl Herida {
             _ => "General",
         };
         
-        // Usar pools expandidos del módulo gramática
-        let causante = sujetos_causantes().choose(rng).unwrap().to_string();
-        let circunstancia = generar_circunstancia_herida(rng, tipo_str);
-        let cambio = cambios_internos().choose(rng).unwrap().to_string();
-        let gatillo = gatillos_emocionales().choose(rng).unwrap().to_string();
-        let defensa = mecanismos_defensa().choose(rng).unwrap().to_string();
+        // Usar pools expandidos del módulo gramática, ya en el idioma pedido
+        let causante = sujetos_causantes(lang).choose(rng).unwrap().to_string();
+        let circunstancia = generar_circunstancia_herida(rng, tipo_str, lang);
+        let cambio = cambios_internos(lang).choose(rng).unwrap().to_string();
+        let gatillo = gatillos_emocionales(lang).choose(rng).unwrap().to_string();
+        let defensa = mecanismos_defensa(lang).choose(rng).unwrap().to_string();
         
         (causante, circunstancia, cambio, gatillo, defensa)
     }
@@ -224,49 +208,21 @@ pub struct Mascara {
 }
 
 impl Mascara {
-    pub fn generar(rng: &mut impl Rng, herida: &Herida) -> Self {
-        let mascaras = vec![
-            ("Confiado y carismático", "Alguien que tiene todo bajo control", 
-             "No hay problema sin solución", "Soledad profunda",
-             "Que descubran que no sabe lo que hace", "Ser amado sin condiciones"),
-            ("Frío y calculador", "Alguien que no necesita a nadie",
-             "Las emociones son debilidad", "Vergüenza constante",
-             "Que no es suficiente", "Poder descansar de probar su valía"),
-            ("Alegre y despreocupado", "Alguien a quien nada afecta",
-             "¿Por qué preocuparse?", "Rabia contenida",
-             "Que si la libera destruirá todo", "Que alguien entienda su dolor"),
-            ("Servicial y abnegado", "Alguien indispensable",
-             "Tus problemas son más importantes", "Vacío existencial",
-             "Que nada de lo que hace importa", "Encontrar significado"),
-            ("Cínico y sarcástico", "Alguien demasiado listo para esperanzas",
-             "Ya lo veía venir", "Terror constante",
-             "Que todo será arrebatado de nuevo", "Poder amar sin miedo"),
-        ];
-        
-        let m = mascaras.choose(rng).unwrap();
+    pub fn generar(rng: &mut impl Rng, herida: &Herida, lang: &Language) -> Self {
+        let m = campos(lang, "capa_mascara", rng);
         
         Self {
-            comportamiento_publico: m.0.to_string(),
-            imagen_proyectada: m.1.to_string(),
-            frase_tipica: m.2.to_string(),
-            sentimiento_oculto: m.3.to_string(),
-            miedo_central: m.4.to_string(),
-            deseo_secreto: m.5.to_string(),
-            trigger_que_la_rompe: format!("Cuando {}", herida.gatillo_emocional.to_lowercase()),
-            costo_de_mantenerla: Self::generar_costo(rng),
+            comportamiento_publico: m[0].clone(),
+            imagen_proyectada: m[1].clone(),
+            frase_tipica: m[2].clone(),
+            sentimiento_oculto: m[3].clone(),
+            miedo_central: m[4].clone(),
+            deseo_secreto: m[5].clone(),
+            // El gatillo de la herida ya es la circunstancia ("Cuando...")
+            trigger_que_la_rompe: herida.gatillo_emocional.clone(),
+            costo_de_mantenerla: lang.paquete().frase(Seccion::Gramatica, "capa_mascara_costo", &[], rng),
         }
     }
-    
-    fn generar_costo(rng: &mut impl Rng) -> String {
-        let costos = [
-            "Insomnio y pesadillas",
-            "Estallidos de ira inexplicables",
-            "Episodios de desconexión",
-            "Relaciones que se deterioran",
-            "Agotamiento emocional constante",
-        ];
-        costos.choose(rng).unwrap().to_string()
-    }
 }
 
 // ============================================================================
@@ -285,40 +241,20 @@ pub struct DeseoNecesidad {
 }
 
 impl DeseoNecesidad {
-    pub fn generar(rng: &mut impl Rng, _herida: &Herida) -> Self {
-        let combos = vec![
-            ("Poder absoluto", "Nunca más será vulnerable", "Acumular recursos y aliados",
-             "Perdonarse a sí mismo", "Cree que no merece perdón"),
-            ("Venganza", "Cree que le dará paz", "Dedicar cada recurso a ese fin",
-             "Dejar ir el pasado", "Cree que olvidar es traicionar"),
-            ("Reconocimiento", "Probar que vale algo", "Hazañas cada vez más peligrosas",
-             "Aceptar que ya es suficiente", "Ha construido su identidad en probarlo"),
-            ("Proteger a alguien", "No repetir el fracaso pasado", "Control obsesivo del entorno",
-             "Aceptar que no puede controlarlo todo", "Lo asocia con debilidad"),
-            ("Un lugar donde pertenecer", "Llenar el vacío", "Moldearse a lo que otros quieren",
-             "Conexión genuina", "Lo ve como peligroso"),
-        ];
-        
-        let c = combos.choose(rng).unwrap();
-        
-        let conflicto = format!(
-            "Persigue {} creyendo que le dará paz, pero necesita {}.",
-            c.0.to_lowercase(), c.3.to_lowercase()
-        );
-        
-        let ironia = format!(
-            "Si obtuviera {} sin trabajar en {}, seguiría vacío.",
-            c.0.to_lowercase(), c.3.to_lowercase()
-        );
+    pub fn generar(rng: &mut impl Rng, _herida: &Herida, lang: &Language) -> Self {
+        let c = campos(lang, "capa_deseo", rng);
+        let deseo = minuscula_inicial(&c[0]);
+        let necesidad = minuscula_inicial(&c[3]);
+        let paquete = lang.paquete();
         
         Self {
-            deseo_consciente: c.0.to_string(),
-            motivacion_del_deseo: c.1.to_string(),
-            estrategia: c.2.to_string(),
-            necesidad_real: c.3.to_string(),
-            por_que_no_la_ve: c.4.to_string(),
-            conflicto,
-            ironia,
+            deseo_consciente: c[0].clone(),
+            motivacion_del_deseo: c[1].clone(),
+            estrategia: c[2].clone(),
+            necesidad_real: c[3].clone(),
+            por_que_no_la_ve: c[4].clone(),
+            conflicto: paquete.texto(Seccion::Gramatica, "capa_deseo_conflicto", &[&deseo, &necesidad]),
+            ironia: paquete.texto(Seccion::Gramatica, "capa_deseo_ironia", &[&deseo, &necesidad]),
         }
     }
 }
@@ -337,53 +273,16 @@ pub struct Sombra {
 }
 
 impl Sombra {
-    pub fn generar(rng: &mut impl Rng) -> Self {
-        let sombras = vec![
-            (
-                "La crueldad que es capaz de ejercer",
-                vec!["Frialdad bajo presión", "Fantasías violentas", "Placer oculto ante el sufrimiento enemigo"],
-                "Cuando alguien que ama es amenazado",
-                "Hacer lo necesario sin romperse",
-                "Convertirse en lo que jura combatir"
-            ),
-            (
-                "El egoísmo tras el altruismo",
-                vec!["Resentimiento cuando no aprecian su ayuda", "Llevar cuenta de favores", "Hacer sentir culpables"],
-                "Cuando su sacrificio es ignorado",
-                "Ayudar sin expectativas",
-                "Manipulación disfrazada de bondad"
-            ),
-            (
-                "El miedo que lo paraliza",
-                vec!["Agresividad cuando se siente acorralado", "Evitar situaciones incontrolables", "Despreciar a los miedosos"],
-                "Situaciones de impotencia pasada",
-                "Valor que reconoce el miedo pero actúa",
-                "Parálisis o reacciones desproporcionadas"
-            ),
-            (
-                "La envidia que corroe",
-                vec!["Críticas sutiles a quienes tienen lo que quiere", "Satisfacción ante fracasos ajenos"],
-                "El éxito de otros donde ha fallado",
-                "Ambición sana sin destruir",
-                "Sabotaje para sentirse mejor"
-            ),
-            (
-                "La necesidad de control",
-                vec!["Manipulación sutil", "Ansiedad ante lo impredecible", "Incapacidad de delegar"],
-                "Cualquier situación impredecible",
-                "Liderazgo que empodera",
-                "Tiranía disfrazada de responsabilidad"
-            ),
-        ];
-        
-        let s = sombras.choose(rng).unwrap();
+    pub fn generar(rng: &mut impl Rng, lang: &Language) -> Self {
+        // Rasgo, tres filtraciones, despertar, potencial y peligro
+        let s = campos(lang, "capa_sombra", rng);
         
         Self {
-            rasgo_negado: s.0.to_string(),
-            como_se_filtra: s.1.iter().map(|x| x.to_string()).collect(),
-            que_la_despierta: s.2.to_string(),
-            potencial_integrado: s.3.to_string(),
-            peligro_si_domina: s.4.to_string(),
+            rasgo_negado: s[0].clone(),
+            como_se_filtra: s[1..4].to_vec(),
+            que_la_despierta: s[4].clone(),
+            potencial_integrado: s[5].clone(),
+            peligro_si_domina: s[6].clone(),
         }
     }
 }
@@ -422,7 +321,7 @@ pub enum EstiloApego {
 }
 
 impl CapaVinculos {
-    pub fn generar(rng: &mut impl Rng, herida: &Herida) -> Self {
+    pub fn generar(rng: &mut impl Rng, herida: &Herida, lang: &Language) -> Self {
         let patron = match herida.tipo {
             TipoHerida::Abandono => *[PatronVincular::DistanciaPorDefecto, PatronVincular::PruebasConstantes].choose(rng).unwrap(),
             TipoHerida::Traicion => PatronVincular::PruebasConstantes,
@@ -437,58 +336,21 @@ impl CapaVinculos {
             _ => *[EstiloApego::Ansioso, EstiloApego::Evitativo].choose(rng).unwrap(),
         };
         
+        // Cada texto depende del patrón o de la herida; el paquete puede
+        // dejar un valor común para las variantes que no distingue
+        let paquete = lang.paquete();
+        let patron_clave = format!("{:?}", patron);
+        let texto = |clave: &str, variante: &str| paquete.texto_de_variante(Seccion::Gramatica, clave, variante, &[]);
+        
         Self {
             patron,
             estilo_apego: estilo,
-            rol_en_grupos: Self::generar_rol(&patron),
-            como_expresa_afecto: Self::generar_expresion(&patron),
-            que_busca_en_otros: Self::generar_busqueda(rng, herida),
-            que_ofrece: Self::generar_oferta(&patron),
+            rol_en_grupos: texto("vinculos_rol", &patron_clave),
+            como_expresa_afecto: texto("vinculos_expresion", &patron_clave),
+            que_busca_en_otros: texto("vinculos_busqueda", &format!("{:?}", herida.tipo)),
+            que_ofrece: texto("vinculos_oferta", &patron_clave),
         }
     }
-    
-    fn generar_rol(patron: &PatronVincular) -> String {
-        match patron {
-            PatronVincular::CuidadorCompulsivo => "El que mantiene al grupo unido",
-            PatronVincular::IndependenciaFeroz => "El lobo solitario que ayuda a distancia",
-            PatronVincular::IntensidadInicial => "El corazón apasionado",
-            PatronVincular::DistanciaPorDefecto => "El observador que interviene cuando importa",
-            PatronVincular::PruebasConstantes => "El escéptico eterno",
-            PatronVincular::DarParaRecibir => "El negociador",
-            PatronVincular::SacrificioTotal => "El mártir",
-        }.to_string()
-    }
-    
-    fn generar_expresion(patron: &PatronVincular) -> String {
-        match patron {
-            PatronVincular::CuidadorCompulsivo => "Actos de servicio abrumadores",
-            PatronVincular::DistanciaPorDefecto => "Gestos pequeños que pasan desapercibidos",
-            PatronVincular::IntensidadInicial => "Declaraciones apasionadas",
-            _ => "Acciones más que palabras",
-        }.to_string()
-    }
-    
-    fn generar_busqueda(_rng: &mut impl Rng, herida: &Herida) -> String {
-        match herida.tipo {
-            TipoHerida::Abandono => "Alguien que nunca se irá",
-            TipoHerida::Traicion => "Lealtad incuestionable",
-            TipoHerida::Humillacion => "Respeto incondicional",
-            TipoHerida::Rechazo => "Aceptación total",
-            _ => "Alguien que entienda sin juzgar",
-        }.to_string()
-    }
-    
-    fn generar_oferta(patron: &PatronVincular) -> String {
-        match patron {
-            PatronVincular::CuidadorCompulsivo => "Cuidado incondicional",
-            PatronVincular::IndependenciaFeroz => "Ayuda competente sin ataduras",
-            PatronVincular::IntensidadInicial => "Pasión y devoción total",
-            PatronVincular::DistanciaPorDefecto => "Lealtad probada con el tiempo",
-            PatronVincular::PruebasConstantes => "Verdad brutal pero honesta",
-            PatronVincular::DarParaRecibir => "Intercambio justo",
-            PatronVincular::SacrificioTotal => "Todo lo que tiene",
-        }.to_string()
-    }
 }
 
 // ============================================================================
@@ -507,69 +369,26 @@ pub struct Mentira {
 }
 
 impl Mentira {
-    pub fn generar(rng: &mut impl Rng, _herida: &Herida) -> Self {
-        let mentiras = vec![
-            (
-                "Estoy solo porque todos me traicionarán",
-                "Una traición que generalizó al mundo",
-                "Ve amenazas donde hay indiferencia",
-                "Nunca confía, siempre tiene plan de escape",
-                "La vulnerabilidad es el precio de la conexión",
-                "Alguien lo elija sobre su propio beneficio",
-                "Arriesgar ser herido de nuevo"
-            ),
-            (
-                "Si no soy fuerte, no valgo nada",
-                "Su debilidad tuvo consecuencias terribles",
-                "Ve cualquier limitación como fracaso moral",
-                "Nunca pide ayuda",
-                "La fortaleza incluye aceptar límites",
-                "Fallar y aun así ser aceptado",
-                "Enfrentar la vergüenza evitada"
-            ),
-            (
-                "No merezco ser feliz",
-                "Culpa por un error magnificado",
-                "Ve la felicidad propia como algo robado",
-                "Sabotea cualquier posibilidad de felicidad",
-                "El perdón es posible",
-                "Perdonar a alguien que le hizo algo similar",
-                "Soltar la identidad construida sobre culpa"
-            ),
-            (
-                "El mundo es injusto, debo serlo también",
-                "Injusticias que sufrió o presenció",
-                "Ve la bondad como ingenuidad",
-                "Justifica acciones cuestionables",
-                "Puede elegir ser diferente al mundo que lo hirió",
-                "Ver el impacto de sus acciones en un inocente",
-                "Admitir que ha causado daño"
-            ),
-            (
-                "Si sacrifico todo, seré suficiente",
-                "Nunca fue suficiente para alguien importante",
-                "Confunde su valor con su utilidad",
-                "Se destruye probando su valor",
-                "Ya es suficiente sin probarlo",
-                "Ser amado sin hacer nada extraordinario",
-                "Enfrentar el vacío de no saber quién es sin servir"
-            ),
-        ];
-        
-        let m = mentiras.choose(rng).unwrap();
+    pub fn generar(rng: &mut impl Rng, _herida: &Herida, lang: &Language) -> Self {
+        let m = campos(lang, "capa_mentira", rng);
         
         Self {
-            la_mentira: m.0.to_string(),
-            como_nacio: m.1.to_string(),
-            como_distorsiona: m.2.to_string(),
-            decisiones_que_causa: m.3.to_string(),
-            verdad_necesaria: m.4.to_string(),
-            catalizador_potencial: m.5.to_string(),
-            costo_de_la_verdad: m.6.to_string(),
+            la_mentira: m[0].clone(),
+            como_nacio: m[1].clone(),
+            como_distorsiona: m[2].clone(),
+            decisiones_que_causa: m[3].clone(),
+            verdad_necesaria: m[4].clone(),
+            catalizador_potencial: m[5].clone(),
+            costo_de_la_verdad: m[6].clone(),
         }
     }
 }
 
+/// Campos de una entrada al azar de `clave` en la gramática del idioma
+fn campos(lang: &Language, clave: &str, rng: &mut impl Rng) -> Vec<String> {
+    lang.paquete().campos(Seccion::Gramatica, clave, None, &[], rng)
+}
+
 /// Las 7 Capas completas del Alma
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SietCapas {
@@ -586,42 +405,11 @@ impl SietCapas {
     pub fn generar(rng: &mut impl Rng, mundo: &super::Mundo, lang: &Language) -> Self {
         let arquetipo = Arquetipo::generar(rng, lang);
         let herida = Herida::generar(rng, mundo, lang);
-        // TODO: Adaptar el resto de capas
-        let mut mascara = Mascara::generar(rng, &herida);
-        let mut deseo_necesidad = DeseoNecesidad::generar(rng, &herida);
-        let mut sombra = Sombra::generar(rng);
-        let vinculos = CapaVinculos::generar(rng, &herida);
-        let mut mentira = Mentira::generar(rng, &herida);
-        
-        // Adaptación de idioma para las capas restantes
-        if *lang != Language::Espanol {
-            let lang_code = match lang { Language::English => "en", Language::Japanese => "jp", _ => "es" };
-            let seed = rng.next_u64();
-            
-            // Mascara
-            let img = mascara.imagen_proyectada.clone();
-            mascara.imagen_proyectada = super::adapter::adapt_text(&img, lang_code, seed.wrapping_add(10));
-            let frase = mascara.frase_tipica.clone();
-            mascara.frase_tipica = super::adapter::adapt_text(&frase, lang_code, seed.wrapping_add(11));
-            
-            // Deseo/Necesidad
-            let deseo = deseo_necesidad.deseo_consciente.clone();
-            deseo_necesidad.deseo_consciente = super::adapter::adapt_text(&deseo, lang_code, seed.wrapping_add(20));
-            let necesidad = deseo_necesidad.necesidad_real.clone();
-            deseo_necesidad.necesidad_real = super::adapter::adapt_text(&necesidad, lang_code, seed.wrapping_add(21));
-            
-            // Sombra
-            let rasgo = sombra.rasgo_negado.clone();
-            sombra.rasgo_negado = super::adapter::adapt_text(&rasgo, lang_code, seed.wrapping_add(30));
-            let peligro = sombra.peligro_si_domina.clone();
-            sombra.peligro_si_domina = super::adapter::adapt_text(&peligro, lang_code, seed.wrapping_add(31));
-            
-            // Mentira
-            let men = mentira.la_mentira.clone();
-            mentira.la_mentira = super::adapter::adapt_text(&men, lang_code, seed.wrapping_add(40));
-            let ver = mentira.verdad_necesaria.clone();
-            mentira.verdad_necesaria = super::adapter::adapt_text(&ver, lang_code, seed.wrapping_add(41));
-        }
+        let mascara = Mascara::generar(rng, &herida, lang);
+        let deseo_necesidad = DeseoNecesidad::generar(rng, &herida, lang);
+        let sombra = Sombra::generar(rng, lang);
+        let vinculos = CapaVinculos::generar(rng, &herida, lang);
+        let mentira = Mentira::generar(rng, &herida, lang);
         
         Self {
             arquetipo,
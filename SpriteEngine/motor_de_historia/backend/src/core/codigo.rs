@@ -0,0 +1,363 @@
+//! Códigos de personaje: los parámetros y la semilla de un alma empaquetados
+//! en una cadena base62 corta que se puede compartir en lugar del JSON.
+//!
+//! Formato (bytes, big endian):
+//! `[versión][semilla: 8][campos: 4][edad: 2][nombre: UTF-8...][checksum: 2]`
+//!
+//! - `versión` es la [`VERSION_GENERADOR`] con la que se creó. Cada versión
+//!   anterior conserva su generador congelado (`core::v1`, ...), así un código
+//!   viejo sigue dando el mismo personaje aunque el contenido cambie; solo se
+//!   rechazan las versiones que este servidor no conoce.
+//! - `campos` empaqueta cada enum como índice + 1 (0 = sin fijar) según las
+//!   tablas de abajo, cuyo orden forma parte del formato.
+//! - `edad` es edad + 1 (0 = sin fijar) y el nombre ocupa el resto.
+//!
+//! Los bytes se codifican en bloques de 8, cada uno con un ancho fijo de
+//! dígitos base62 que depende solo de su longitud.
+
+use std::fmt;
+
+use serde::Serialize;
+
+use super::{
+    fnv1a, Alma, Genero, Language, Mundo, NivelConflicto, ParametrosGeneracion, Profundidad, Raza,
+    Rol, SoulForge, TonoMoral, VERSIONES_GENERADOR, VERSION_GENERADOR,
+};
+
+// ============================================================
+// TABLAS DEL FORMATO (solo se puede añadir al final)
+// ============================================================
+
+const MUNDOS: [Mundo; 25] = [
+    Mundo::FantasiaMedieval, Mundo::FantasiaOscura, Mundo::FantasiaUrbana,
+    Mundo::SciFiSpace, Mundo::SciFiCyberpunk, Mundo::SciFiPostApocaliptico,
+    Mundo::Realista, Mundo::HistoricoMedieval, Mundo::HistoricoAntiguo,
+    Mundo::HistoricoModerno, Mundo::Victoriano,
+    Mundo::JaponFeudal, Mundo::AnimeFantasia, Mundo::ChinaImperial, Mundo::Wuxia,
+    Mundo::CoreaHistorica, Mundo::MitologiaAsiatica,
+    Mundo::Anime, Mundo::Mitologico, Mundo::MitologiaGriega, Mundo::MitologiaNordica,
+    Mundo::Steampunk, Mundo::Western, Mundo::Noir, Mundo::PiratasCaribe,
+];
+
+const ROLES: [Rol; 16] = [
+    Rol::Heroe, Rol::Villano, Rol::Mentor, Rol::Aliado,
+    Rol::Catalizador, Rol::Sombra, Rol::Guardian, Rol::Embaucador,
+    Rol::Mercenario, Rol::Lider, Rol::Marginado, Rol::Rebelde,
+    Rol::Bufon, Rol::Victima, Rol::Profeta, Rol::Jugador,
+];
+
+const TONOS: [TonoMoral; 5] = [
+    TonoMoral::Luminoso, TonoMoral::Claro, TonoMoral::Gris, TonoMoral::Oscuro, TonoMoral::Abismal,
+];
+
+const CONFLICTOS: [NivelConflicto; 4] = [
+    NivelConflicto::Bajo, NivelConflicto::Medio, NivelConflicto::Alto, NivelConflicto::Extremo,
+];
+
+const GENEROS: [Genero; 2] = [Genero::Masculino, Genero::Femenino];
+
+const PROFUNDIDADES: [Profundidad; 4] = [
+    Profundidad::Minima, Profundidad::Media, Profundidad::Completa, Profundidad::Exhaustiva,
+];
+
+const IDIOMAS: [Language; 3] = [Language::Espanol, Language::English, Language::Japanese];
+
+const RAZAS: [Raza; 8] = [
+    Raza::Humano, Raza::Elfo, Raza::Enano, Raza::Halfling,
+    Raza::Dragonborn, Raza::Gnomo, Raza::Tiefling, Raza::Orco,
+];
+
+/// (desplazamiento, bits) de cada enum dentro de `campos`
+const BITS_MUNDO: (u32, u32) = (0, 5);
+const BITS_ROL: (u32, u32) = (5, 5);
+const BITS_TONO: (u32, u32) = (10, 3);
+const BITS_CONFLICTO: (u32, u32) = (13, 3);
+const BITS_GENERO: (u32, u32) = (16, 2);
+const BITS_PROFUNDIDAD: (u32, u32) = (18, 3);
+const BITS_IDIOMA: (u32, u32) = (21, 2);
+const BITS_RAZA: (u32, u32) = (23, 4);
+
+const CABECERA: usize = 15;
+const CHECKSUM: usize = 2;
+const NOMBRE_MAX: usize = 48;
+const BLOQUE: usize = 8;
+
+// ============================================================
+// ERRORES
+// ============================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodigoError {
+    /// Los parámetros no traen semilla: sin ella el código no reproduce nada
+    SinSemilla,
+    NombreDemasiadoLargo(usize),
+    EdadFueraDeRango(u32),
+    /// Carácter fuera del alfabeto base62 o longitud imposible
+    Malformado,
+    ChecksumInvalido,
+    /// El código es de una versión del generador que este servidor no tiene
+    VersionIncompatible { codigo: u8, generador: u8 },
+    /// Idioma de un paquete cargado: no tiene hueco fijo en el código
+    IdiomaSinCodigo(String),
+    /// Mundo propio de un paquete: tampoco cabe en la tabla de mundos
+    MundoSinCodigo(String),
+}
+
+impl CodigoError {
+    /// Identificador estable para respuestas JSON
+    pub fn clave(&self) -> &'static str {
+        match self {
+            CodigoError::SinSemilla => "sin_semilla",
+            CodigoError::NombreDemasiadoLargo(_) => "nombre_demasiado_largo",
+            CodigoError::EdadFueraDeRango(_) => "edad_fuera_de_rango",
+            CodigoError::Malformado => "codigo_malformado",
+            CodigoError::ChecksumInvalido => "checksum_invalido",
+            CodigoError::VersionIncompatible { .. } => "version_incompatible",
+            CodigoError::IdiomaSinCodigo(_) => "idioma_sin_codigo",
+            CodigoError::MundoSinCodigo(_) => "mundo_sin_codigo",
+        }
+    }
+}
+
+impl fmt::Display for CodigoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CodigoError::SinSemilla => write!(f, "Los parámetros no tienen semilla"),
+            CodigoError::NombreDemasiadoLargo(n) => {
+                write!(f, "El nombre ocupa {} bytes; el máximo en un código es {}", n, NOMBRE_MAX)
+            }
+            CodigoError::EdadFueraDeRango(e) => write!(f, "La edad {} no cabe en un código", e),
+            CodigoError::Malformado => write!(f, "El código no es válido"),
+            CodigoError::ChecksumInvalido => write!(f, "El código está incompleto o mal copiado"),
+            CodigoError::VersionIncompatible { codigo, generador } => write!(
+                f,
+                "El código es de la versión {} del generador; este servidor genera hasta la versión {}",
+                codigo, generador
+            ),
+            CodigoError::IdiomaSinCodigo(idioma) => {
+                write!(f, "El idioma '{}' viene de un paquete y no cabe en un código", idioma)
+            }
+            CodigoError::MundoSinCodigo(mundo) => {
+                write!(f, "El mundo '{}' viene de un paquete y no cabe en un código", mundo)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CodigoError {}
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Empaqueta los parámetros (con `semilla` obligatoria) en un código base62
+pub fn codificar(params: &ParametrosGeneracion) -> Result<String, CodigoError> {
+    let semilla = params.semilla.ok_or(CodigoError::SinSemilla)?;
+    let nombre = params.nombre_fijo.as_deref().unwrap_or("");
+    if nombre.len() > NOMBRE_MAX {
+        return Err(CodigoError::NombreDemasiadoLargo(nombre.len()));
+    }
+    let edad = match params.edad_fija {
+        None => 0,
+        Some(e) => e.checked_add(1)
+            .and_then(|e| u16::try_from(e).ok())
+            .ok_or(CodigoError::EdadFueraDeRango(e))?,
+    };
+    if let Some(Language::Paquete(idioma)) = params.idioma {
+        return Err(CodigoError::IdiomaSinCodigo(idioma.as_str().to_string()));
+    }
+    if let Some(Mundo::Custom(mundo)) = params.mundo {
+        return Err(CodigoError::MundoSinCodigo(mundo.as_str().to_string()));
+    }
+
+    let campos = empaquetar(BITS_MUNDO, indice(&MUNDOS, params.mundo))
+        | empaquetar(BITS_ROL, indice(&ROLES, params.rol))
+        | empaquetar(BITS_TONO, indice(&TONOS, params.tono_moral))
+        | empaquetar(BITS_CONFLICTO, indice(&CONFLICTOS, params.nivel_conflicto))
+        | empaquetar(BITS_GENERO, indice(&GENEROS, params.genero))
+        | empaquetar(BITS_PROFUNDIDAD, indice(&PROFUNDIDADES, params.profundidad))
+        | empaquetar(BITS_IDIOMA, indice(&IDIOMAS, params.idioma))
+        | empaquetar(BITS_RAZA, indice(&RAZAS, params.raza));
+
+    let mut bytes = Vec::with_capacity(CABECERA + nombre.len() + CHECKSUM);
+    bytes.push(VERSION_GENERADOR);
+    bytes.extend_from_slice(&semilla.to_be_bytes());
+    bytes.extend_from_slice(&campos.to_be_bytes());
+    bytes.extend_from_slice(&edad.to_be_bytes());
+    bytes.extend_from_slice(nombre.as_bytes());
+    bytes.extend_from_slice(&checksum(&bytes).to_be_bytes());
+
+    Ok(bytes.chunks(BLOQUE).map(codificar_bloque).collect())
+}
+
+/// Contenido de un código: los parámetros y la versión del generador que los forja
+#[derive(Debug, Clone, Serialize)]
+pub struct Decodificado {
+    pub version_generador: u8,
+    pub parametros: ParametrosGeneracion,
+}
+
+/// Recupera los parámetros de un código, comprobando checksum y versión
+pub fn decodificar(codigo: &str) -> Result<Decodificado, CodigoError> {
+    let bytes = bytes_de_codigo(codigo.trim())?;
+    if bytes.len() < CABECERA + CHECKSUM {
+        return Err(CodigoError::Malformado);
+    }
+    let (datos, suma) = bytes.split_at(bytes.len() - CHECKSUM);
+    if checksum(datos).to_be_bytes() != suma {
+        return Err(CodigoError::ChecksumInvalido);
+    }
+    if !VERSIONES_GENERADOR.contains(&datos[0]) {
+        return Err(CodigoError::VersionIncompatible { codigo: datos[0], generador: VERSION_GENERADOR });
+    }
+
+    let semilla = u64::from_be_bytes(datos[1..9].try_into().unwrap());
+    let campos = u32::from_be_bytes(datos[9..13].try_into().unwrap());
+    let edad = u16::from_be_bytes(datos[13..15].try_into().unwrap());
+    let nombre = std::str::from_utf8(&datos[CABECERA..]).map_err(|_| CodigoError::Malformado)?;
+
+    let parametros = ParametrosGeneracion {
+        semilla: Some(semilla),
+        mundo: desempaquetar(&MUNDOS, BITS_MUNDO, campos)?,
+        rol: desempaquetar(&ROLES, BITS_ROL, campos)?,
+        tono_moral: desempaquetar(&TONOS, BITS_TONO, campos)?,
+        nivel_conflicto: desempaquetar(&CONFLICTOS, BITS_CONFLICTO, campos)?,
+        genero: desempaquetar(&GENEROS, BITS_GENERO, campos)?,
+        profundidad: desempaquetar(&PROFUNDIDADES, BITS_PROFUNDIDAD, campos)?,
+        idioma: desempaquetar(&IDIOMAS, BITS_IDIOMA, campos)?,
+        raza: desempaquetar(&RAZAS, BITS_RAZA, campos)?,
+        edad_fija: edad.checked_sub(1).map(u32::from),
+        nombre_fijo: (!nombre.is_empty()).then(|| nombre.to_string()),
+    };
+    Ok(Decodificado { version_generador: datos[0], parametros })
+}
+
+/// Decodifica y vuelve a forjar el alma exacta del código, con su versión
+pub fn regenerar(codigo: &str) -> Result<Alma, CodigoError> {
+    let Decodificado { version_generador, parametros } = decodificar(codigo)?;
+    let semilla = parametros.semilla.unwrap_or_default();
+    SoulForge::con_semilla(semilla)
+        .forjar_version(version_generador, parametros)
+        .ok_or(CodigoError::VersionIncompatible { codigo: version_generador, generador: VERSION_GENERADOR })
+}
+
+// ============================================================
+// INTERNOS
+// ============================================================
+
+fn indice<T: PartialEq>(tabla: &[T], valor: Option<T>) -> u32 {
+    valor
+        .and_then(|v| tabla.iter().position(|t| *t == v))
+        .map_or(0, |i| i as u32 + 1)
+}
+
+fn empaquetar((desplazamiento, _): (u32, u32), valor: u32) -> u32 {
+    valor << desplazamiento
+}
+
+fn desempaquetar<T: Copy>(tabla: &[T], (desplazamiento, bits): (u32, u32), campos: u32) -> Result<Option<T>, CodigoError> {
+    match (campos >> desplazamiento) & ((1 << bits) - 1) {
+        0 => Ok(None),
+        i => tabla.get(i as usize - 1).copied().map(Some).ok_or(CodigoError::Malformado),
+    }
+}
+
+fn checksum(bytes: &[u8]) -> u16 {
+    let h = fnv1a(bytes);
+    (h ^ (h >> 16) ^ (h >> 32) ^ (h >> 48)) as u16
+}
+
+/// Dígitos base62 necesarios para un bloque de `n` bytes (distinto para cada n)
+fn ancho_bloque(n: usize) -> usize {
+    let maximo = if n == BLOQUE { u64::MAX as u128 } else { (1u128 << (8 * n)) - 1 };
+    base62::encode(maximo).len()
+}
+
+fn codificar_bloque(bloque: &[u8]) -> String {
+    let valor = bloque.iter().fold(0u128, |v, &b| (v << 8) | b as u128);
+    format!("{:0>ancho$}", base62::encode(valor), ancho = ancho_bloque(bloque.len()))
+}
+
+fn bytes_de_codigo(codigo: &str) -> Result<Vec<u8>, CodigoError> {
+    let ancho_completo = ancho_bloque(BLOQUE);
+    let mut bytes = Vec::new();
+    let mut resto = codigo;
+    while !resto.is_empty() {
+        let (n, ancho) = if resto.len() >= ancho_completo {
+            (BLOQUE, ancho_completo)
+        } else {
+            (1..BLOQUE)
+                .map(|n| (n, ancho_bloque(n)))
+                .find(|&(_, ancho)| ancho == resto.len())
+                .ok_or(CodigoError::Malformado)?
+        };
+        let digitos = resto.get(..ancho).ok_or(CodigoError::Malformado)?;
+        let valor = base62::decode(digitos).map_err(|_| CodigoError::Malformado)?;
+        if valor >> (8 * n) != 0 {
+            return Err(CodigoError::Malformado);
+        }
+        bytes.extend_from_slice(&valor.to_be_bytes()[16 - n..]);
+        resto = &resto[ancho..];
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codigo_ida_y_vuelta() {
+        let params = ParametrosGeneracion {
+            semilla: Some(u64::MAX - 7),
+            mundo: Some(Mundo::PiratasCaribe),
+            rol: Some(Rol::Jugador),
+            tono_moral: Some(TonoMoral::Abismal),
+            genero: Some(Genero::Femenino),
+            raza: Some(Raza::Orco),
+            idioma: Some(Language::Japanese),
+            nombre_fijo: Some("Ñora 真希".to_string()),
+            edad_fija: Some(312),
+            ..Default::default()
+        };
+        let codigo = codificar(&params).unwrap();
+        assert!(codigo.chars().all(|c| c.is_ascii_alphanumeric()));
+
+        let vuelta = decodificar(&codigo).unwrap();
+        assert_eq!(vuelta.version_generador, VERSION_GENERADOR);
+        assert_eq!(serde_json::to_value(&vuelta.parametros).unwrap(), serde_json::to_value(&params).unwrap());
+
+        let alma = regenerar(&codigo).unwrap();
+        assert_eq!(alma.id, SoulForge::nuevo().forjar(params).id);
+    }
+
+    #[test]
+    fn codigo_detecta_errores() {
+        let minimo = codificar(&ParametrosGeneracion { semilla: Some(7), ..Default::default() }).unwrap();
+        assert!(minimo.len() <= 24, "{}", minimo);
+        assert_eq!(codificar(&ParametrosGeneracion::default()), Err(CodigoError::SinSemilla));
+
+        // Cambiar un dígito del bloque de la semilla rompe el checksum
+        let mut alterado: Vec<char> = minimo.chars().collect();
+        alterado[3] = if alterado[3] == 'a' { 'b' } else { 'a' };
+        let alterado: String = alterado.into_iter().collect();
+        assert_eq!(decodificar(&alterado).unwrap_err(), CodigoError::ChecksumInvalido);
+        assert_eq!(decodificar(&minimo[..minimo.len() - 1]).unwrap_err(), CodigoError::Malformado);
+
+        // Mismo contenido con otra versión del generador: las anteriores se
+        // conservan, las futuras no se pueden regenerar aquí
+        let con_version = |version: u8| {
+            let mut bytes = bytes_de_codigo(&minimo).unwrap();
+            bytes[0] = version;
+            let n = bytes.len() - CHECKSUM;
+            let suma = checksum(&bytes[..n]).to_be_bytes();
+            bytes[n..].copy_from_slice(&suma);
+            bytes.chunks(BLOQUE).map(codificar_bloque).collect::<String>()
+        };
+        assert_eq!(decodificar(&con_version(1)).unwrap().version_generador, 1);
+        assert_eq!(
+            decodificar(&con_version(VERSION_GENERADOR + 1)).unwrap_err(),
+            CodigoError::VersionIncompatible { codigo: VERSION_GENERADOR + 1, generador: VERSION_GENERADOR }
+        );
+    }
+}
@@ -2,7 +2,8 @@
 
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
-use super::{Mundo, Genero};
+use super::paquete_idioma::Seccion;
+use super::{paquete_mundo, Genero, Language, Mundo, Raza};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Identidad {
@@ -19,7 +20,7 @@ pub struct Identidad {
 }
 
 impl Identidad {
-    pub fn generar(rng: &mut impl Rng, mundo: &Mundo, genero_opt: Option<Genero>, edad_opt: Option<u32>) -> Self {
+    pub fn generar(rng: &mut impl Rng, mundo: &Mundo, genero_opt: Option<Genero>, edad_opt: Option<u32>, lang: &Language) -> Self {
         let genero = genero_opt.unwrap_or_else(|| Self::generar_genero(rng));
         let (nombre, apellido) = Self::generar_nombre(rng, mundo, &genero);
         
@@ -28,14 +29,14 @@ impl Identidad {
         Self {
             nombre,
             apellido,
-            titulo: Self::generar_titulo(rng),
+            titulo: Self::generar_titulo(rng, lang),
             edad,
             genero,
-            rasgo_distintivo: Self::generar_rasgo(rng),
-            vestimenta: Self::generar_vestimenta(rng),
-            voz: Self::generar_voz(rng),
-            manierismo: Self::generar_manierismo(rng),
-            cicatriz: Self::generar_cicatriz(rng),
+            rasgo_distintivo: frase(lang, "identidad_rasgo", rng),
+            vestimenta: frase(lang, "identidad_vestimenta", rng),
+            voz: frase(lang, "identidad_voz", rng),
+            manierismo: frase(lang, "identidad_manierismo", rng),
+            cicatriz: Self::generar_cicatriz(rng, lang),
         }
     }
     
@@ -45,7 +46,7 @@ impl Identidad {
     }
     
     fn generar_nombre(rng: &mut impl Rng, mundo: &Mundo, genero: &Genero) -> (String, Option<String>) {
-        let nombres_m = match mundo {
+        let nombres_m = match mundo.base() {
             Mundo::FantasiaMedieval | Mundo::FantasiaOscura => vec![
                 "Aldric", "Kael", "Theron", "Varen", "Draken", "Ezran", "Lucian", "Malakai", "Silas", "Ronan", "Caden",
                 "Roland", "Gareth", "Tristan", "Edmund", "Garrick", "Thorne", "Valerius", "Caelum", "Darius", 
@@ -128,7 +129,7 @@ impl Identidad {
             ],
         };
         
-        let nombres_f = match mundo {
+        let nombres_f = match mundo.base() {
             Mundo::FantasiaMedieval | Mundo::FantasiaOscura => vec![
                 "Seraphina", "Lyria", "Isolde", "Elara", "Althea", "Mira", "Vivienne", "Rowena", "Astrid", "Freya", 
                 "Helena", "Liora", "Aria", "Briar", "Celia", "Dahlia", "Elowen", "Fiora", "Genevieve", "Hazel", 
@@ -204,7 +205,7 @@ impl Identidad {
             ],
         };
         
-        let apellidos = match mundo {
+        let apellidos = match mundo.base() {
             Mundo::FantasiaMedieval | Mundo::FantasiaOscura => vec![
                 "Ravencroft", "Shadowend", "Darkhollow", "Stormborn", "Ironwood",
                 "Blackthorn", "Ashford", "Nightfall", "Silvermoon", "Frostbourne"
@@ -239,6 +240,11 @@ impl Identidad {
             ],
         };
         
+        // Un mundo propio puede traer sus listas; las que no, salen de su base
+        let nombres_m = paquete_mundo::propia(mundo, |p| &p.nombres_masculinos, nombres_m);
+        let nombres_f = paquete_mundo::propia(mundo, |p| &p.nombres_femeninos, nombres_f);
+        let apellidos = paquete_mundo::propia(mundo, |p| &p.apellidos, apellidos);
+        
         let nombre = match genero {
             Genero::Masculino => nombres_m.choose(rng).unwrap(),
             Genero::Femenino => nombres_f.choose(rng).unwrap(),
@@ -252,70 +258,27 @@ impl Identidad {
         (nombre.to_string(), apellido)
     }
     
-    fn generar_titulo(rng: &mut impl Rng) -> Option<String> {
+    fn generar_titulo(rng: &mut impl Rng, lang: &Language) -> Option<String> {
         if rng.gen_bool(0.4) {
-            let titulos = [
-                "El Silencioso", "Manos Rojas", "El Último de su Nombre",
-                "La Sombra", "Corazón de Hierro", "El Errante",
-                "Sin Nombre", "El Caído", "La Voz del Trueno",
-                "Ojos de Tormenta", "El Roto", "La Esperanza Perdida"
-            ];
-            Some(titulos.choose(rng).unwrap().to_string())
+            Some(frase(lang, "identidad_titulo", rng))
         } else { None }
     }
     
-    fn generar_rasgo(rng: &mut impl Rng) -> String {
-        let rasgos = [
-            "Ojos que parecen ver más de lo que deberían",
-            "Una sonrisa que nunca llega a los ojos",
-            "Manos que tiemblan cuando está quieto",
-            "Postura militar incluso dormido",
-            "Una mirada que hace sentir juzgado"
-        ];
-        rasgos.choose(rng).unwrap().to_string()
-    }
-    
-    fn generar_vestimenta(rng: &mut impl Rng) -> String {
-        let vestimentas = [
-            "Ropa práctica, siempre lista para huir",
-            "Capas que ocultan su verdadera forma",
-            "Vestimenta que contradice su rol social",
-            "Algo elegante con rastros de desgaste",
-            "Simple, como queriendo pasar desapercibido"
-        ];
-        vestimentas.choose(rng).unwrap().to_string()
-    }
-    
-    fn generar_voz(rng: &mut impl Rng) -> String {
-        let voces = [
-            "Grave y medida, cada palabra elegida",
-            "Suave pero con un filo que advierte",
-            "Cansada, como quien no espera ser escuchado",
-            "Intensa, incluso cuando susurra",
-            "Cambiante según la audiencia"
-        ];
-        voces.choose(rng).unwrap().to_string()
-    }
-    
-    fn generar_manierismo(rng: &mut impl Rng) -> String {
-        let manierismos = [
-            "Cuenta cosas obsesivamente - pasos, latidos",
-            "Habla de sí mismo en tercera persona bajo estrés",
-            "Repite la última palabra de otros antes de responder",
-            "Se toca una cicatriz invisible cuando miente",
-            "Cierra los ojos antes de decisiones importantes"
-        ];
-        manierismos.choose(rng).unwrap().to_string()
-    }
-    
-    fn generar_cicatriz(rng: &mut impl Rng) -> Option<String> {
+    fn generar_cicatriz(rng: &mut impl Rng, lang: &Language) -> Option<String> {
         if rng.gen_bool(0.5) {
-            let cicatrices = [
-                "Una quemadura en el antebrazo que oculta",
-                "Una línea fina en el cuello",
-                "Marcas en los nudillos"
-            ];
-            Some(cicatrices.choose(rng).unwrap().to_string())
+            Some(frase(lang, "identidad_cicatriz", rng))
         } else { None }
     }
+    
+    /// Añade la raza de la ficha al rasgo distintivo
+    pub fn anotar_raza(&mut self, raza: Raza, lang: &Language) {
+        let paquete = lang.paquete();
+        let nombre_raza = paquete.texto_de_variante(Seccion::Gramatica, "raza", &format!("{:?}", raza), &[]);
+        self.rasgo_distintivo = paquete.texto(Seccion::Gramatica, "identidad_raza", &[&self.rasgo_distintivo, &nombre_raza]);
+    }
+}
+
+/// Rasgos, voces, títulos...: la gramática del idioma tiene un banco por campo
+fn frase(lang: &Language, clave: &str, rng: &mut impl Rng) -> String {
+    lang.paquete().frase(Seccion::Gramatica, clave, &[], rng)
 }
@@ -34,9 +34,7 @@ pub enum Rarity {
 pub struct ItemGenerator;
 
 impl ItemGenerator {
-    pub fn generate_loot(rarity: Option<Rarity>) -> Item {
-        let mut rng = rand::thread_rng();
-        
+    pub fn generate_loot(rng: &mut impl Rng, rarity: Option<Rarity>) -> Item {
         let actual_rarity = rarity.unwrap_or_else(|| {
             let roll = rng.gen_range(0..100);
             if roll < 50 { Rarity::Common }
@@ -53,12 +51,10 @@ impl ItemGenerator {
             _ => ItemType::Potion,
         };
 
-        Self::create_item(item_type, actual_rarity)
+        Self::create_item(rng, item_type, actual_rarity)
     }
 
-    pub fn forge(item_type: ItemType, material_quality: u8, smith_skill: u8) -> Item {
-        let mut rng = rand::thread_rng();
-        
+    pub fn forge(rng: &mut impl Rng, item_type: ItemType, material_quality: u8, smith_skill: u8) -> Item {
         // Forge logic: Quality + Skill determines rarity
         let score = material_quality as u32 + smith_skill as u32 + rng.gen_range(0..20);
         let rarity = if score < 50 { Rarity::Common }
@@ -67,12 +63,10 @@ impl ItemGenerator {
             else if score < 190 { Rarity::Epic }
             else { Rarity::Legendary };
 
-        Self::create_item(item_type, rarity)
+        Self::create_item(rng, item_type, rarity)
     }
 
-    fn create_item(item_type: ItemType, rarity: Rarity) -> Item {
-        let mut rng = rand::thread_rng();
-        
+    fn create_item(rng: &mut impl Rng, item_type: ItemType, rarity: Rarity) -> Item {
         let (name, desc, stats) = match item_type {
             ItemType::Weapon => {
                 let bases = ["Espada", "Hacha", "Lanza", "Daga", "Maza", "Arco", "Mandoble"];
@@ -80,9 +74,9 @@ impl ItemGenerator {
                 let nouns = ["del Soldado", "del Rey", "de la Furia", "del Ocaso", "de Sangre", "del Viento", "del Dolor"];
                 
                 let n = format!("{} {} {}", 
-                    bases.choose(&mut rng).unwrap(), 
-                    match rarity { Rarity::Common => "", _ => adjs.choose(&mut rng).unwrap() },
-                    match rarity { Rarity::Common | Rarity::Uncommon => "", _ => nouns.choose(&mut rng).unwrap() }
+                    bases.choose(rng).unwrap(), 
+                    match rarity { Rarity::Common => "", _ => adjs.choose(rng).unwrap() },
+                    match rarity { Rarity::Common | Rarity::Uncommon => "", _ => nouns.choose(rng).unwrap() }
                 ).trim().replace("  ", " ");
 
                 let dmg = match rarity {
@@ -100,7 +94,7 @@ impl ItemGenerator {
                 let bases = ["Peto", "Escudo", "Yelmo", "Botas", "Guanteletes"];
                 let mats = ["de Cuero", "de Malla", "de Placas", "de Escamas", "de Mitril"];
                 
-                let n = format!("{} {}", bases.choose(&mut rng).unwrap(), mats.choose(&mut rng).unwrap());
+                let n = format!("{} {}", bases.choose(rng).unwrap(), mats.choose(rng).unwrap());
                 (n, "Protección esencial.".to_string(), vec!["Defensa +2".to_string()])
             },
             ItemType::Trinket => ("Amuleto Perdido".to_string(), "Brilla tenuemente.".to_string(), vec!["Suerte +1".to_string()]),
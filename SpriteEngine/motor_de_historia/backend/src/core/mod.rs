@@ -8,7 +8,6 @@ mod biografia;
 mod conexiones;
 pub mod gramatica;
 pub mod i18n;
-pub mod adapter;
 pub mod procedural_text;
 pub mod narrativa;
 pub mod promo;
@@ -16,6 +15,11 @@ pub mod items;
 pub mod skills;
 pub mod mundo_narrativo;
 pub mod ia_integration;
+pub mod codigo;
+pub mod reforja;
+pub mod paquete_idioma;
+pub mod paquete_mundo;
+mod v1;
 
 pub use alma::*;
 pub use capas::*;
@@ -23,12 +27,12 @@ pub use identidad::*;
 pub use arco::*;
 pub use biografia::*;
 pub use conexiones::*;
-pub use adapter::*;
 
 
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 
 /// Rol narrativo del personaje
@@ -188,6 +192,10 @@ pub enum Mundo {
     Western,
     Noir,
     PiratasCaribe,
+    /// Mundo propio cargado desde un paquete (ver `paquete_mundo`). Se
+    /// serializa como su id, igual que los integrados como su nombre
+    #[serde(untagged)]
+    Custom(paquete_mundo::IdMundo),
 }
 
 impl Mundo {
@@ -226,14 +234,27 @@ pub enum Language {
     Espanol,
     English,
     Japanese,
+    /// Idioma cargado desde un paquete (ver `paquete_idioma`)
+    Paquete(paquete_idioma::CodigoIdioma),
 }
 
 impl Language {
     pub fn from_str(s: &str) -> Self {
         match s {
+            "es" => Language::Espanol,
             "en" => Language::English,
             "jp" => Language::Japanese,
-            _ => Language::Espanol,
+            _ => paquete_idioma::CodigoIdioma::buscar(s).map_or(Language::Espanol, Language::Paquete),
+        }
+    }
+
+    /// Código del idioma ("es", "en", "jp" o el del paquete)
+    pub fn codigo(&self) -> &'static str {
+        match self {
+            Language::Espanol => "es",
+            Language::English => "en",
+            Language::Japanese => "jp",
+            Language::Paquete(codigo) => codigo.as_str(),
         }
     }
 }
@@ -260,7 +281,7 @@ impl Default for ForgeConfig {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Profundidad {
     Minima,
     Media,
@@ -288,6 +309,20 @@ impl SoulForge {
         Alma::generar(&mut self.rng, params, &self.config)
     }
     
+    /// Forja con una versión concreta del generador, si este servidor la conserva
+    pub fn forjar_version(&mut self, version: u8, params: ParametrosGeneracion) -> Option<Alma> {
+        match version {
+            1 => Some(v1::forjar(&mut self.rng, params, &self.config)),
+            VERSION_GENERADOR => Some(self.forjar(params)),
+            _ => None,
+        }
+    }
+    
+    /// Regenera solo las partes elegidas de un alma (y lo que depende de ellas)
+    pub fn reforjar(&self, alma: &Alma, partes: &[reforja::ParteAlma], semilla: u64, idioma: &Language) -> reforja::Reforja {
+        reforja::reforjar(alma, partes, semilla, idioma)
+    }
+    
     /// Forja múltiples almas con relaciones emergentes
     pub fn forjar_constelacion(&mut self, params: ParametrosConstelacion) -> crate::constelacion::Constelacion {
         crate::constelacion::ConstelacionBuilder::new()
@@ -301,10 +336,71 @@ impl SoulForge {
     }
 }
 
+/// Versión del generador de almas. Subirla siempre que la salida para una
+/// misma semilla cambie: los códigos compartidos la llevan dentro y las
+/// pruebas doradas (`tests/golden`) no se dejan regenerar sin ella.
+/// Al subirla, el generador anterior se congela como `core::vN` para que
+/// sus códigos sigan dando el mismo personaje (ver [`SoulForge::forjar_version`])
+pub const VERSION_GENERADOR: u8 = 2;
+
+/// Versiones que este servidor sabe forjar: la actual y las congeladas
+pub const VERSIONES_GENERADOR: [u8; 2] = [1, VERSION_GENERADOR];
+
+/// Flujo de ChaCha reservado a los ids, para que no consuma ni repita
+/// los números con los que se genera el contenido de la misma semilla
+const FLUJO_IDS: u64 = 0x1D;
+
+/// Id (con formato UUID v4) derivado de una semilla: misma semilla, mismo id
+pub fn uuid_desde_semilla(semilla: u64) -> Uuid {
+    let mut rng = ChaCha8Rng::seed_from_u64(semilla);
+    rng.set_stream(FLUJO_IDS);
+    uuid::Builder::from_random_bytes(rng.gen()).into_uuid()
+}
+
+/// Id de un alma: depende de la semilla y de los valores ya resueltos con
+/// que se forjó (mundo, rol, tono, género, raza, idioma, nombre y edad), así
+/// que la misma semilla con otro mundo o rol da otro id, y pedir el mundo por
+/// defecto o no pedirlo da el mismo. Qué entra en la huella es parte de la
+/// salida: cambiarlo exige subir `VERSION_GENERADOR`.
+/// El generador de la versión 1 sigue usando [`uuid_desde_semilla`]
+pub fn uuid_de_alma(alma: &Alma, idioma: &Language) -> Uuid {
+    let mundo = match &alma.mundo {
+        Mundo::Custom(id) => id.as_str().to_string(),
+        integrado => format!("{:?}", integrado),
+    };
+    let raza = alma.ficha_tecnica.as_ref().map(|f| format!("{:?}", f.raza)).unwrap_or_default();
+    let campos = [
+        VERSION_GENERADOR.to_string(),
+        mundo,
+        format!("{:?}", alma.rol),
+        format!("{:?}", alma.tono_moral),
+        format!("{:?}", alma.identidad.genero),
+        raza,
+        idioma.codigo().to_string(),
+        alma.identidad.nombre.clone(),
+        alma.identidad.edad.to_string(),
+        alma.semilla.to_string(),
+    ];
+    let huella = fnv1a(campos.join("\u{1f}").as_bytes());
+
+    let mut clave = [0u8; 32];
+    clave[..8].copy_from_slice(&alma.semilla.to_le_bytes());
+    clave[8..16].copy_from_slice(&huella.to_le_bytes());
+    let mut rng = ChaCha8Rng::from_seed(clave);
+    rng.set_stream(FLUJO_IDS);
+    uuid::Builder::from_random_bytes(rng.gen()).into_uuid()
+}
+
+/// Hash FNV-1a de 64 bits: estable entre ejecuciones y plataformas
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |h, &b| (h ^ b as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParametrosConstelacion {
+    pub semilla: Option<u64>,
     pub cantidad: usize,
     pub mundo: Mundo,
     pub densidad_relaciones: DensidadRelaciones,
@@ -316,11 +412,17 @@ pub struct ParametrosConstelacion {
     pub edades_fijas: Option<Vec<u32>>,
     pub tipo_villano: Option<String>, // "Envidia", "Ideologia", "PuraMaldad", "Obstaculo"
     pub modo_romance: Option<String>, // "Pareja", "Triangulo", "AmorProhibido", "Poliamor"
+    /// Tono de todos salvo el antagonista; sin él, cada uno se sortea
+    #[serde(default)]
+    pub tono: Option<TonoMoral>,
+    #[serde(default)]
+    pub idioma: Option<Language>,
 }
 
 impl Default for ParametrosConstelacion {
     fn default() -> Self {
         Self {
+            semilla: None,
             cantidad: 5,
             mundo: Mundo::FantasiaMedieval,
             densidad_relaciones: DensidadRelaciones::Normal,
@@ -332,6 +434,8 @@ impl Default for ParametrosConstelacion {
             edades_fijas: None,
             tipo_villano: None,
             modo_romance: None,
+            tono: None,
+            idioma: None,
         }
     }
 }
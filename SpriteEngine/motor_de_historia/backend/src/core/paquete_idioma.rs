@@ -0,0 +1,571 @@
+//! Paquetes de idioma: los bancos de frases de la generación, fuera del código.
+//!
+//! Cada idioma es un JSON con cuatro secciones (`gramatica`, `narrativa`,
+//! `mundo`, `procedural`) que asocian una clave a una lista de entradas. Una
+//! entrada es un texto o, cuando varias partes deben ir juntas, una lista de
+//! campos. Dentro de un texto:
+//!
+//! - `{0}`, `{1}`... se sustituyen por los parámetros de quien pide la frase.
+//! - `{clave}` se sustituye por una entrada al azar de esa clave de la misma
+//!   sección (así se combinan sujetos, verbos y circunstancias).
+//!
+//! Las claves que varían por mundo llevan el mundo detrás de un punto
+//! (`eventos.Noir`); `eventos.ChinaImperial|Wuxia` vale para los dos mundos y
+//! `eventos` a secas es la opción por defecto.
+//!
+//! Español, inglés y japonés vienen compilados. Otros idiomas se añaden
+//! dejando su paquete en un directorio (ver [`cargar_directorio`]); lo que
+//! un paquete no traduzca se toma de su `respaldo` (español por defecto).
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::Path;
+use std::sync::{Arc, Mutex, RwLock};
+
+use once_cell::sync::Lazy;
+use rand::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Language, Mundo};
+
+/// Idiomas compilados: generan la salida de `VERSION_GENERADOR` y no se
+/// pueden reemplazar desde fuera
+const INTEGRADOS: [(&str, &str); 3] = [
+    ("es", include_str!("paquetes/es.json")),
+    ("en", include_str!("paquetes/en.json")),
+    ("jp", include_str!("paquetes/jp.json")),
+];
+
+/// Niveles máximos de `{clave}` dentro de otra expansión
+const PROFUNDIDAD_MAX: usize = 8;
+
+static REGISTRO: Lazy<RwLock<BTreeMap<String, Arc<PaqueteIdioma>>>> = Lazy::new(|| {
+    let mut registro: BTreeMap<String, Arc<PaqueteIdioma>> = BTreeMap::new();
+    for (codigo, json) in INTEGRADOS {
+        let mut paquete = leer(json).unwrap_or_else(|e| panic!("Paquete integrado '{}' inválido: {}", codigo, e));
+        // El español va primero: es el respaldo de los otros dos
+        paquete.respaldo_resuelto = registro.get("es").cloned();
+        registro.insert(codigo.to_string(), Arc::new(paquete));
+    }
+    RwLock::new(registro)
+});
+
+/// Códigos de idioma ya vistos. Cada código se guarda una sola vez, por
+/// muchas veces que se vuelva a registrar su paquete
+static CODIGOS: Lazy<Mutex<BTreeSet<&'static str>>> = Lazy::new(Default::default);
+
+// ============================================================
+// TIPOS
+// ============================================================
+
+/// Sección de un paquete
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Seccion {
+    Gramatica,
+    Narrativa,
+    Mundo,
+    Procedural,
+}
+
+/// Una opción de un banco
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Entrada {
+    Texto(String),
+    /// Partes que se eligen juntas (p. ej. mentira, origen y verdad)
+    Campos(Vec<String>),
+}
+
+impl Entrada {
+    fn forma(&self) -> Option<usize> {
+        match self {
+            Entrada::Texto(_) => None,
+            Entrada::Campos(c) => Some(c.len()),
+        }
+    }
+
+    /// Primer (o único) texto de la entrada
+    pub fn texto(&self) -> &str {
+        &self.textos()[0]
+    }
+
+    fn textos(&self) -> &[String] {
+        match self {
+            Entrada::Texto(t) => std::slice::from_ref(t),
+            Entrada::Campos(c) => c,
+        }
+    }
+}
+
+pub type Banco = BTreeMap<String, Vec<Entrada>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaqueteIdioma {
+    pub codigo: String,
+    pub nombre: String,
+    /// Idioma del que se toman las claves que falten; `None` = español
+    #[serde(default)]
+    pub respaldo: Option<String>,
+    #[serde(default)]
+    pub gramatica: Banco,
+    #[serde(default)]
+    pub narrativa: Banco,
+    #[serde(default)]
+    pub mundo: Banco,
+    #[serde(default)]
+    pub procedural: Banco,
+    /// Paquete de `respaldo` (o el español) tal como estaba al registrar este
+    #[serde(skip)]
+    respaldo_resuelto: Option<Arc<PaqueteIdioma>>,
+}
+
+/// Código de un idioma que llega por paquete (`"pt"`, `"fr"`...).
+/// Solo se construye para idiomas registrados
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CodigoIdioma(&'static str);
+
+impl CodigoIdioma {
+    /// Código de un idioma registrado, si lo hay
+    pub fn buscar(codigo: &str) -> Option<Self> {
+        buscar(codigo).map(|p| CodigoIdioma(internar(&p.codigo)))
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+fn internar(codigo: &str) -> &'static str {
+    let mut codigos = CODIGOS.lock().unwrap();
+    match codigos.get(codigo) {
+        Some(codigo) => codigo,
+        None => {
+            let codigo: &'static str = Box::leak(codigo.into());
+            codigos.insert(codigo);
+            codigo
+        }
+    }
+}
+
+impl fmt::Debug for CodigoIdioma {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl Serialize for CodigoIdioma {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for CodigoIdioma {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let codigo = String::deserialize(deserializer)?;
+        CodigoIdioma::buscar(&codigo)
+            .ok_or_else(|| serde::de::Error::custom(format!("no hay paquete para el idioma '{}'", codigo)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaqueteError {
+    Json(String),
+    /// Los códigos son minúsculas, dígitos o '-', hasta 16 caracteres
+    CodigoInvalido(String),
+    /// es, en y jp vienen compilados
+    CodigoReservado(String),
+    RespaldoDesconocido(String),
+    /// Siguiendo los respaldos se vuelve al propio paquete
+    RespaldoCircular(String),
+    BancoVacio(String),
+    /// `{hueco}` en `clave` no corresponde a ninguna clave de la sección
+    HuecoSinBanco { clave: String, hueco: String },
+    /// La clave existe en español con otra forma (texto o número de campos)
+    FormaDistinta(String),
+    Archivo { ruta: String, error: Box<PaqueteError> },
+}
+
+impl fmt::Display for PaqueteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaqueteError::Json(e) => write!(f, "JSON inválido: {}", e),
+            PaqueteError::CodigoInvalido(c) => write!(f, "'{}' no es un código de idioma válido", c),
+            PaqueteError::CodigoReservado(c) => write!(f, "El idioma '{}' viene integrado y no se puede reemplazar", c),
+            PaqueteError::RespaldoDesconocido(c) => write!(f, "El idioma de respaldo '{}' no está cargado", c),
+            PaqueteError::RespaldoCircular(c) => write!(f, "El respaldo '{}' acaba volviendo a este mismo idioma", c),
+            PaqueteError::BancoVacio(c) => write!(f, "La clave '{}' no tiene entradas", c),
+            PaqueteError::HuecoSinBanco { clave, hueco } => {
+                write!(f, "'{}' usa {{{}}}, que no existe en su sección", clave, hueco)
+            }
+            PaqueteError::FormaDistinta(c) => write!(f, "La clave '{}' no tiene la misma forma que en español", c),
+            PaqueteError::Archivo { ruta, error } => write!(f, "{}: {}", ruta, error),
+        }
+    }
+}
+
+impl std::error::Error for PaqueteError {}
+
+// ============================================================
+// REGISTRO
+// ============================================================
+
+/// Paquete de un idioma registrado
+pub fn buscar(codigo: &str) -> Option<Arc<PaqueteIdioma>> {
+    REGISTRO.read().unwrap().get(codigo).cloned()
+}
+
+/// Idiomas disponibles: (código, nombre)
+pub fn disponibles() -> Vec<(String, String)> {
+    REGISTRO.read().unwrap().values().map(|p| (p.codigo.clone(), p.nombre.clone())).collect()
+}
+
+/// Valida y registra un paquete. Un paquete con el código de otro ya
+/// cargado (no integrado) lo reemplaza; el anterior se libera cuando deja
+/// de usarse
+pub fn registrar(json: &str) -> Result<Arc<PaqueteIdioma>, PaqueteError> {
+    let mut paquete = leer(json)?;
+    if INTEGRADOS.iter().any(|(c, _)| *c == paquete.codigo) {
+        return Err(PaqueteError::CodigoReservado(paquete.codigo));
+    }
+    validar(&paquete)?;
+    paquete.respaldo_resuelto = match &paquete.respaldo {
+        Some(codigo) => Some(buscar(codigo).ok_or_else(|| PaqueteError::RespaldoDesconocido(codigo.clone()))?),
+        None => Some(espanol()),
+    };
+
+    let paquete = Arc::new(paquete);
+    REGISTRO.write().unwrap().insert(paquete.codigo.clone(), Arc::clone(&paquete));
+    Ok(paquete)
+}
+
+/// Registra todos los `*.json` de `dir` y devuelve sus códigos. Un
+/// directorio que no existe no es un error: no hay idiomas extra
+pub fn cargar_directorio(dir: &Path) -> Result<Vec<String>, PaqueteError> {
+    let Ok(lectura) = std::fs::read_dir(dir) else {
+        return Ok(Vec::new());
+    };
+    let mut pendientes = Vec::new();
+    for entrada in lectura.flatten() {
+        let ruta = entrada.path();
+        if ruta.extension().is_some_and(|e| e == "json") {
+            let json = std::fs::read_to_string(&ruta).map_err(|e| PaqueteError::Archivo {
+                ruta: ruta.display().to_string(),
+                error: Box::new(PaqueteError::Json(e.to_string())),
+            })?;
+            pendientes.push((ruta.display().to_string(), json));
+        }
+    }
+    pendientes.sort();
+
+    // Un paquete puede apoyarse en otro del mismo directorio: se reintenta
+    // mientras alguno consiga registrarse
+    let mut codigos = Vec::new();
+    loop {
+        let antes = pendientes.len();
+        let mut fallos = Vec::new();
+        for (ruta, json) in pendientes {
+            match registrar(&json) {
+                Ok(p) => codigos.push(p.codigo.clone()),
+                Err(error) => fallos.push((ruta, json, error)),
+            }
+        }
+        if fallos.is_empty() {
+            return Ok(codigos);
+        }
+        if fallos.len() == antes {
+            let (ruta, _, error) = fallos.remove(0);
+            return Err(PaqueteError::Archivo { ruta, error: Box::new(error) });
+        }
+        pendientes = fallos.into_iter().map(|(ruta, json, _)| (ruta, json)).collect();
+    }
+}
+
+/// Parseo sin validar; expande las claves con varios mundos (`a.X|Y`)
+fn leer(json: &str) -> Result<PaqueteIdioma, PaqueteError> {
+    let mut paquete: PaqueteIdioma = serde_json::from_str(json).map_err(|e| PaqueteError::Json(e.to_string()))?;
+    for banco in [&mut paquete.gramatica, &mut paquete.narrativa, &mut paquete.mundo, &mut paquete.procedural] {
+        let multiples: Vec<String> = banco.keys().filter(|k| k.contains('|')).cloned().collect();
+        for clave in multiples {
+            let entradas = banco.remove(&clave).unwrap_or_default();
+            let (base, mundos) = clave.rsplit_once('.').unwrap_or(("", clave.as_str()));
+            for mundo in mundos.split('|') {
+                let clave = if base.is_empty() { mundo.to_string() } else { format!("{}.{}", base, mundo) };
+                banco.insert(clave, entradas.clone());
+            }
+        }
+    }
+    Ok(paquete)
+}
+
+fn validar(paquete: &PaqueteIdioma) -> Result<(), PaqueteError> {
+    let codigo_valido = !paquete.codigo.is_empty()
+        && paquete.codigo.len() <= 16
+        && paquete.codigo.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !codigo_valido {
+        return Err(PaqueteError::CodigoInvalido(paquete.codigo.clone()));
+    }
+    if let Some(respaldo) = &paquete.respaldo {
+        let primero = buscar(respaldo).ok_or_else(|| PaqueteError::RespaldoDesconocido(respaldo.clone()))?;
+        let mut cadena: &PaqueteIdioma = &primero;
+        loop {
+            if cadena.codigo == paquete.codigo {
+                return Err(PaqueteError::RespaldoCircular(respaldo.clone()));
+            }
+            match cadena.respaldo() {
+                Some(siguiente) => cadena = siguiente,
+                None => break,
+            }
+        }
+    }
+
+    let espanol = espanol();
+    for seccion in [Seccion::Gramatica, Seccion::Narrativa, Seccion::Mundo, Seccion::Procedural] {
+        for (clave, entradas) in paquete.banco(seccion) {
+            if entradas.is_empty() {
+                return Err(PaqueteError::BancoVacio(clave.clone()));
+            }
+            if let Some(referencia) = espanol.banco(seccion).get(clave) {
+                if entradas.iter().any(|e| e.forma() != referencia[0].forma()) {
+                    return Err(PaqueteError::FormaDistinta(clave.clone()));
+                }
+            }
+            for texto in entradas.iter().flat_map(Entrada::textos) {
+                for hueco in huecos(texto) {
+                    if paquete.lista(seccion, hueco).is_empty() {
+                        return Err(PaqueteError::HuecoSinBanco { clave: clave.clone(), hueco: hueco.to_string() });
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Nombres de los `{clave}` de un texto (no los `{0}` de parámetros)
+fn huecos(texto: &str) -> impl Iterator<Item = &str> {
+    texto.split('{').skip(1).filter_map(|resto| resto.find('}').map(|fin| &resto[..fin])).filter(|h| es_hueco(h))
+}
+
+fn es_hueco(nombre: &str) -> bool {
+    !nombre.is_empty()
+        && nombre.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+        && !nombre.chars().all(|c| c.is_ascii_digit())
+}
+
+fn espanol() -> Arc<PaqueteIdioma> {
+    buscar("es").expect("el paquete español siempre está registrado")
+}
+
+// ============================================================
+// CONSULTA
+// ============================================================
+
+impl PaqueteIdioma {
+    fn banco(&self, seccion: Seccion) -> &Banco {
+        match seccion {
+            Seccion::Gramatica => &self.gramatica,
+            Seccion::Narrativa => &self.narrativa,
+            Seccion::Mundo => &self.mundo,
+            Seccion::Procedural => &self.procedural,
+        }
+    }
+
+    fn respaldo(&self) -> Option<&PaqueteIdioma> {
+        self.respaldo_resuelto.as_deref()
+    }
+
+    /// Entradas de `clave`, siguiendo la cadena de respaldo; vacío si no existe
+    pub fn lista(&self, seccion: Seccion, clave: &str) -> &[Entrada] {
+        if let Some(entradas) = self.banco(seccion).get(clave) {
+            return entradas;
+        }
+        match self.respaldo() {
+            Some(respaldo) => respaldo.lista(seccion, clave),
+            None => &[],
+        }
+    }
+
+    /// Primera de las claves que exista
+    fn primera(&self, seccion: Seccion, claves: &[String]) -> &[Entrada] {
+        claves.iter().map(|c| self.lista(seccion, c)).find(|l| !l.is_empty()).unwrap_or(&[])
+    }
+
+    /// Texto fijo de `clave` (su primera entrada), con parámetros; no usa el rng
+    pub fn texto(&self, seccion: Seccion, clave: &str, params: &[&str]) -> String {
+        match self.lista(seccion, clave).first() {
+            Some(entrada) => sustituir(&entrada.textos()[0], params),
+            None => format!("[MISSING: {}]", clave),
+        }
+    }
+
+    /// Texto fijo de `clave.variante`, o de `clave` si el paquete no distingue esa variante
+    pub fn texto_de_variante(&self, seccion: Seccion, clave: &str, variante: &str, params: &[&str]) -> String {
+        let claves = [format!("{}.{}", clave, variante), clave.to_string()];
+        match self.primera(seccion, &claves).first() {
+            Some(entrada) => sustituir(&entrada.textos()[0], params),
+            None => format!("[MISSING: {}]", clave),
+        }
+    }
+
+    /// Campos fijos de `clave` (su primera entrada), con parámetros
+    pub fn campos_fijos(&self, seccion: Seccion, clave: &str, params: &[&str]) -> Vec<String> {
+        match self.lista(seccion, clave).first() {
+            Some(entrada) => entrada.textos().iter().map(|t| sustituir(t, params)).collect(),
+            None => vec![format!("[MISSING: {}]", clave)],
+        }
+    }
+
+    /// Una entrada al azar de `clave`, con los huecos expandidos y los parámetros puestos
+    pub fn frase(&self, seccion: Seccion, clave: &str, params: &[&str], rng: &mut impl Rng) -> String {
+        match elegir(self.lista(seccion, clave), rng) {
+            Some(entrada) => self.expandir(seccion, &entrada.textos()[0], params, rng, 0),
+            None => format!("[MISSING: {}]", clave),
+        }
+    }
+
+    /// Como [`frase`](Self::frase), con la variante del mundo si el paquete la tiene
+    pub fn frase_de_mundo(&self, seccion: Seccion, clave: &str, mundo: &Mundo, params: &[&str], rng: &mut impl Rng) -> String {
+        match elegir(self.primera(seccion, &claves_de_mundo(clave, mundo)), rng) {
+            Some(entrada) => self.expandir(seccion, &entrada.textos()[0], params, rng, 0),
+            None => format!("[MISSING: {}]", clave),
+        }
+    }
+
+    /// Campos de una entrada al azar de `clave` (o de su variante del mundo)
+    pub fn campos(&self, seccion: Seccion, clave: &str, mundo: Option<&Mundo>, params: &[&str], rng: &mut impl Rng) -> Vec<String> {
+        let entradas = match mundo {
+            Some(mundo) => self.primera(seccion, &claves_de_mundo(clave, mundo)),
+            None => self.lista(seccion, clave),
+        };
+        match elegir(entradas, rng) {
+            Some(entrada) => entrada.textos().iter().map(|t| self.expandir(seccion, t, params, rng, 0)).collect(),
+            None => vec![format!("[MISSING: {}]", clave)],
+        }
+    }
+
+    /// Campos de `n` entradas distintas al azar de `clave`, expandidos
+    pub fn varias(&self, seccion: Seccion, clave: &str, n: usize, params: &[&str], rng: &mut impl Rng) -> Vec<Vec<String>> {
+        let elegidas: Vec<&Entrada> = self.lista(seccion, clave).choose_multiple(rng, n).collect();
+        elegidas
+            .into_iter()
+            .map(|entrada| entrada.textos().iter().map(|t| self.expandir(seccion, t, params, rng, 0)).collect())
+            .collect()
+    }
+
+    /// Textos de `clave` (o de su variante del mundo), sin elegir ni expandir
+    pub fn textos_de_mundo(&self, seccion: Seccion, clave: &str, mundo: &Mundo) -> Vec<&str> {
+        self.primera(seccion, &claves_de_mundo(clave, mundo)).iter().map(Entrada::texto).collect()
+    }
+
+    fn expandir(&self, seccion: Seccion, texto: &str, params: &[&str], rng: &mut impl Rng, profundidad: usize) -> String {
+        let mut resultado = String::with_capacity(texto.len());
+        let mut resto = texto;
+        while let Some(inicio) = resto.find('{') {
+            resultado.push_str(&resto[..inicio]);
+            resto = &resto[inicio..];
+            let hueco = resto.find('}').map(|fin| &resto[1..fin]).filter(|h| es_hueco(h));
+            match hueco.and_then(|clave| Some(clave).zip(elegir(self.lista(seccion, clave), rng))) {
+                Some((clave, entrada)) if profundidad < PROFUNDIDAD_MAX => {
+                    resultado.push_str(&self.expandir(seccion, &entrada.textos()[0], &[], rng, profundidad + 1));
+                    resto = &resto[clave.len() + 2..];
+                }
+                _ => {
+                    resultado.push('{');
+                    resto = &resto[1..];
+                }
+            }
+        }
+        resultado.push_str(resto);
+        sustituir(&resultado, params)
+    }
+}
+
+/// Entrada al azar; con una sola no se consume el rng, así una plantilla
+/// fija no desplaza las elecciones de sus huecos
+fn elegir<'a>(entradas: &'a [Entrada], rng: &mut impl Rng) -> Option<&'a Entrada> {
+    match entradas {
+        [unica] => Some(unica),
+        _ => entradas.choose(rng),
+    }
+}
+
+fn sustituir(texto: &str, params: &[&str]) -> String {
+    let mut resultado = texto.to_string();
+    for (i, param) in params.iter().enumerate() {
+        resultado = resultado.replace(&format!("{{{}}}", i), param);
+    }
+    resultado
+}
+
+fn claves_de_mundo(clave: &str, mundo: &Mundo) -> [String; 2] {
+    // Un mundo propio usa los bancos del mundo integrado del que hereda
+    [format!("{}.{:?}", clave, mundo.base()), clave.to_string()]
+}
+
+impl Language {
+    /// Paquete con los bancos de frases del idioma
+    pub fn paquete(&self) -> Arc<PaqueteIdioma> {
+        buscar(self.codigo()).unwrap_or_else(espanol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn integrados_validos_y_con_la_forma_del_espanol() {
+        for (codigo, _) in INTEGRADOS {
+            let paquete = buscar(codigo).unwrap();
+            assert_eq!(paquete.codigo, codigo);
+            assert_eq!(validar(&paquete), Ok(()), "{}", codigo);
+        }
+        // Las claves con varios mundos se reparten
+        let es = espanol();
+        assert_eq!(es.lista(Seccion::Mundo, "eventos.Wuxia").len(), es.lista(Seccion::Mundo, "eventos.ChinaImperial").len());
+    }
+
+    #[test]
+    fn paquete_nuevo_se_apoya_en_su_respaldo() {
+        let pt = registrar(r#"{
+            "codigo": "pt-test",
+            "nombre": "Português",
+            "respaldo": "en",
+            "gramatica": {
+                "sujetos_causantes": ["Seu pai", "Sua mãe"],
+                "circunstancia": ["{sujetos_causantes} partiu {0}"]
+            }
+        }"#).unwrap();
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+
+        let frase = pt.frase(Seccion::Gramatica, "circunstancia", &["cedo"], &mut rng);
+        assert!(frase == "Seu pai partiu cedo" || frase == "Sua mãe partiu cedo", "{}", frase);
+        // Lo que no trae sale del inglés
+        assert_eq!(pt.texto(Seccion::Narrativa, "titulo_origen", &[]), buscar("en").unwrap().texto(Seccion::Narrativa, "titulo_origen", &[]));
+        assert_eq!(Language::from_str("pt-test").paquete().nombre, "Português");
+
+        assert!(matches!(
+            registrar(r#"{"codigo": "x1", "nombre": "X", "gramatica": {"mentira": ["sin campos"]}}"#),
+            Err(PaqueteError::FormaDistinta(_))
+        ));
+        assert!(matches!(
+            registrar(r#"{"codigo": "x2", "nombre": "X", "narrativa": {"a": ["{no_existe}"]}}"#),
+            Err(PaqueteError::HuecoSinBanco { .. })
+        ));
+        assert_eq!(registrar(r#"{"codigo": "en", "nombre": "X"}"#).unwrap_err(), PaqueteError::CodigoReservado("en".into()));
+    }
+
+    #[test]
+    fn volver_a_registrar_libera_el_anterior() {
+        let json = r#"{"codigo": "test-recarga", "nombre": "X", "gramatica": {"sujetos_causantes": ["a"]}}"#;
+        let anterior = Arc::downgrade(&registrar(json).unwrap());
+        let antes = CodigoIdioma::buscar("test-recarga").unwrap();
+        registrar(&json.replace("[\"a\"]", "[\"b\"]")).unwrap();
+        let despues = CodigoIdioma::buscar("test-recarga").unwrap();
+
+        assert!(anterior.upgrade().is_none());
+        assert!(std::ptr::eq(antes.as_str(), despues.as_str()));
+        assert_eq!(Language::Paquete(despues).paquete().texto(Seccion::Gramatica, "sujetos_causantes", &[]), "b");
+    }
+}
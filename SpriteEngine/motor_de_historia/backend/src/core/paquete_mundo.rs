@@ -0,0 +1,329 @@
+//! Paquetes de mundo: escenarios propios más allá de los mundos integrados.
+//!
+//! Un paquete es un JSON que hereda de un mundo integrado (`base`) y puede
+//! traer sus propias listas de nombres, apellidos, lugares de origen,
+//! eventos catalizadores, elementos temáticos y ganchos (`{0}` = nombre del
+//! personaje). Cada lista que falte, y todo lo demás que dependa del mundo
+//! (sombras, vestimenta de constelaciones, encuentros...), sale de la base
+//! en el idioma pedido. Los textos propios se usan tal cual en cualquier
+//! idioma: un mundo de campaña se escribe en el idioma de la campaña.
+//!
+//! Los paquetes se cargan de un directorio al arrancar (ver
+//! [`cargar_directorio`]) y se generan como `Mundo::Custom(id)`, que se
+//! serializa como el id a secas (`"mundo": "islas-de-ceniza"`).
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::Path;
+use std::sync::{Arc, Mutex, RwLock};
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::Mundo;
+
+static REGISTRO: Lazy<RwLock<BTreeMap<String, Arc<PaqueteMundo>>>> = Lazy::new(Default::default);
+
+/// Ids de mundo ya vistos. Cada id se guarda una sola vez, por muchas veces
+/// que se vuelva a registrar su paquete
+static IDS: Lazy<Mutex<BTreeSet<&'static str>>> = Lazy::new(Default::default);
+
+// ============================================================
+// TIPOS
+// ============================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaqueteMundo {
+    pub id: String,
+    pub nombre: String,
+    /// Mundo integrado del que se hereda lo que el paquete no traiga
+    pub base: Mundo,
+    #[serde(default)]
+    pub nombres_masculinos: Vec<String>,
+    #[serde(default)]
+    pub nombres_femeninos: Vec<String>,
+    #[serde(default)]
+    pub apellidos: Vec<String>,
+    #[serde(default)]
+    pub lugares: Vec<String>,
+    #[serde(default)]
+    pub eventos: Vec<String>,
+    #[serde(default)]
+    pub elementos: Vec<String>,
+    #[serde(default)]
+    pub ganchos: Vec<String>,
+}
+
+/// Identificador de un mundo que llega por paquete. Solo se construye para
+/// mundos registrados
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IdMundo(&'static str);
+
+impl IdMundo {
+    /// Identificador de un mundo registrado, si lo hay
+    pub fn buscar(id: &str) -> Option<Self> {
+        buscar(id).map(|p| IdMundo(internar(&p.id)))
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+
+    /// Paquete registrado ahora mismo con este id
+    pub fn paquete(&self) -> Result<Arc<PaqueteMundo>, PaqueteMundoError> {
+        buscar(self.0).ok_or_else(|| PaqueteMundoError::NoRegistrado(self.0.to_string()))
+    }
+}
+
+fn internar(id: &str) -> &'static str {
+    let mut ids = IDS.lock().unwrap();
+    match ids.get(id) {
+        Some(id) => id,
+        None => {
+            let id: &'static str = Box::leak(id.into());
+            ids.insert(id);
+            id
+        }
+    }
+}
+
+impl fmt::Debug for IdMundo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl Serialize for IdMundo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for IdMundo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = String::deserialize(deserializer)?;
+        IdMundo::buscar(&id).ok_or_else(|| serde::de::Error::custom(PaqueteMundoError::NoRegistrado(id)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaqueteMundoError {
+    Json(String),
+    /// Los ids son minúsculas, dígitos o '-', hasta 32 caracteres
+    IdInvalido(String),
+    /// La base tiene que ser un mundo integrado, no otro paquete
+    BaseNoIntegrada(String),
+    /// El paquete no trae ninguna lista: sería su base con otro nombre
+    SinContenido(String),
+    /// Texto vacío en la lista `campo`
+    TextoVacio(String),
+    /// Un gancho usa un hueco que no es `{0}`
+    HuecoDesconocido { gancho: String },
+    Archivo { ruta: String, error: Box<PaqueteMundoError> },
+    /// Ningún paquete registrado tiene ese id
+    NoRegistrado(String),
+}
+
+impl fmt::Display for PaqueteMundoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaqueteMundoError::Json(e) => write!(f, "JSON inválido: {}", e),
+            PaqueteMundoError::IdInvalido(id) => write!(f, "'{}' no es un id de mundo válido", id),
+            PaqueteMundoError::BaseNoIntegrada(id) => {
+                write!(f, "El mundo '{}' tiene que heredar de un mundo integrado", id)
+            }
+            PaqueteMundoError::SinContenido(id) => write!(f, "El mundo '{}' no trae ninguna lista propia", id),
+            PaqueteMundoError::TextoVacio(campo) => write!(f, "La lista '{}' tiene un texto vacío", campo),
+            PaqueteMundoError::HuecoDesconocido { gancho } => {
+                write!(f, "El gancho '{}' solo puede usar {{0}} (el nombre)", gancho)
+            }
+            PaqueteMundoError::Archivo { ruta, error } => write!(f, "{}: {}", ruta, error),
+            PaqueteMundoError::NoRegistrado(id) => write!(f, "No hay paquete para el mundo '{}'", id),
+        }
+    }
+}
+
+impl std::error::Error for PaqueteMundoError {}
+
+// ============================================================
+// REGISTRO
+// ============================================================
+
+/// Paquete de un mundo registrado
+pub fn buscar(id: &str) -> Option<Arc<PaqueteMundo>> {
+    REGISTRO.read().unwrap().get(id).cloned()
+}
+
+/// Mundos propios disponibles: (id, nombre, base)
+pub fn disponibles() -> Vec<(String, String, Mundo)> {
+    REGISTRO.read().unwrap().values().map(|p| (p.id.clone(), p.nombre.clone(), p.base)).collect()
+}
+
+/// Valida y registra un paquete. Uno con el id de otro ya cargado lo reemplaza
+pub fn registrar(json: &str) -> Result<Arc<PaqueteMundo>, PaqueteMundoError> {
+    let paquete: PaqueteMundo = serde_json::from_str(json).map_err(|e| PaqueteMundoError::Json(e.to_string()))?;
+    validar(&paquete)?;
+
+    let paquete = Arc::new(paquete);
+    REGISTRO.write().unwrap().insert(paquete.id.clone(), Arc::clone(&paquete));
+    Ok(paquete)
+}
+
+/// Registra todos los `*.json` de `dir` y devuelve sus ids. Un directorio
+/// que no existe no es un error: no hay mundos propios
+pub fn cargar_directorio(dir: &Path) -> Result<Vec<String>, PaqueteMundoError> {
+    let Ok(lectura) = std::fs::read_dir(dir) else {
+        return Ok(Vec::new());
+    };
+    let mut rutas: Vec<_> = lectura
+        .flatten()
+        .map(|e| e.path())
+        .filter(|r| r.extension().is_some_and(|e| e == "json"))
+        .collect();
+    rutas.sort();
+
+    rutas
+        .into_iter()
+        .map(|ruta| {
+            let en_archivo = |error| PaqueteMundoError::Archivo { ruta: ruta.display().to_string(), error: Box::new(error) };
+            let json = std::fs::read_to_string(&ruta).map_err(|e| en_archivo(PaqueteMundoError::Json(e.to_string())))?;
+            registrar(&json).map(|p| p.id.clone()).map_err(en_archivo)
+        })
+        .collect()
+}
+
+fn validar(paquete: &PaqueteMundo) -> Result<(), PaqueteMundoError> {
+    let id_valido = !paquete.id.is_empty()
+        && paquete.id.len() <= 32
+        && paquete.id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !id_valido {
+        return Err(PaqueteMundoError::IdInvalido(paquete.id.clone()));
+    }
+    if matches!(paquete.base, Mundo::Custom(_)) {
+        return Err(PaqueteMundoError::BaseNoIntegrada(paquete.id.clone()));
+    }
+    if paquete.listas().iter().all(|(_, lista)| lista.is_empty()) {
+        return Err(PaqueteMundoError::SinContenido(paquete.id.clone()));
+    }
+    for (campo, lista) in paquete.listas() {
+        if lista.iter().any(|t| t.trim().is_empty()) {
+            return Err(PaqueteMundoError::TextoVacio(campo.to_string()));
+        }
+    }
+    if let Some(gancho) = paquete.ganchos.iter().find(|g| g.replace("{0}", "").contains(['{', '}'])) {
+        return Err(PaqueteMundoError::HuecoDesconocido { gancho: gancho.clone() });
+    }
+    Ok(())
+}
+
+// ============================================================
+// CONSULTA
+// ============================================================
+
+impl PaqueteMundo {
+    fn listas(&self) -> [(&'static str, &Vec<String>); 7] {
+        [
+            ("nombres_masculinos", &self.nombres_masculinos),
+            ("nombres_femeninos", &self.nombres_femeninos),
+            ("apellidos", &self.apellidos),
+            ("lugares", &self.lugares),
+            ("eventos", &self.eventos),
+            ("elementos", &self.elementos),
+            ("ganchos", &self.ganchos),
+        ]
+    }
+}
+
+/// Lista propia de `mundo` si es un mundo propio que la trae; si no (mundo
+/// integrado, lista vacía o paquete ya no registrado), la de su base
+pub fn propia(mundo: &Mundo, lista: impl Fn(&PaqueteMundo) -> &Vec<String>, integrada: Vec<&str>) -> Vec<String> {
+    let paquete = match mundo {
+        Mundo::Custom(id) => id.paquete().ok(),
+        _ => None,
+    };
+    match paquete.as_deref().map(lista) {
+        Some(lista) if !lista.is_empty() => lista.clone(),
+        _ => integrada.into_iter().map(String::from).collect(),
+    }
+}
+
+impl Mundo {
+    /// Mundo integrado del que sale todo lo que no defina un mundo propio;
+    /// el propio mundo si ya es integrado. Un mundo propio cuyo paquete ya
+    /// no está registrado cae en el mundo por defecto
+    pub fn base(&self) -> Mundo {
+        match self {
+            Mundo::Custom(id) => id.paquete().map_or(Mundo::FantasiaMedieval, |p| p.base),
+            integrado => *integrado,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Language, ParametrosGeneracion, SoulForge};
+
+    #[test]
+    fn mundo_propio_sustituye_listas_y_hereda_el_resto() {
+        let paquete = registrar(r#"{
+            "id": "test-islas",
+            "nombre": "Islas de Ceniza",
+            "base": "PiratasCaribe",
+            "nombres_masculinos": ["Oren"],
+            "nombres_femeninos": ["Ysolde"],
+            "lugares": ["un atolón de ceniza"],
+            "ganchos": ["{0} lleva un mapa tatuado en la espalda", "Los volcanes callan cuando {0} canta"]
+        }"#).unwrap();
+        let mundo = Mundo::Custom(IdMundo::buscar(&paquete.id).unwrap());
+        assert_eq!(mundo.base(), Mundo::PiratasCaribe);
+
+        let alma = SoulForge::con_semilla(7).forjar(ParametrosGeneracion {
+            semilla: Some(7),
+            mundo: Some(mundo),
+            idioma: Some(Language::English),
+            ..Default::default()
+        });
+        assert!(["Oren", "Ysolde"].contains(&alma.identidad.nombre.as_str()));
+        // Dos ganchos del mundo y dos genéricos, de los que quedan tres
+        assert!(alma.ganchos_narrativos.iter().any(|g| g.contains("mapa tatuado") || g.contains("volcanes")));
+
+        // El alma viaja con su mundo propio y vuelve a leerse
+        let json = serde_json::to_value(&alma).unwrap();
+        assert_eq!(json["mundo"], "test-islas");
+        assert_eq!(serde_json::from_value::<crate::core::Alma>(json).unwrap().mundo, mundo);
+    }
+
+    #[test]
+    fn paquetes_invalidos_se_rechazan_al_cargar() {
+        let error = |json: &str| registrar(json).unwrap_err();
+        assert_eq!(error(r#"{"id": "Mal Id", "nombre": "x", "base": "Noir", "lugares": ["a"]}"#),
+                   PaqueteMundoError::IdInvalido("Mal Id".into()));
+        assert_eq!(error(r#"{"id": "vacio", "nombre": "x", "base": "Noir"}"#),
+                   PaqueteMundoError::SinContenido("vacio".into()));
+        assert_eq!(error(r#"{"id": "hueco", "nombre": "x", "base": "Noir", "ganchos": ["{0} y {1}"]}"#),
+                   PaqueteMundoError::HuecoDesconocido { gancho: "{0} y {1}".into() });
+        assert!(matches!(error(r#"{"id": "typo", "nombre": "x", "base": "Noir", "lugar": ["a"]}"#),
+                         PaqueteMundoError::Json(_)));
+
+        registrar(r#"{"id": "test-base", "nombre": "x", "base": "Noir", "lugares": ["a"]}"#).unwrap();
+        assert_eq!(error(r#"{"id": "nieto", "nombre": "x", "base": "test-base", "lugares": ["a"]}"#),
+                   PaqueteMundoError::BaseNoIntegrada("nieto".into()));
+        assert!(buscar("nieto").is_none());
+    }
+
+    #[test]
+    fn volver_a_registrar_reutiliza_el_id() {
+        let json = r#"{"id": "test-recarga", "nombre": "x", "base": "Noir", "lugares": ["a"]}"#;
+        registrar(json).unwrap();
+        let antes = IdMundo::buscar("test-recarga").unwrap();
+        registrar(&json.replace("\"a\"", "\"b\"")).unwrap();
+        let despues = IdMundo::buscar("test-recarga").unwrap();
+
+        assert!(std::ptr::eq(antes.as_str(), despues.as_str()));
+        assert_eq!(antes.paquete().unwrap().lugares, ["b"]);
+        assert_eq!(IdMundo(internar("test-sin-paquete")).paquete().unwrap_err(),
+                   PaqueteMundoError::NoRegistrado("test-sin-paquete".into()));
+    }
+}
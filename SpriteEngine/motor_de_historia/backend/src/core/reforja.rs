@@ -0,0 +1,196 @@
+//! Reforja parcial: regenerar partes elegidas de un alma sin tocar el resto.
+//!
+//! Las partes que dependen de una regenerada se rehacen también (la máscara,
+//! el deseo, los vínculos y la mentira salen de la herida; el arco, de la
+//! mentira...). El resultado dice qué se pidió y qué cambió por arrastre.
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Alma, Arquetipo, ArcoNarrativo, CapaVinculos, DeseoNecesidad, Herida, Identidad, Language,
+    Mascara, Mentira, MotorBiografia, Sombra,
+};
+
+/// Parte regenerable de un alma. El orden de declaración es el de
+/// generación: cada parte solo depende de partes anteriores
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ParteAlma {
+    Identidad,
+    Arquetipo,
+    Herida,
+    Mascara,
+    DeseoNecesidad,
+    Sombra,
+    Vinculos,
+    Mentira,
+    Arco,
+    Ganchos,
+    Momentos,
+    Biografia,
+    Habilidades,
+}
+
+impl ParteAlma {
+    /// Partes que se calculan directamente a partir de esta
+    pub fn dependientes(&self) -> &'static [ParteAlma] {
+        use ParteAlma::*;
+        match self {
+            Identidad => &[Ganchos, Biografia, Habilidades],
+            Herida => &[Mascara, DeseoNecesidad, Vinculos, Mentira, Ganchos, Biografia, Habilidades],
+            Mascara | Sombra => &[Momentos],
+            Mentira => &[Arco, Ganchos, Biografia],
+            Arquetipo | DeseoNecesidad | Vinculos | Arco | Ganchos | Momentos | Biografia | Habilidades => &[],
+        }
+    }
+}
+
+/// Resultado de una reforja
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reforja {
+    pub alma: Alma,
+    /// Semilla de la reforja: con el alma original y las mismas partes la reproduce
+    pub semilla: u64,
+    pub pedidas: Vec<ParteAlma>,
+    /// Partes que no se pidieron pero cambiaron por depender de una pedida
+    pub derivadas: Vec<ParteAlma>,
+}
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Regenera `partes` (y todo lo que depende de ellas) con `semilla`, en
+/// `idioma` (el alma no lo guarda: es el idioma en que se pidió).
+/// El id, la semilla original, el rol, el tono y el mundo se conservan
+pub fn reforjar(alma: &Alma, partes: &[ParteAlma], semilla: u64, idioma: &Language) -> Reforja {
+    let mut pedidas: Vec<ParteAlma> = partes.iter().copied().filter(|p| aplica(alma, *p)).collect();
+    pedidas.sort();
+    pedidas.dedup();
+
+    let mut afectadas = pedidas.clone();
+    let mut i = 0;
+    while i < afectadas.len() {
+        for dep in afectadas[i].dependientes() {
+            if aplica(alma, *dep) && !afectadas.contains(dep) {
+                afectadas.push(*dep);
+            }
+        }
+        i += 1;
+    }
+    afectadas.sort();
+
+    let mut rng = ChaCha8Rng::seed_from_u64(semilla);
+    let mut nueva = alma.clone();
+    for parte in &afectadas {
+        regenerar_parte(&mut rng, &mut nueva, *parte, idioma);
+    }
+
+    let derivadas = afectadas.into_iter().filter(|p| !pedidas.contains(p)).collect();
+    Reforja { alma: nueva, semilla, pedidas, derivadas }
+}
+
+// ============================================================
+// INTERNOS
+// ============================================================
+
+/// Las habilidades solo existen en almas con tier (rol Jugador)
+fn aplica(alma: &Alma, parte: ParteAlma) -> bool {
+    parte != ParteAlma::Habilidades || alma.soul_tier.is_some()
+}
+
+fn regenerar_parte(rng: &mut ChaCha8Rng, alma: &mut Alma, parte: ParteAlma, idioma: &Language) {
+    let capas = &mut alma.capas;
+
+    match parte {
+        ParteAlma::Identidad => {
+            let mut identidad = Identidad::generar(rng, &alma.mundo, Some(alma.identidad.genero), None, idioma);
+            if let Some(ficha) = &alma.ficha_tecnica {
+                identidad.anotar_raza(ficha.raza, idioma);
+            }
+            alma.identidad = identidad;
+        }
+        ParteAlma::Arquetipo => capas.arquetipo = Arquetipo::generar(rng, idioma),
+        ParteAlma::Herida => capas.herida = Herida::generar(rng, &alma.mundo, idioma),
+        ParteAlma::Mascara => capas.mascara = Mascara::generar(rng, &capas.herida, idioma),
+        ParteAlma::DeseoNecesidad => capas.deseo_necesidad = DeseoNecesidad::generar(rng, &capas.herida, idioma),
+        ParteAlma::Sombra => capas.sombra = Sombra::generar(rng, idioma),
+        ParteAlma::Vinculos => capas.vinculos = CapaVinculos::generar(rng, &capas.herida, idioma),
+        ParteAlma::Mentira => capas.mentira = Mentira::generar(rng, &capas.herida, idioma),
+        ParteAlma::Arco => alma.arco = ArcoNarrativo::generar(rng, &alma.rol, &alma.tono_moral, &capas.mentira, idioma),
+        ParteAlma::Ganchos => {
+            alma.ganchos_narrativos = Alma::generar_ganchos(rng, capas, idioma, &alma.mundo, &alma.identidad.nombre);
+        }
+        ParteAlma::Momentos => alma.momentos_definitorios = Alma::generar_momentos(rng, capas, idioma),
+        ParteAlma::Biografia => {
+            alma.biografia = MotorBiografia::generar(
+                rng,
+                &alma.identidad.nombre,
+                capas,
+                &alma.mundo,
+                &alma.rol,
+                &alma.tono_moral,
+                idioma,
+                Some(alma.identidad.edad),
+            );
+        }
+        ParteAlma::Habilidades => {
+            if let Some(tier) = alma.soul_tier {
+                alma.skills = Alma::generar_habilidades(rng, &alma.rol, tier, capas, alma.identidad.edad);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ParametrosGeneracion, Rol, SoulForge};
+
+    fn json<T: Serialize>(valor: &T) -> serde_json::Value {
+        serde_json::to_value(valor).unwrap()
+    }
+
+    #[test]
+    fn reforjar_herida_arrastra_dependientes() {
+        let alma = SoulForge::nuevo().forjar(ParametrosGeneracion {
+            semilla: Some(99),
+            rol: Some(Rol::Jugador),
+            ..Default::default()
+        });
+        let r = reforjar(&alma, &[ParteAlma::Herida], 7, &Language::Espanol);
+
+        assert_eq!(r.pedidas, vec![ParteAlma::Herida]);
+        assert_eq!(r.derivadas, vec![
+            ParteAlma::Mascara, ParteAlma::DeseoNecesidad, ParteAlma::Vinculos, ParteAlma::Mentira,
+            ParteAlma::Arco, ParteAlma::Ganchos, ParteAlma::Momentos, ParteAlma::Biografia,
+            ParteAlma::Habilidades,
+        ]);
+        // Lo bloqueado queda idéntico
+        assert_eq!(r.alma.id, alma.id);
+        assert_eq!(json(&r.alma.identidad), json(&alma.identidad));
+        assert_eq!(json(&r.alma.capas.arquetipo), json(&alma.capas.arquetipo));
+        assert_eq!(json(&r.alma.capas.sombra), json(&alma.capas.sombra));
+        // La máscara se rehízo sobre la herida nueva
+        assert_eq!(r.alma.capas.mascara.trigger_que_la_rompe, r.alma.capas.herida.gatillo_emocional);
+        // Reproducible
+        assert_eq!(json(&reforjar(&alma, &[ParteAlma::Herida], 7, &Language::Espanol).alma), json(&r.alma));
+    }
+
+    #[test]
+    fn reforjar_hoja_no_arrastra_nada() {
+        let alma = SoulForge::nuevo().forjar(ParametrosGeneracion {
+            semilla: Some(5),
+            idioma: Some(Language::English),
+            ..Default::default()
+        });
+        let r = reforjar(&alma, &[ParteAlma::Arquetipo, ParteAlma::Habilidades], 1, &Language::English);
+        assert_eq!(r.pedidas, vec![ParteAlma::Arquetipo]);
+        let arquetipo = &r.alma.capas.arquetipo;
+        assert_eq!(arquetipo.manifestacion_luz, Arquetipo::get_text(&arquetipo.tipo, &Language::English).0);
+        assert!(r.derivadas.is_empty());
+        assert_eq!(json(&r.alma.capas.herida), json(&alma.capas.herida));
+        assert_eq!(json(&r.alma.biografia), json(&alma.biografia));
+    }
+}
@@ -16,7 +16,7 @@
 
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use super::procedural_text::ProceduralTextGenerator;
 
 /// ═══════════════════════════════════════════════════════════════════════════════
@@ -44,8 +44,8 @@ impl TargetLanguage {
 pub struct LiteraryAdapter {
     rng: ChaCha8Rng,
     // Diccionarios de transformación
-    es_to_en: HashMap<&'static str, Vec<&'static str>>,
-    es_to_jp: HashMap<&'static str, Vec<&'static str>>,
+    es_to_en: BTreeMap<&'static str, Vec<&'static str>>,
+    es_to_jp: BTreeMap<&'static str, Vec<&'static str>>,
     // Templates de frases literarias
     english_templates: LiteraryTemplates,
     japanese_templates: LiteraryTemplates,
@@ -77,8 +77,8 @@ impl LiteraryAdapter {
     pub fn with_seed(seed: u64) -> Self {
         let mut adapter = Self {
             rng: ChaCha8Rng::seed_from_u64(seed),
-            es_to_en: HashMap::new(),
-            es_to_jp: HashMap::new(),
+            es_to_en: BTreeMap::new(),
+            es_to_jp: BTreeMap::new(),
             english_templates: LiteraryTemplates::default(),
             japanese_templates: LiteraryTemplates::default(),
         };
@@ -693,7 +693,7 @@ impl LiteraryAdapter {
         
         // SIEMPRE usar el generador procedural si el texto aún parece español (no japonés)
         // Detección: si no contiene caracteres japoneses y no fue transformado, es español
-        let has_japanese = result.chars().any(|c| c >= '\u{3000}' && c <= '\u{9FFF}');
+        let has_japanese = result.chars().any(|c| ('\u{3000}'..='\u{9FFF}').contains(&c));
         let still_spanish = !transformed || !has_japanese;
         
         if still_spanish {
@@ -764,49 +764,3 @@ pub fn adapt_text(spanish_text: &str, lang_code: &str, seed: u64) -> String {
     let mut adapter = LiteraryAdapter::with_seed(seed);
     adapter.adapt(spanish_text, target)
 }
-
-/// ═══════════════════════════════════════════════════════════════════════════════
-/// Tests
-/// ═══════════════════════════════════════════════════════════════════════════════
-#[cfg(test)]
-mod tests {
-    use super::*;
-    
-    #[test]
-    fn test_language_detection() {
-        assert!(matches!(TargetLanguage::from_code("en"), Some(TargetLanguage::English)));
-        assert!(matches!(TargetLanguage::from_code("jp"), Some(TargetLanguage::Japanese)));
-        assert!(TargetLanguage::from_code("es").is_none());
-    }
-    
-    #[test]
-    fn test_deterministic_output() {
-        let text = "Nunca pudo perdonarse por lo que hizo";
-        let result1 = adapt_text(text, "en", 12345);
-        let result2 = adapt_text(text, "en", 12345);
-        assert_eq!(result1, result2, "Same seed should produce same output");
-    }
-    
-    #[test]
-    fn test_english_adaptation() {
-        let text = "Arrastra una culpa que nunca logró nombrar";
-        let result = adapt_text(text, "en", 42);
-        // Should contain English phrases, not Spanish
-        assert!(!result.contains("Arrastra") || result != text);
-    }
-    
-    #[test]
-    fn test_japanese_adaptation() {
-        let text = "Nunca pudo perdonarse";
-        let result = adapt_text(text, "jp", 42);
-        // Should contain Japanese characters
-        assert!(result.chars().any(|c| c > '\u{3000}'));
-    }
-    
-    #[test]
-    fn test_spanish_passthrough() {
-        let text = "Este es texto en español";
-        let result = adapt_text(text, "es", 42);
-        assert_eq!(result, text, "Spanish should pass through unchanged");
-    }
-}
@@ -0,0 +1,38 @@
+//! Generador congelado de la versión 1
+//!
+//! Copia literal del motor de almas tal como estaba cuando `VERSION_GENERADOR`
+//! valía 1 (bancos de frases en el código y adaptación posterior a EN/JP).
+//! Existe solo para que los códigos de personaje de la versión 1 sigan
+//! reproduciendo el mismo personaje: no se toca, aunque el motor actual
+//! cambie. Cualquier cambio aquí rompe `tests/golden/v1`.
+
+// Código congelado: ni se limpia ni se corrige, solo se conserva
+#![allow(dead_code)]
+
+use rand::Rng;
+
+use super::{
+    uuid_desde_semilla, DndStats, ForgeConfig, Genero, Language, Mundo, NivelConflicto,
+    ParametrosGeneracion, Raza, Rol, TonoMoral,
+};
+
+mod adapter;
+mod alma;
+mod arco;
+mod biografia;
+mod capas;
+mod gramatica;
+mod identidad;
+mod mundo_narrativo;
+mod narrativa;
+mod procedural_text;
+
+use capas::SietCapas;
+
+/// Forja un alma con el generador de la versión 1
+pub fn forjar(rng: &mut impl Rng, params: ParametrosGeneracion, config: &ForgeConfig) -> super::Alma {
+    let alma = alma::Alma::generar(rng, params, config);
+    // Los tipos congelados serializan igual que los actuales
+    serde_json::from_value(serde_json::to_value(alma).expect("alma v1 serializable"))
+        .expect("alma v1 compatible con Alma")
+}
@@ -14,6 +14,13 @@ use crate::handlers::{ws_handler, create_room_handler, room_info_handler};
 
 // Importar librería de generación
 use soulforge_server::{SoulForge, ParametrosGeneracion, ParametrosConstelacion, Mundo, Rol, TonoMoral, Language};
+use soulforge_server::core::{codigo, paquete_idioma, paquete_mundo, Alma, Raza};
+use soulforge_server::core::reforja::ParteAlma;
+use soulforge_server::core::items::{ItemGenerator, ItemType};
+use soulforge_server::cartographer::parser::BackstoryParser;
+use soulforge_server::cartographer::world_generator::{WorldConfig, WorldGenerator};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
 
 #[derive(Debug, Deserialize)]
 struct CharacterQuery {
@@ -24,6 +31,8 @@ struct CharacterQuery {
     tono: Option<String>,
     edad: Option<u32>,
     lang: Option<String>,
+    raza: Option<String>,
+    semilla: Option<u64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -32,6 +41,36 @@ struct ConstellationQuery {
     mundo: Option<String>,
     tono: Option<String>,
     lang: Option<String>,
+    semilla: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReforjaRequest {
+    alma: soulforge_server::Alma,
+    partes: Vec<ParteAlma>,
+    semilla: Option<u64>,
+    /// Idioma en que se forjó el alma ("es" si no se indica)
+    lang: Option<String>,
+}
+
+/// `?semilla=` de las rutas de objetos y mapas
+#[derive(Debug, Deserialize)]
+struct SemillaQuery {
+    semilla: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ForjaObjetoRequest {
+    item_type: String, // "weapon", "armor", etc.
+    quality: u8,
+    skill: u8,
+}
+
+#[derive(Debug, Deserialize)]
+struct MapaRequest {
+    characters: Vec<serde_json::Value>,
+    width: Option<u32>,
+    height: Option<u32>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -63,7 +102,7 @@ fn parse_mundo(s: &str) -> Mundo {
         "piratascaribe" | "piratas" => Mundo::PiratasCaribe,
         "victoriano" => Mundo::Victoriano,
         "realista" => Mundo::Realista,
-        _ => Mundo::FantasiaMedieval,
+        otro => paquete_mundo::IdMundo::buscar(otro).map_or(Mundo::FantasiaMedieval, Mundo::Custom),
     }
 }
 
@@ -99,8 +138,6 @@ fn parse_genero(s: &str) -> Option<soulforge_server::Genero> {
 }
 
 async fn generate_character_handler(query: CharacterQuery) -> Result<impl warp::Reply, warp::Rejection> {
-    let mut forge = SoulForge::nuevo();
-    
     let params = ParametrosGeneracion {
         nombre_fijo: query.nombre,
         mundo: query.mundo.as_deref().map(parse_mundo),
@@ -109,27 +146,76 @@ async fn generate_character_handler(query: CharacterQuery) -> Result<impl warp::
         edad_fija: query.edad,
         genero: query.genero.as_deref().and_then(parse_genero),
         idioma: query.lang.as_deref().map(Language::from_str),
+        raza: query.raza.as_deref().and_then(Raza::from_str),
+        // La semilla se fija aquí para poder devolver el código del personaje
+        semilla: Some(query.semilla.unwrap_or_else(rand::random)),
         ..Default::default()
     };
     
-    let alma = forge.forjar(params);
+    let codigo = codigo::codificar(&params).ok();
+    Ok(warp::reply::json(&responder_personaje(SoulForge::nuevo().forjar(params), codigo)))
+}
+
+/// Enriquece el alma y adjunta su código compartible (`null` si no cabe en uno)
+fn responder_personaje(alma: Alma, codigo: Option<String>) -> serde_json::Value {
 
     // Convertir a serde_json::Value para pasar al enriquecedor
     let json_val = serde_json::to_value(&alma).unwrap_or_default();
 
     // Enriquecer con IA (si está disponible)
     use soulforge_server::core::ia_integration::enriquecer_personaje;
-    let enhanced_json = enriquecer_personaje(json_val);
+    let mut enhanced_json = enriquecer_personaje(json_val);
+    if let Some(obj) = enhanced_json.as_object_mut() {
+        obj.insert("codigo".to_string(), serde_json::json!(codigo));
+    }
+    enhanced_json
+}
+
+fn error_de_codigo(error: codigo::CodigoError) -> warp::reply::WithStatus<warp::reply::Json> {
+    warp::reply::with_status(
+        warp::reply::json(&serde_json::json!({ "error": error.clave(), "mensaje": error.to_string() })),
+        warp::http::StatusCode::BAD_REQUEST,
+    )
+}
+
+async fn character_from_code_handler(codigo_personaje: String) -> Result<impl warp::Reply, warp::Rejection> {
+    // Cada código se regenera con la versión del generador que lo creó
+    Ok(match codigo::regenerar(&codigo_personaje) {
+        Ok(alma) => warp::reply::with_status(
+            warp::reply::json(&responder_personaje(alma, Some(codigo_personaje.trim().to_string()))),
+            warp::http::StatusCode::OK,
+        ),
+        Err(e) => error_de_codigo(e),
+    })
+}
+
+async fn decode_code_handler(codigo_personaje: String) -> Result<impl warp::Reply, warp::Rejection> {
+    Ok(match codigo::decodificar(&codigo_personaje) {
+        Ok(decodificado) => warp::reply::with_status(
+            warp::reply::json(&decodificado),
+            warp::http::StatusCode::OK,
+        ),
+        Err(e) => error_de_codigo(e),
+    })
+}
+
+async fn reforge_character_handler(req: ReforjaRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let semilla = req.semilla.unwrap_or_else(rand::random);
+    let idioma = req.lang.as_deref().map_or(Language::Espanol, Language::from_str);
+    let reforja = SoulForge::nuevo().reforjar(&req.alma, &req.partes, semilla, &idioma);
     
-    Ok(warp::reply::json(&enhanced_json))
+    Ok(warp::reply::json(&reforja))
 }
 
 async fn generate_constellation_handler(query: ConstellationQuery) -> Result<impl warp::Reply, warp::Rejection> {
     let mut forge = SoulForge::nuevo();
     
     let params = ParametrosConstelacion {
+        semilla: query.semilla,
         cantidad: query.num_personajes.unwrap_or(4),
         mundo: query.mundo.as_deref().map(parse_mundo).unwrap_or(Mundo::FantasiaMedieval),
+        tono: query.tono.as_deref().map(parse_tono),
+        idioma: query.lang.as_deref().map(Language::from_str),
         ..Default::default()
     };
     
@@ -138,6 +224,42 @@ async fn generate_constellation_handler(query: ConstellationQuery) -> Result<imp
     Ok(warp::reply::json(&constelacion))
 }
 
+/// Sin `?semilla=` se sortea una y se devuelve, para poder repetir el resultado
+fn semilla_de(query: &SemillaQuery) -> u64 {
+    query.semilla.unwrap_or_else(rand::random)
+}
+
+async fn loot_handler(query: SemillaQuery) -> Result<impl warp::Reply, warp::Rejection> {
+    let semilla = semilla_de(&query);
+    let objeto = ItemGenerator::generate_loot(&mut ChaCha8Rng::seed_from_u64(semilla), None);
+    Ok(warp::reply::json(&serde_json::json!({ "semilla": semilla, "objeto": objeto })))
+}
+
+async fn forge_item_handler(query: SemillaQuery, req: ForjaObjetoRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let tipo = match req.item_type.to_lowercase().as_str() {
+        "weapon" => ItemType::Weapon,
+        "armor" => ItemType::Armor,
+        "trinket" => ItemType::Trinket,
+        "potion" => ItemType::Potion,
+        _ => ItemType::Material,
+    };
+    let semilla = semilla_de(&query);
+    let objeto = ItemGenerator::forge(&mut ChaCha8Rng::seed_from_u64(semilla), tipo, req.quality, req.skill);
+    Ok(warp::reply::json(&serde_json::json!({ "semilla": semilla, "objeto": objeto })))
+}
+
+async fn world_map_handler(query: SemillaQuery, req: MapaRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let parser = BackstoryParser::new();
+    let geografias = req.characters.iter().map(|c| parser.parse_character(c)).collect();
+
+    let mut config = WorldConfig { seed: semilla_de(&query), ..Default::default() };
+    if let Some(w) = req.width { config.width = w; }
+    if let Some(h) = req.height { config.height = h; }
+
+    let mundo = WorldGenerator::new(config).generate_from_characters(geografias);
+    Ok(warp::reply::json(&mundo))
+}
+
 async fn aria_chat_handler(req: AriaRequest) -> Result<impl warp::Reply, warp::Rejection> {
     println!("[BACKEND] Recibida solicitud para Aria chat");
     use soulforge_server::core::ia_integration::chat_con_aria;
@@ -162,6 +284,23 @@ async fn main() {
         .parse()
         .expect("PORT must be a number");
     
+    // Paquetes de idioma extra: cada *.json del directorio se valida al
+    // arrancar; uno roto detiene el servidor en vez de generar a medias
+    let dir_idiomas = env::var("SOULFORGE_IDIOMAS").unwrap_or_else(|_| "idiomas".to_string());
+    let cargados = paquete_idioma::cargar_directorio(std::path::Path::new(&dir_idiomas))
+        .unwrap_or_else(|e| panic!("Paquete de idioma inválido en '{}': {}", dir_idiomas, e));
+    if !cargados.is_empty() {
+        tracing::info!("Idiomas cargados desde {}: {}", dir_idiomas, cargados.join(", "));
+    }
+    
+    // Mundos propios, igual: se validan todos antes de aceptar peticiones
+    let dir_mundos = env::var("SOULFORGE_MUNDOS").unwrap_or_else(|_| "mundos".to_string());
+    let cargados = paquete_mundo::cargar_directorio(std::path::Path::new(&dir_mundos))
+        .unwrap_or_else(|e| panic!("Paquete de mundo inválido en '{}': {}", dir_mundos, e));
+    if !cargados.is_empty() {
+        tracing::info!("Mundos cargados desde {}: {}", dir_mundos, cargados.join(", "));
+    }
+    
     // Estado compartido
     let room_manager = Arc::new(RoomManager::new());
     
@@ -206,12 +345,70 @@ async fn main() {
         .and(warp::query::<CharacterQuery>())
         .and_then(generate_character_handler);
     
+    // GET /api/v1/personaje/codigo/{codigo}: regenera el personaje de un código
+    let personaje_codigo_route = warp::path!("api" / "v1" / "personaje" / "codigo" / String)
+        .and(warp::get())
+        .and_then(character_from_code_handler);
+    
+    // POST /api/v1/personaje/reforjar: regenera solo las partes pedidas
+    let reforjar_route = warp::path!("api" / "v1" / "personaje" / "reforjar")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(reforge_character_handler);
+    
+    // GET /api/v1/codigo/{codigo}: solo decodifica los parámetros
+    let codigo_route = warp::path!("api" / "v1" / "codigo" / String)
+        .and(warp::get())
+        .and_then(decode_code_handler);
+    
     // GET /api/v1/constelacion?num_personajes=...&mundo=...
     let constelacion_route = warp::path!("api" / "v1" / "constelacion")
         .and(warp::get())
         .and(warp::query::<ConstellationQuery>())
         .and_then(generate_constellation_handler);
 
+    // GET /api/v1/objeto/botin?semilla=...
+    let botin_route = warp::path!("api" / "v1" / "objeto" / "botin")
+        .and(warp::get())
+        .and(warp::query::<SemillaQuery>())
+        .and_then(loot_handler);
+    
+    // POST /api/v1/objeto/forjar?semilla=...
+    let forjar_objeto_route = warp::path!("api" / "v1" / "objeto" / "forjar")
+        .and(warp::post())
+        .and(warp::query::<SemillaQuery>())
+        .and(warp::body::json())
+        .and_then(forge_item_handler);
+    
+    // POST /api/v1/mapa?semilla=...: mapa del mundo a partir de personajes
+    let mapa_route = warp::path!("api" / "v1" / "mapa")
+        .and(warp::post())
+        .and(warp::query::<SemillaQuery>())
+        .and(warp::body::json())
+        .and_then(world_map_handler);
+
+    // GET /api/v1/idiomas: idiomas disponibles (integrados y cargados)
+    let idiomas_route = warp::path!("api" / "v1" / "idiomas")
+        .and(warp::get())
+        .map(|| {
+            let idiomas: Vec<_> = paquete_idioma::disponibles()
+                .into_iter()
+                .map(|(codigo, nombre)| serde_json::json!({ "codigo": codigo, "nombre": nombre }))
+                .collect();
+            warp::reply::json(&idiomas)
+        });
+
+    // GET /api/v1/mundos: mundos propios cargados y el mundo del que heredan
+    let mundos_route = warp::path!("api" / "v1" / "mundos")
+        .and(warp::get())
+        .map(|| {
+            let mundos: Vec<_> = paquete_mundo::disponibles()
+                .into_iter()
+                .map(|(id, nombre, base)| serde_json::json!({ "id": id, "nombre": nombre, "base": base }))
+                .collect();
+            warp::reply::json(&mundos)
+        });
+
     // POST /api/chat & /api/v1/aria/chat (Compatibilidad)
     // POST /api/chat (Ruta corta)
     let aria_chat_short = warp::path("api")
@@ -243,7 +440,15 @@ async fn main() {
         .or(aria_chat_legacy)
         .or(aria_diag_route)
         .or(personaje_route)
+        .or(personaje_codigo_route)
+        .or(reforjar_route)
+        .or(codigo_route)
         .or(constelacion_route)
+        .or(botin_route)
+        .or(forjar_objeto_route)
+        .or(mapa_route)
+        .or(idiomas_route)
+        .or(mundos_route)
         .or(ws_route)
         .or(create_route)
         .or(info_route)
@@ -9,6 +9,8 @@ mod vinculos;
 pub use quimica::*;
 pub use vinculos::*;
 
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
 use crate::core::Alma;
 
@@ -50,7 +52,10 @@ pub struct Vinculo {
 }
 
 impl Vinculo {
+    /// La historia y la tensión se eligen con una semilla derivada de las dos
+    /// almas: el mismo par (en el mismo orden) da siempre el mismo vínculo
     pub fn crear(alma_a: &Alma, alma_b: &Alma) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(alma_a.semilla ^ alma_b.semilla.rotate_left(32));
         let quimica = Quimica::calcular(alma_a, alma_b);
         let tipo = Self::determinar_tipo(&quimica, alma_a, alma_b);
         let intensidad = quimica.intensidad_total();
@@ -60,8 +65,8 @@ impl Vinculo {
             id_persona_b: alma_b.id,
             tipo,
             intensidad,
-            historia_compartida: Self::generar_historia(&quimica, alma_a, alma_b),
-            tension_activa: Self::generar_tension(&quimica, alma_a, alma_b),
+            historia_compartida: Self::generar_historia(&mut rng, &quimica, alma_a, alma_b),
+            tension_activa: Self::generar_tension(&mut rng, &quimica, alma_a, alma_b),
             potencial_narrativo: Self::generar_potencial(&quimica, &tipo),
             quimica,
         }
@@ -101,9 +106,9 @@ impl Vinculo {
         }
     }
     
-    fn generar_historia(quimica: &Quimica, a: &Alma, b: &Alma) -> Option<String> {
+    fn generar_historia(rng: &mut impl Rng, quimica: &Quimica, a: &Alma, b: &Alma) -> Option<String> {
         if quimica.intensidad_total() > 0.5 {
-            let historias = vec![
+            let historias = [
                 format!(
                     "Se conocieron cuando {} necesitaba ayuda, y {} fue el único que respondió.",
                     a.identidad.nombre, b.identidad.nombre
@@ -116,15 +121,15 @@ impl Vinculo {
                     "Estuvieron en lados opuestos de un conflicto, hasta que descubrieron la verdad.",
                 ),
             ];
-            Some(historias[rand::random::<usize>() % historias.len()].clone())
+            historias.choose(rng).cloned()
         } else {
             None
         }
     }
     
-    fn generar_tension(quimica: &Quimica, a: &Alma, b: &Alma) -> Option<String> {
+    fn generar_tension(rng: &mut impl Rng, quimica: &Quimica, a: &Alma, b: &Alma) -> Option<String> {
         if quimica.conflicto_sombras > 0.4 || quimica.deseo_necesidad_tension > 0.4 {
-            let tensiones = vec![
+            let tensiones = [
                 format!(
                     "{} ve en {} todo lo que niega de sí mismo - y no puede ignorarlo.",
                     a.identidad.nombre, b.identidad.nombre
@@ -141,7 +146,7 @@ impl Vinculo {
                     a.identidad.nombre, b.identidad.nombre
                 ),
             ];
-            Some(tensiones[rand::random::<usize>() % tensiones.len()].clone())
+            tensiones.choose(rng).cloned()
         } else {
             None
         }
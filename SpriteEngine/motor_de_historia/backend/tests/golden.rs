@@ -0,0 +1,153 @@
+//! Salidas doradas: la misma semilla debe producir exactamente el mismo JSON.
+//!
+//! Si un cambio altera la generación a propósito, congelar antes el generador
+//! actual como `core::vN` (los códigos de personaje ya emitidos siguen dando
+//! el mismo personaje con él), subir `VERSION_GENERADOR`, regenerar con
+//! `SOULFORGE_BLESS=1 cargo test --test golden` y revisar el diff. Sin subir
+//! la versión, regenerar falla; las salidas de `tests/golden/vN` no se
+//! regeneran nunca.
+
+use std::path::PathBuf;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::Serialize;
+use soulforge_server::cartographer::parser::BackstoryParser;
+use soulforge_server::cartographer::world_generator::{WorldConfig, WorldGenerator};
+use soulforge_server::core::codigo;
+use soulforge_server::core::items::{ItemGenerator, ItemType};
+use soulforge_server::core::paquete_mundo::{self, IdMundo};
+use soulforge_server::core::VERSION_GENERADOR;
+use soulforge_server::{Language, Mundo, ParametrosConstelacion, ParametrosGeneracion, Rol, SoulForge};
+
+const SEMILLA: u64 = 0x050F_026E;
+
+/// Código de `params_alma(Language::Espanol)` emitido por la versión 1
+const CODIGO_V1: &str = "05K1WLnh5QA9RWrO84FhKt31";
+
+fn comparar<T: Serialize>(nombre: &str, valor: &T) {
+    comparar_version(VERSION_GENERADOR, nombre, valor)
+}
+
+/// Las versiones congeladas viven en `tests/golden/v<N>` y nunca se regeneran
+fn comparar_version<T: Serialize>(version: u8, nombre: &str, valor: &T) {
+    let mut ruta = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    if version != VERSION_GENERADOR {
+        ruta.push(format!("v{}", version));
+    }
+    let ruta = ruta.join(format!("{}.json", nombre));
+    let dorado = serde_json::json!({ "version_generador": version, "salida": valor });
+    let actual = serde_json::to_string_pretty(&dorado).unwrap() + "\n";
+    let esperado = std::fs::read_to_string(&ruta).ok();
+    let version_esperada = esperado.as_deref()
+        .and_then(|e| serde_json::from_str::<serde_json::Value>(e).ok())
+        .and_then(|e| e["version_generador"].as_u64());
+
+    if std::env::var_os("SOULFORGE_BLESS").is_some() && version == VERSION_GENERADOR {
+        assert!(
+            esperado.as_deref() == Some(actual.as_str()) || version_esperada != Some(VERSION_GENERADOR as u64),
+            "La salida de '{}' cambió sin subir VERSION_GENERADOR", nombre
+        );
+        std::fs::create_dir_all(ruta.parent().unwrap()).unwrap();
+        std::fs::write(&ruta, &actual).unwrap();
+        return;
+    }
+
+    let esperado = esperado.unwrap_or_else(|| panic!("Falta {}: ejecutar con SOULFORGE_BLESS=1", ruta.display()));
+    assert!(actual == esperado, "La salida de '{}' ya no coincide con {}", nombre, ruta.display());
+}
+
+fn params_alma(idioma: Language) -> ParametrosGeneracion {
+    ParametrosGeneracion {
+        semilla: Some(SEMILLA),
+        mundo: Some(Mundo::FantasiaOscura),
+        rol: Some(Rol::Heroe),
+        idioma: Some(idioma),
+        ..Default::default()
+    }
+}
+
+fn params_constelacion() -> ParametrosConstelacion {
+    ParametrosConstelacion {
+        semilla: Some(SEMILLA),
+        cantidad: 3,
+        mundo: Mundo::FantasiaMedieval,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn almas_doradas() {
+    // El rng del forjador no debe influir cuando hay semilla
+    let alma = SoulForge::nuevo().forjar(params_alma(Language::Espanol));
+    let repetida = SoulForge::con_semilla(1).forjar(params_alma(Language::Espanol));
+    assert_eq!(alma.id, repetida.id);
+    assert_eq!(serde_json::to_value(&alma).unwrap(), serde_json::to_value(&repetida).unwrap());
+
+    // El código compartible reproduce la misma alma
+    let codigo = codigo::codificar(&params_alma(Language::Espanol)).unwrap();
+    assert_eq!(serde_json::to_value(codigo::regenerar(&codigo).unwrap()).unwrap(), serde_json::to_value(&alma).unwrap());
+
+    comparar("codigo_es", &codigo);
+    comparar("alma_es", &alma);
+    comparar("alma_en", &SoulForge::nuevo().forjar(params_alma(Language::English)));
+}
+
+/// El motor (`soulforge-engine`, `alma_bridge`) lee esta salida: un mundo
+/// propio tiene que llegarle como texto, igual que uno integrado
+#[test]
+fn alma_de_mundo_propio_dorada() {
+    paquete_mundo::registrar(r#"{
+        "id": "islas-de-ceniza",
+        "nombre": "Islas de Ceniza",
+        "base": "PiratasCaribe",
+        "nombres_femeninos": ["Ysolde"],
+        "nombres_masculinos": ["Oren"],
+        "ganchos": ["{0} lleva un mapa tatuado en la espalda"]
+    }"#).unwrap();
+    let alma = SoulForge::nuevo().forjar(ParametrosGeneracion {
+        mundo: Some(Mundo::Custom(IdMundo::buscar("islas-de-ceniza").unwrap())),
+        ..params_alma(Language::Espanol)
+    });
+    assert_eq!(serde_json::to_value(&alma).unwrap()["mundo"], "islas-de-ceniza");
+
+    comparar("alma_mundo_propio", &alma);
+}
+
+#[test]
+fn almas_doradas_v1() {
+    // El generador congelado reproduce las almas de la versión 1
+    for (nombre, idioma) in [("alma_es", Language::Espanol), ("alma_en", Language::English)] {
+        let alma = SoulForge::nuevo().forjar_version(1, params_alma(idioma)).unwrap();
+        comparar_version(1, nombre, &alma);
+    }
+
+    // Un código de la versión 1 sigue regenerando su personaje de entonces
+    assert_eq!(codigo::decodificar(CODIGO_V1).unwrap().version_generador, 1);
+    comparar_version(1, "alma_es", &codigo::regenerar(CODIGO_V1).unwrap());
+    assert!(SoulForge::nuevo().forjar_version(VERSION_GENERADOR + 1, params_alma(Language::Espanol)).is_none());
+}
+
+#[test]
+fn constelacion_dorada() {
+    let constelacion = SoulForge::nuevo().forjar_constelacion(params_constelacion());
+    let repetida = SoulForge::nuevo().forjar_constelacion(params_constelacion());
+    assert_eq!(constelacion.id, repetida.id);
+    assert_eq!(constelacion.semilla, SEMILLA);
+
+    comparar("constelacion", &constelacion);
+}
+
+#[test]
+fn objetos_y_mundo_dorados() {
+    let mut rng = ChaCha8Rng::seed_from_u64(SEMILLA);
+    let botin = ItemGenerator::generate_loot(&mut rng, None);
+    let forjado = ItemGenerator::forge(&mut rng, ItemType::Weapon, 80, 90);
+    comparar("objetos", &(botin, forjado));
+
+    let alma = serde_json::to_value(SoulForge::nuevo().forjar(params_alma(Language::Espanol))).unwrap();
+    let geografia = BackstoryParser::new().parse_character(&alma);
+    let config = WorldConfig { width: 12, height: 12, seed: SEMILLA, ..Default::default() };
+    let mundo = WorldGenerator::new(config).generate_from_characters(vec![geografia]);
+    comparar("mundo", &mundo);
+}
@@ -0,0 +1,500 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::soul_parser::*;
+use crate::text_analysis::AnalyzedText;
+
+// ============================================================
+// ESPEJO TIPADO DEL JSON DE `Alma` (soulforge-server)
+// ============================================================
+//
+// Solo se declaran los campos que el puente necesita. Todo lleva
+// `#[serde(default)]` para aceptar almas generadas con versiones
+// anteriores del servidor o con capas recortadas.
+
+/// Un personaje tal como lo serializa `SoulForge::forjar`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Alma {
+    pub id: String,
+    pub semilla: u64,
+    pub rol: String,
+    pub tono_moral: String,
+    pub mundo: String,
+    pub nivel_conflicto: String,
+    pub identidad: Identidad,
+    pub capas: SietCapas,
+    pub arco: ArcoNarrativo,
+    pub ganchos_narrativos: Vec<String>,
+    pub momentos_definitorios: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Identidad {
+    pub nombre: String,
+    pub apellido: Option<String>,
+    pub titulo: Option<String>,
+    pub edad: u32,
+    pub cicatriz: Option<String>,
+}
+
+/// Las 7 capas psicológicas
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SietCapas {
+    pub arquetipo: Arquetipo,
+    pub herida: Herida,
+    pub mascara: Mascara,
+    pub deseo_necesidad: DeseoNecesidad,
+    pub sombra: Sombra,
+    pub vinculos: CapaVinculos,
+    pub mentira: Mentira,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Arquetipo {
+    pub tipo: String,
+    pub manifestacion_luz: String,
+    pub manifestacion_sombra: String,
+    pub don_natural: String,
+    pub debilidad: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Herida {
+    pub tipo: String,
+    pub edad_cuando_ocurrio: String,
+    pub causante: String,
+    pub circunstancia: String,
+    pub como_lo_cambio: String,
+    pub gatillo_emocional: String,
+    pub mecanismo_defensa: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Mascara {
+    pub comportamiento_publico: String,
+    pub imagen_proyectada: String,
+    pub frase_tipica: String,
+    pub sentimiento_oculto: String,
+    pub miedo_central: String,
+    pub deseo_secreto: String,
+    pub trigger_que_la_rompe: String,
+    pub costo_de_mantenerla: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeseoNecesidad {
+    pub deseo_consciente: String,
+    pub motivacion_del_deseo: String,
+    pub necesidad_real: String,
+    pub por_que_no_la_ve: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Sombra {
+    pub rasgo_negado: String,
+    pub como_se_filtra: Vec<String>,
+    pub que_la_despierta: String,
+    pub potencial_integrado: String,
+    pub peligro_si_domina: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CapaVinculos {
+    pub patron: String,
+    pub estilo_apego: String,
+    pub rol_en_grupos: String,
+    pub como_expresa_afecto: String,
+    pub que_busca_en_otros: String,
+    pub que_ofrece: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Mentira {
+    pub la_mentira: String,
+    pub como_nacio: String,
+    pub como_distorsiona: String,
+    pub decisiones_que_causa: String,
+    pub verdad_necesaria: String,
+    pub catalizador_potencial: String,
+    pub costo_de_la_verdad: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ArcoNarrativo {
+    pub tipo: String,
+    pub estado_inicial: String,
+    pub punto_de_quiebre: String,
+    pub climax_potencial: String,
+    pub resolucion_positiva: String,
+    pub resolucion_tragica: String,
+}
+
+// ============================================================
+// PUENTE PRINCIPAL
+// ============================================================
+
+/// Convierte el JSON de un `Alma` del servidor en un `LivingSoul`
+pub fn soul_from_alma_json(alma_json: &str) -> Result<LivingSoul, serde_json::Error> {
+    let alma: Alma = serde_json::from_str(alma_json)?;
+    Ok(soul_from_alma(&alma))
+}
+
+/// Convierte un `Alma` ya deserializado en un `LivingSoul`
+pub fn soul_from_alma(alma: &Alma) -> LivingSoul {
+    let capas = &alma.capas;
+    let mut raw_attrs: HashMap<String, String> = HashMap::new();
+
+    let name = match alma.identidad.apellido.as_deref() {
+        Some(apellido) if !apellido.trim().is_empty()
+            && !alma.identidad.nombre.contains(apellido) => {
+            format!("{} {}", alma.identidad.nombre, apellido)
+        },
+        _ => alma.identidad.nombre.clone(),
+    };
+    let name = if name.trim().is_empty() {
+        "Alma Sin Nombre".to_string()
+    } else {
+        name
+    };
+
+    let titles = alma.identidad.titulo.iter()
+        .filter(|t| !t.trim().is_empty())
+        .cloned()
+        .collect();
+
+    // === PSIQUE ===
+    let psyche = SoulPsyche {
+        core_fear: capas.mascara.miedo_central.clone(),
+        deepest_desire: first_non_empty(&[
+            &capas.mascara.deseo_secreto,
+            &capas.deseo_necesidad.deseo_consciente,
+        ]),
+        fatal_flaw: first_non_empty(&[
+            &capas.arquetipo.debilidad,
+            &capas.mentira.decisiones_que_causa,
+        ]),
+        moral_code: Vec::new(),
+        breaking_point: first_non_empty(&[
+            &alma.arco.punto_de_quiebre,
+            &capas.mascara.trigger_que_la_rompe,
+        ]),
+        shadow_self: capas.sombra.rasgo_negado.clone(),
+    };
+
+    let weight = conflict_weight(&alma.nivel_conflicto);
+    let scars = alma_scars(alma, weight);
+    let prophecies = alma_prophecies(alma);
+    let bonds = alma_bonds(&capas.herida, &capas.vinculos);
+
+    // === ATRIBUTOS CRUDOS ===
+    raw_attrs.insert("core_fear".to_string(), psyche.core_fear.clone());
+    raw_attrs.insert("deepest_desire".to_string(), psyche.deepest_desire.clone());
+    raw_attrs.insert("fatal_flaw".to_string(), psyche.fatal_flaw.clone());
+    let extra = [
+        ("alma_id", alma.id.clone()),
+        ("semilla", alma.semilla.to_string()),
+        ("rol", alma.rol.clone()),
+        ("tono_moral", alma.tono_moral.clone()),
+        ("mundo", alma.mundo.clone()),
+        ("nivel_conflicto", alma.nivel_conflicto.clone()),
+        ("tipo_arco", alma.arco.tipo.clone()),
+        ("mascara", capas.mascara.comportamiento_publico.clone()),
+        ("frase_tipica", capas.mascara.frase_tipica.clone()),
+        ("gatillo_emocional", capas.herida.gatillo_emocional.clone()),
+        ("mecanismo_defensa", capas.herida.mecanismo_defensa.clone()),
+        ("deseo_consciente", capas.deseo_necesidad.deseo_consciente.clone()),
+        ("necesidad_real", capas.deseo_necesidad.necesidad_real.clone()),
+        ("la_mentira", capas.mentira.la_mentira.clone()),
+        ("verdad_necesaria", capas.mentira.verdad_necesaria.clone()),
+        ("patron_vincular", capas.vinculos.patron.clone()),
+        ("estilo_apego", capas.vinculos.estilo_apego.clone()),
+    ];
+    for (key, value) in extra {
+        if !value.is_empty() {
+            raw_attrs.insert(key.to_string(), value);
+        }
+    }
+
+    LivingSoul {
+        name,
+        titles,
+        archetype: archetype_label(&capas.arquetipo.tipo),
+        psyche,
+        tension_markers: calculate_initial_tension(&scars, &prophecies, &bonds),
+        scars,
+        prophecies,
+        nemesis: None,
+        bonds,
+        chronicle: Vec::new(),
+        raw_attributes: raw_attrs,
+    }
+}
+
+// ============================================================
+// MAPEOS POR CAPA
+// ============================================================
+
+fn alma_scars(alma: &Alma, weight: f32) -> Vec<SoulScar> {
+    let herida = &alma.capas.herida;
+    let mut scars = Vec::new();
+
+    if !herida.causante.is_empty() || !herida.circunstancia.is_empty() {
+        scars.push(SoulScar {
+            id: "scar_herida".to_string(),
+            name: wound_label(&herida.tipo),
+            origin_event: format!("{} {}", herida.causante, herida.circunstancia)
+                .trim()
+                .to_string(),
+            trauma_type: wound_trauma_type(&herida.tipo),
+            healed: false,
+            narrative_weight: weight,
+        });
+    }
+
+    // La cicatriz física de la identidad pesa menos que la herida
+    if let Some(ref marca) = alma.identidad.cicatriz {
+        if !marca.trim().is_empty() {
+            scars.push(SoulScar {
+                id: "scar_marca".to_string(),
+                name: marca.clone(),
+                origin_event: marca.clone(),
+                trauma_type: TraumaType::Custom("marca_fisica".to_string()),
+                healed: true,
+                narrative_weight: (weight * 0.5).max(0.2),
+            });
+        }
+    }
+
+    scars
+}
+
+fn alma_prophecies(alma: &Alma) -> Vec<Prophecy> {
+    let arco = &alma.arco;
+    let mut prophecies = Vec::new();
+
+    // El arco se lee como dos destinos posibles
+    let (glory_type, doom_type) = match arco.tipo.as_str() {
+        "Caida" | "Corrupcion" => (ProphecyType::Ambiguous, ProphecyType::Doom),
+        "Ascenso" | "Redencion" => (ProphecyType::Glory, ProphecyType::Ambiguous),
+        "Plano" => (ProphecyType::Glory, ProphecyType::Ambiguous),
+        _ => (ProphecyType::Choice, ProphecyType::Choice),
+    };
+
+    if !arco.resolucion_positiva.is_empty() {
+        prophecies.push(Prophecy {
+            id: "prophecy_arco_luz".to_string(),
+            text: arco.resolucion_positiva.clone(),
+            prophecy_type: glory_type,
+            fulfilled: false,
+            progress: 0.0,
+        });
+    }
+    if !arco.resolucion_tragica.is_empty() {
+        prophecies.push(Prophecy {
+            id: "prophecy_arco_sombra".to_string(),
+            text: arco.resolucion_tragica.clone(),
+            prophecy_type: doom_type,
+            fulfilled: false,
+            progress: 0.0,
+        });
+    }
+
+    for (idx, gancho) in alma.ganchos_narrativos.iter().enumerate() {
+        if gancho.trim().is_empty() {
+            continue;
+        }
+        prophecies.push(Prophecy {
+            id: format!("prophecy_gancho_{}", idx),
+            text: gancho.clone(),
+            prophecy_type: ProphecyType::Ambiguous,
+            fulfilled: false,
+            progress: 0.0,
+        });
+    }
+
+    prophecies
+}
+
+fn alma_bonds(herida: &Herida, vinculos: &CapaVinculos) -> Vec<SoulBond> {
+    if herida.causante.trim().is_empty() {
+        return Vec::new();
+    }
+
+    // El causante de la herida es el vínculo que la herida dejó marcado
+    let status = match herida.tipo.as_str() {
+        "Traicion" | "Abandono" | "Violencia" => BondStatus::Broken,
+        "Perdida" => BondStatus::Dormant,
+        _ => BondStatus::Strained,
+    };
+    let strength = match vinculos.estilo_apego.as_str() {
+        "Ansioso" => 0.8,
+        "Desorganizado" => 0.7,
+        "Evitativo" => 0.3,
+        _ => 0.5,
+    };
+
+    vec![SoulBond {
+        entity_name: herida.causante.clone(),
+        bond_type: bond_type_from_causante(&herida.causante),
+        strength,
+        status,
+    }]
+}
+
+// ============================================================
+// HELPERS
+// ============================================================
+
+fn first_non_empty(options: &[&String]) -> String {
+    options.iter()
+        .find(|s| !s.trim().is_empty())
+        .map(|s| s.to_string())
+        .unwrap_or_default()
+}
+
+fn conflict_weight(nivel: &str) -> f32 {
+    match nivel {
+        "Bajo" => 0.4,
+        "Medio" => 0.6,
+        "Alto" => 0.8,
+        "Extremo" => 0.95,
+        _ => 0.5,
+    }
+}
+
+fn wound_trauma_type(tipo: &str) -> TraumaType {
+    match tipo {
+        "Traicion" => TraumaType::Betrayal,
+        "Perdida" => TraumaType::Loss,
+        "Impotencia" | "Culpa" => TraumaType::Failure,
+        "Violencia" => TraumaType::Violence,
+        "Abandono" | "Rechazo" | "Negligencia" => TraumaType::Abandonment,
+        "" => TraumaType::Custom("herida".to_string()),
+        other => TraumaType::Custom(other.to_lowercase()),
+    }
+}
+
+fn wound_label(tipo: &str) -> String {
+    let label = match tipo {
+        "Abandono" => "La Herida del Abandono",
+        "Traicion" => "La Herida de la Traición",
+        "Perdida" => "La Herida de la Pérdida",
+        "Humillacion" => "La Herida de la Humillación",
+        "Injusticia" => "La Herida de la Injusticia",
+        "Impotencia" => "La Herida de la Impotencia",
+        "Culpa" => "La Herida de la Culpa",
+        "Rechazo" => "La Herida del Rechazo",
+        "Negligencia" => "La Herida del Descuido",
+        "Violencia" => "La Herida de la Violencia",
+        _ => "La Herida Original",
+    };
+    label.to_string()
+}
+
+fn archetype_label(tipo: &str) -> String {
+    let label = match tipo {
+        "Inocente" => "Inocente",
+        "Huerfano" => "Huérfano",
+        "Guerrero" => "Guerrero",
+        "Cuidador" => "Cuidador",
+        "Buscador" => "Buscador",
+        "Destructor" => "Destructor",
+        "Amante" => "Amante",
+        "Creador" => "Creador",
+        "Gobernante" => "Gobernante",
+        "Mago" => "Mago",
+        "Sabio" => "Sabio",
+        "Bufon" => "Bufón",
+        other => other,
+    };
+    label.to_string()
+}
+
+fn bond_type_from_causante(causante: &str) -> BondType {
+    let text = AnalyzedText::new(causante);
+    let has = |words: &[&str]| words.iter().any(|w| text.contains(w));
+
+    if has(&["padre", "madre", "hermano", "familia", "hijo"]) {
+        BondType::Blood
+    } else if has(&["mentor", "maestro", "líder", "anciano", "protector"]) {
+        BondType::Mentorship
+    } else if has(&["amor", "prometido", "amante", "pareja"]) {
+        BondType::Love
+    } else if has(&["amigo", "compañero", "confiaba"]) {
+        BondType::Friendship
+    } else if has(&["juraron", "consejo", "comunidad"]) {
+        BondType::Oath
+    } else {
+        BondType::Complex(causante.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALMA_JSON: &str = r#"{
+        "id": "4f98564e-a166-493f-9951-072188780d81",
+        "semilla": 42,
+        "rol": "Heroe",
+        "nivel_conflicto": "Alto",
+        "identidad": { "nombre": "Calliope", "apellido": "Ravencroft", "titulo": "El Roto" },
+        "capas": {
+            "arquetipo": { "tipo": "Sabio", "debilidad": "Conocer sin actuar" },
+            "herida": { "tipo": "Traicion", "causante": "Su hermano mayor",
+                        "circunstancia": "en el momento en que más lo necesitaba" },
+            "mascara": { "miedo_central": "Que todo será arrebatado de nuevo",
+                         "deseo_secreto": "Poder amar sin miedo" },
+            "sombra": { "rasgo_negado": "La crueldad" },
+            "vinculos": { "estilo_apego": "Ansioso" }
+        },
+        "arco": { "tipo": "Caida", "resolucion_positiva": "Aprende a confiar",
+                  "resolucion_tragica": "Se pierde a sí mismo" },
+        "ganchos_narrativos": ["Alguien del pasado reaparece"]
+    }"#;
+
+    #[test]
+    fn test_alma_maps_layers_onto_soul() {
+        let soul = soul_from_alma_json(ALMA_JSON).unwrap();
+
+        assert_eq!(soul.name, "Calliope Ravencroft");
+        assert_eq!(soul.archetype, "Sabio");
+        assert_eq!(soul.psyche.core_fear, "Que todo será arrebatado de nuevo");
+        assert!(matches!(soul.scars[0].trauma_type, TraumaType::Betrayal));
+        assert_eq!(soul.scars[0].narrative_weight, 0.8);
+        assert!(matches!(soul.bonds[0].bond_type, BondType::Blood));
+        assert!(matches!(soul.bonds[0].status, BondStatus::Broken));
+        assert_eq!(soul.prophecies.len(), 3);
+        assert!(matches!(soul.prophecies[1].prophecy_type, ProphecyType::Doom));
+        assert_eq!(soul.tension_markers.unresolved_conflicts, 1);
+    }
+
+    #[test]
+    fn test_reads_server_golden_with_custom_world() {
+        // Salida dorada de soulforge-server: un alma de un mundo propio
+        let golden: serde_json::Value = serde_json::from_str(include_str!(
+            "../../../SpriteEngine/motor_de_historia/backend/tests/golden/alma_mundo_propio.json"
+        )).unwrap();
+        let alma: Alma = serde_json::from_value(golden["salida"].clone()).unwrap();
+        assert_eq!(alma.mundo, "islas-de-ceniza");
+
+        let soul = soul_from_alma(&alma);
+        assert!(soul.name.starts_with(&alma.identidad.nombre));
+        assert!(!soul.scars.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_alma_json_is_an_error() {
+        assert!(soul_from_alma_json("{ no es json").is_err());
+    }
+}
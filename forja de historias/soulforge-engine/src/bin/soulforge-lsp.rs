@@ -0,0 +1,569 @@
+//! `soulforge-lsp` - Servidor Language Server Protocol para manuscritos
+//!
+//! Habla JSON-RPC por stdin/stdout. Al iniciar recorre la carpeta del
+//! proyecto (capítulos `.md` y fichas `.html`/`.md`) y después sigue los
+//! documentos abiertos en el editor:
+//!
+//! - diagnósticos: las `CoherenceViolation` de cada capítulo, situadas con `position_hint`
+//! - inlay hints: la tensión de cada alma al final de cada línea que la dispara
+//! - hover: la tensión global de cada alma y las ondas de la línea
+//! - code actions: la sugerencia de cada violación y las `suggested_revisions`
+//!   como comentario Markdown encima de la línea
+//!
+//! El idioma se elige con `initializationOptions.language` o con
+//! `settings.soulforge.language` en `workspace/didChangeConfiguration`.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use serde_json::{json, Value};
+use soulforge_engine::*;
+
+/// Carpetas que nunca forman parte del manuscrito
+const SKIPPED_DIRS: &[&str] = &["target", "node_modules"];
+
+// Códigos de error JSON-RPC
+const PARSE_ERROR: i64 = -32700;
+const INVALID_PARAMS: i64 = -32602;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_REQUEST: i64 = -32600;
+
+fn main() -> ExitCode {
+    Server::new(io::stdout()).run(&mut io::stdin().lock())
+}
+
+enum Flow {
+    Continue,
+    Exit(ExitCode),
+}
+
+/// Lo que llega por la entrada
+enum Incoming {
+    Message(Value),
+    /// Cabeceras sin `Content-Length` o cuerpo que no es JSON
+    Malformed(String),
+    Closed,
+}
+
+struct Server<W: Write> {
+    output: W,
+    workspace: ManuscriptWorkspace,
+    root: Option<PathBuf>,
+    uris: HashMap<String, String>,      // Ruta -> URI tal como la manda el editor
+    open: HashSet<String>,              // Rutas abiertas en el editor (mandan sobre el disco)
+    shutting_down: bool,
+}
+
+impl<W: Write> Server<W> {
+    fn new(output: W) -> Self {
+        Self {
+            output,
+            workspace: ManuscriptWorkspace::new(Language::default()),
+            root: None,
+            uris: HashMap::new(),
+            open: HashSet::new(),
+            shutting_down: false,
+        }
+    }
+
+    /// Atiende mensajes hasta `exit`, el fin de la entrada o un error de E/S.
+    /// Un mensaje mal formado se contesta con `Parse error` y se sigue
+    fn run(&mut self, input: &mut impl BufRead) -> ExitCode {
+        loop {
+            let flow = match read_message(input) {
+                Ok(Incoming::Message(message)) => self.handle(message),
+                Ok(Incoming::Malformed(reason)) => {
+                    eprintln!("soulforge-lsp: {}", reason);
+                    self.respond_error(Value::Null, PARSE_ERROR, &reason)
+                },
+                Ok(Incoming::Closed) => return ExitCode::FAILURE,
+                Err(e) => {
+                    eprintln!("soulforge-lsp: no se pudo leer la entrada: {}", e);
+                    return ExitCode::FAILURE;
+                },
+            };
+            match flow {
+                Ok(Flow::Continue) => {},
+                Ok(Flow::Exit(code)) => return code,
+                Err(e) => {
+                    eprintln!("soulforge-lsp: no se pudo escribir la respuesta: {}", e);
+                    return ExitCode::FAILURE;
+                },
+            }
+        }
+    }
+
+    fn handle(&mut self, message: Value) -> io::Result<Flow> {
+        let method = message["method"].as_str().unwrap_or_default().to_string();
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        let Some(id) = message.get("id").cloned() else {
+            return self.notification(&method, &params);
+        };
+        if method.is_empty() {
+            // Respuesta del editor a algo que no pedimos
+            return Ok(Flow::Continue);
+        }
+        if self.shutting_down {
+            return self.respond_error(id, INVALID_REQUEST, "el servidor se está cerrando");
+        }
+
+        let result = match method.as_str() {
+            "initialize" => Ok(self.initialize(&params)),
+            "shutdown" => {
+                self.shutting_down = true;
+                Ok(Value::Null)
+            },
+            "textDocument/hover" => self.hover(&params),
+            "textDocument/inlayHint" => self.inlay_hints(&params),
+            "textDocument/codeAction" => self.code_actions(&params),
+            _ => return self.respond_error(id, METHOD_NOT_FOUND, &format!("método no soportado '{}'", method)),
+        };
+        match result {
+            Ok(value) => {
+                self.send(json!({ "jsonrpc": "2.0", "id": id, "result": value }))?;
+                Ok(Flow::Continue)
+            },
+            Err(message) => self.respond_error(id, INVALID_PARAMS, &message),
+        }
+    }
+
+    fn notification(&mut self, method: &str, params: &Value) -> io::Result<Flow> {
+        match method {
+            "initialized" => {
+                let touched = self.scan_root();
+                self.publish(touched)?;
+            },
+            "exit" => {
+                let code = if self.shutting_down { ExitCode::SUCCESS } else { ExitCode::FAILURE };
+                return Ok(Flow::Exit(code));
+            },
+            "textDocument/didOpen" => {
+                let document = &params["textDocument"];
+                let Some((path, uri)) = document_path(document) else {
+                    return Ok(Flow::Continue);
+                };
+                let text = document["text"].as_str().unwrap_or_default().to_string();
+                self.uris.insert(path.clone(), uri);
+                self.open.insert(path.clone());
+                let touched = self.workspace.update(&path, &text);
+                self.publish(touched)?;
+            },
+            "textDocument/didChange" => {
+                let Some((path, _)) = document_path(&params["textDocument"]) else {
+                    return Ok(Flow::Continue);
+                };
+                // Sincronización completa: el último cambio trae el texto entero
+                let changes = params["contentChanges"].as_array().cloned().unwrap_or_default();
+                let Some(text) = changes.last().and_then(|c| c["text"].as_str()) else {
+                    return Ok(Flow::Continue);
+                };
+                let touched = self.workspace.update(&path, text);
+                self.publish(touched)?;
+            },
+            "textDocument/didClose" => {
+                let Some((path, _)) = document_path(&params["textDocument"]) else {
+                    return Ok(Flow::Continue);
+                };
+                self.open.remove(&path);
+                // Vuelve a lo que haya en disco; si no existe, sale del proyecto
+                let touched = match fs::read_to_string(&path) {
+                    Ok(text) if self.in_project(&path) => self.workspace.update(&path, &text),
+                    _ => self.workspace.remove(&path),
+                };
+                self.publish(touched)?;
+            },
+            "workspace/didChangeWatchedFiles" => {
+                let mut touched = Vec::new();
+                for change in params["changes"].as_array().into_iter().flatten() {
+                    let Some((path, uri)) = document_path(change) else {
+                        continue;
+                    };
+                    if self.open.contains(&path) {
+                        continue;
+                    }
+                    self.uris.insert(path.clone(), uri);
+                    touched.extend(match fs::read_to_string(&path) {
+                        Ok(text) => self.workspace.update(&path, &text),
+                        Err(_) => self.workspace.remove(&path),
+                    });
+                }
+                self.publish(touched)?;
+            },
+            "workspace/didChangeConfiguration" => {
+                let code = params["settings"]["soulforge"]["language"].as_str();
+                if let Some(lang) = code.and_then(Language::parse) {
+                    if lang != self.workspace.language() {
+                        let touched = self.workspace.set_language(lang);
+                        self.publish(touched)?;
+                    }
+                }
+            },
+            _ => {},
+        }
+        Ok(Flow::Continue)
+    }
+
+    // ============================================================
+    // PETICIONES
+    // ============================================================
+
+    fn initialize(&mut self, params: &Value) -> Value {
+        if let Some(lang) = params["initializationOptions"]["language"].as_str().and_then(Language::parse) {
+            self.workspace = ManuscriptWorkspace::new(lang);
+        }
+        let root_uri = params["workspaceFolders"][0]["uri"].as_str()
+            .or_else(|| params["rootUri"].as_str());
+        self.root = root_uri.and_then(uri_to_path)
+            .or_else(|| params["rootPath"].as_str().map(PathBuf::from));
+
+        json!({
+            "capabilities": {
+                "textDocumentSync": { "openClose": true, "change": 1 },
+                "hoverProvider": true,
+                "inlayHintProvider": true,
+                "codeActionProvider": { "codeActionKinds": ["quickfix"] },
+            },
+            "serverInfo": { "name": "soulforge-lsp", "version": env!("CARGO_PKG_VERSION") },
+        })
+    }
+
+    fn hover(&self, params: &Value) -> Result<Value, String> {
+        let (path, _) = document_path(&params["textDocument"]).ok_or("falta textDocument.uri")?;
+        let Some(text) = self.workspace.text(&path) else {
+            return Ok(Value::Null);
+        };
+        let index = LineIndex::new(text);
+        let offset = offset_of(&index, &params["position"]);
+        Ok(match self.workspace.hover(&path, offset) {
+            Some(markdown) => json!({ "contents": { "kind": "markdown", "value": markdown } }),
+            None => Value::Null,
+        })
+    }
+
+    fn inlay_hints(&self, params: &Value) -> Result<Value, String> {
+        let (path, _) = document_path(&params["textDocument"]).ok_or("falta textDocument.uri")?;
+        let Some(text) = self.workspace.text(&path) else {
+            return Ok(json!([]));
+        };
+        let index = LineIndex::new(text);
+        let first = params["range"]["start"]["line"].as_u64().unwrap_or(0) as usize;
+        let last = params["range"]["end"]["line"].as_u64().map(|l| l as usize).unwrap_or(usize::MAX);
+
+        let hints: Vec<Value> = self.workspace.tension_hints(&path).into_iter()
+            .filter(|hint| (first..=last).contains(&hint.line))
+            .map(|hint| json!({
+                "position": position(&index, hint.offset),
+                "label": hint.label,
+                "tooltip": hint.sources.join(", "),
+                "paddingLeft": true,
+            }))
+            .collect();
+        Ok(Value::Array(hints))
+    }
+
+    fn code_actions(&self, params: &Value) -> Result<Value, String> {
+        let (path, uri) = document_path(&params["textDocument"]).ok_or("falta textDocument.uri")?;
+        let Some(text) = self.workspace.text(&path) else {
+            return Ok(json!([]));
+        };
+        let index = LineIndex::new(text);
+        let start = offset_of(&index, &params["range"]["start"]);
+        let end = offset_of(&index, &params["range"]["end"]);
+        let diagnostics = self.workspace.diagnostics(&path);
+
+        let actions: Vec<Value> = self.workspace.revisions(&path, start, end).into_iter()
+            .map(|note| {
+                let at = position(&index, note.offset);
+                let mut action = json!({
+                    "title": note.title,
+                    "kind": "quickfix",
+                    "edit": { "changes": { uri.clone(): [{ "range": { "start": at, "end": at }, "newText": note.note }] } },
+                });
+                if let Some(diagnostic) = note.violation.and_then(|idx| diagnostics.get(idx)) {
+                    action["diagnostics"] = json!([diagnostic_json(&index, diagnostic)]);
+                }
+                action
+            })
+            .collect();
+        Ok(Value::Array(actions))
+    }
+
+    // ============================================================
+    // INTERNOS
+    // ============================================================
+
+    /// Carga del disco todos los capítulos y fichas bajo la raíz
+    fn scan_root(&mut self) -> Vec<String> {
+        let Some(root) = self.root.clone() else {
+            return vec![];
+        };
+        let mut files = Vec::new();
+        collect_files(&root, &mut files);
+
+        let mut touched = Vec::new();
+        for file in files {
+            let path = file.to_string_lossy().to_string();
+            if self.open.contains(&path) {
+                continue;
+            }
+            let Ok(text) = fs::read_to_string(&file) else {
+                continue;
+            };
+            if document_kind(&path, &text).is_some() {
+                touched.extend(self.workspace.update(&path, &text));
+            }
+        }
+        touched
+    }
+
+    fn in_project(&self, path: &str) -> bool {
+        self.root.as_ref().is_some_and(|root| Path::new(path).starts_with(root))
+    }
+
+    /// `textDocument/publishDiagnostics` para cada capítulo tocado (una vez cada uno)
+    fn publish(&mut self, mut paths: Vec<String>) -> io::Result<()> {
+        paths.sort();
+        paths.dedup();
+        for path in paths {
+            let diagnostics: Vec<Value> = match self.workspace.text(&path) {
+                Some(text) => {
+                    let index = LineIndex::new(text);
+                    self.workspace.diagnostics(&path).iter().map(|d| diagnostic_json(&index, d)).collect()
+                },
+                None => vec![],
+            };
+            let uri = self.uris.get(&path).cloned().unwrap_or_else(|| path_to_uri(&path));
+            self.send(json!({
+                "jsonrpc": "2.0",
+                "method": "textDocument/publishDiagnostics",
+                "params": { "uri": uri, "diagnostics": diagnostics },
+            }))?;
+        }
+        Ok(())
+    }
+
+    fn respond_error(&mut self, id: Value, code: i64, message: &str) -> io::Result<Flow> {
+        self.send(json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } }))?;
+        Ok(Flow::Continue)
+    }
+
+    fn send(&mut self, message: Value) -> io::Result<()> {
+        let body = message.to_string();
+        write!(self.output, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+        self.output.flush()
+    }
+}
+
+fn diagnostic_json(index: &LineIndex, diagnostic: &ChapterDiagnostic) -> Value {
+    let violation = &diagnostic.violation;
+    let severity = match violation.severity {
+        ViolationSeverity::Critical => 1,
+        ViolationSeverity::Warning => 2,
+        ViolationSeverity::Notice => 3,
+    };
+    json!({
+        "range": { "start": position(index, diagnostic.start), "end": position(index, diagnostic.end) },
+        "severity": severity,
+        "code": format!("{:?}", violation.violation_type),
+        "source": format!("soulforge · {}", diagnostic.soul),
+        "message": violation.description,
+        "data": { "suggestion": violation.suggestion, "reason": violation.rationale.reason },
+    })
+}
+
+fn position(index: &LineIndex, offset: usize) -> Value {
+    let (line, character) = index.position(offset);
+    json!({ "line": line, "character": character })
+}
+
+fn offset_of(index: &LineIndex, position: &Value) -> usize {
+    let line = position["line"].as_u64().unwrap_or(0) as usize;
+    let character = position["character"].as_u64().unwrap_or(0) as usize;
+    index.offset(line, character)
+}
+
+// ============================================================
+// TRANSPORTE Y RUTAS
+// ============================================================
+
+/// Un mensaje `Content-Length: N\r\n\r\n<json>`. Solo un fallo de E/S es
+/// `Err`: sin `Content-Length` se descartan las cabeceras y un cuerpo que no
+/// es JSON se consume entero, así el siguiente mensaje se lee limpio
+fn read_message(input: &mut impl BufRead) -> io::Result<Incoming> {
+    let mut length: Option<usize> = None;
+    loop {
+        let mut header = String::new();
+        if input.read_line(&mut header)? == 0 {
+            return Ok(Incoming::Closed);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse().ok();
+            }
+        }
+    }
+
+    let Some(length) = length else {
+        return Ok(Incoming::Malformed("mensaje sin Content-Length".to_string()));
+    };
+    let mut body = vec![0; length];
+    input.read_exact(&mut body)?;
+    Ok(match serde_json::from_slice(&body) {
+        Ok(message) => Incoming::Message(message),
+        Err(e) => Incoming::Malformed(format!("JSON-RPC inválido: {}", e)),
+    })
+}
+
+/// Ruta local y URI de un `{ "uri": ... }`
+fn document_path(document: &Value) -> Option<(String, String)> {
+    let uri = document["uri"].as_str()?;
+    let path = uri_to_path(uri)?;
+    Some((path.to_string_lossy().to_string(), uri.to_string()))
+}
+
+fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let rest = uri.strip_prefix("file://")?;
+    let decoded = percent_decode(rest);
+    // file:///C:/... en Windows
+    let trimmed = match decoded.as_bytes() {
+        [b'/', drive, b':', ..] if drive.is_ascii_alphabetic() => &decoded[1..],
+        _ => decoded.as_str(),
+    };
+    Some(PathBuf::from(trimmed))
+}
+
+fn path_to_uri(path: &str) -> String {
+    let mut uri = String::from("file://");
+    if !path.starts_with('/') {
+        uri.push('/');
+    }
+    for byte in path.replace('\\', "/").bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' | b':' => uri.push(byte as char),
+            _ => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    uri
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            },
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            },
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut entries: Vec<PathBuf> = entries.flatten().map(|e| e.path()).collect();
+    entries.sort();
+    for path in entries {
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        if name.starts_with('.') {
+            continue;
+        }
+        if path.is_dir() {
+            if !SKIPPED_DIRS.contains(&name) {
+                collect_files(&path, files);
+            }
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHEET: &str = "---\nname: Aria\nmoral_code:\n  - Honor ante todo\n---\n## Miedo\nla oscuridad\n";
+    const CHAPTER: &str = "# Capítulo 1\n\nAl alba traicionó su palabra.\n";
+
+    fn frame(body: &str) -> String {
+        format!("Content-Length: {}\r\n\r\n{}", body.len(), body)
+    }
+
+    fn did_open(uri: &str, text: &str) -> String {
+        frame(&json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": { "textDocument": { "uri": uri, "languageId": "markdown", "version": 1, "text": text } },
+        }).to_string())
+    }
+
+    fn replies(output: &[u8]) -> Vec<Value> {
+        let mut output = output;
+        let mut replies = Vec::new();
+        while let Incoming::Message(message) = read_message(&mut output).unwrap() {
+            replies.push(message);
+        }
+        replies
+    }
+
+    #[test]
+    fn stdio_session_publishes_diagnostics_and_survives_malformed_messages() {
+        let input = [
+            frame(r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"initializationOptions":{"language":"es"}}}"#),
+            frame("{no es json"),
+            "X-Basura: 1\r\n\r\n".to_string(),
+            did_open("file:///libro/aria.md", SHEET),
+            did_open("file:///libro/cap1.md", CHAPTER),
+            frame(r#"{"jsonrpc":"2.0","id":2,"method":"shutdown"}"#),
+            frame(r#"{"jsonrpc":"2.0","method":"exit"}"#),
+        ].concat();
+
+        let mut server = Server::new(Vec::new());
+        let code = server.run(&mut input.as_bytes());
+        assert_eq!(code, ExitCode::SUCCESS);
+
+        let replies = replies(&server.output);
+        assert_eq!(replies[0]["id"], 1);
+        assert_eq!(replies[0]["result"]["capabilities"]["hoverProvider"], true);
+        for reply in &replies[1..3] {
+            assert_eq!(reply["id"], Value::Null);
+            assert_eq!(reply["error"]["code"], PARSE_ERROR);
+        }
+
+        let published = &replies[3];
+        assert_eq!(published["method"], "textDocument/publishDiagnostics");
+        assert_eq!(published["params"]["uri"], "file:///libro/cap1.md");
+        let diagnostic = &published["params"]["diagnostics"][0];
+        assert_eq!(diagnostic["source"], "soulforge · Aria");
+        assert_eq!(diagnostic["range"]["start"], json!({ "line": 2, "character": 8 }));
+        assert_eq!(replies[4]["id"], 2);
+        assert_eq!(replies.len(), 5);
+    }
+
+    #[test]
+    fn end_of_input_without_exit_is_a_failure() {
+        let input = frame(r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#);
+        let mut server = Server::new(Vec::new());
+        assert_eq!(server.run(&mut input.as_bytes()), ExitCode::FAILURE);
+        assert_eq!(replies(&server.output).len(), 1);
+    }
+}
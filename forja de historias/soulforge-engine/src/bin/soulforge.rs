@@ -0,0 +1,371 @@
+//! `soulforge` - Análisis de manuscritos por lotes desde la línea de comandos
+//!
+//! Todas las salidas son JSON por stdout; los errores van a stderr con
+//! código de salida distinto de cero.
+
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use soulforge_engine::*;
+
+const USAGE: &str = "\
+soulforge - motor narrativo SoulForge
+
+USO:
+    soulforge parse <ficha>...
+    soulforge diagnose <ficha.html>...
+    soulforge detect <ficha.html>... --profile <perfil.json>
+    soulforge render <ficha>...
+    soulforge tension <ficha> <texto>...
+    soulforge ensemble <texto> <ficha>...
+    soulforge audit <ficha> <texto>...
+    soulforge inflections <ficha>...
+    soulforge evolve <ficha> <punto> <desenlace> <capítulo>
+    soulforge simulate <ficha>... [--runs N] [--rounds N] [--seed N]
+    soulforge curve <ficha> <manuscrito>... [--structure <forma>]
+    soulforge continuity <manuscrito> <ficha>...
+    soulforge prophecies <manuscrito> <ficha>...
+    soulforge nemesis <manuscrito> <ficha>...
+    soulforge world <mundo>...
+    soulforge world-effects <mundo> <ficha>...
+    soulforge schema <soul|world|coherence|tension>
+
+FICHAS:
+    .html / .htm   ficha viva (parse_living_sheet)
+    .md / .markdown  ficha Markdown con front matter YAML (---) o TOML (+++)
+    .json          LivingSoul serializado o Alma de soulforge-server
+
+MUNDOS:
+    .json / .toml  WorldSeed de autoría (validado campo a campo)
+
+OPCIONES:
+    --pretty       JSON indentado
+    --lang         es | en | jp: idioma de títulos, avisos y sugerencias
+                   (por defecto es)
+    --structure    three-act | freytag | kishotenketsu | rising-sawtooth
+                   (repetible; por defecto compara con todas)
+    --runs         historias simuladas (por defecto 1000)
+    --rounds       puntos de inflexión por historia (por defecto 5)
+    --seed         semilla del simulador (por defecto 0)
+    --profile      perfil de selectores JSON para fichas de otras herramientas
+                   (repetible; se elige el que más campos llena)
+    -h, --help     muestra esta ayuda
+";
+
+fn main() -> ExitCode {
+    let mut pretty = false;
+    let mut args: Vec<String> = Vec::new();
+    let mut structures: Vec<StoryStructure> = Vec::new();
+    let mut simulation = SimulationConfig::default();
+    let mut profiles: Vec<SelectorProfile> = Vec::new();
+    let mut lang = Language::default();
+    let mut raw = std::env::args().skip(1);
+
+    while let Some(arg) = raw.next() {
+        match arg.as_str() {
+            "--pretty" => pretty = true,
+            "--structure" => {
+                let value = raw.next().unwrap_or_default();
+                match StoryStructure::parse(&value) {
+                    Some(structure) => structures.push(structure),
+                    None => {
+                        eprintln!("error: estructura desconocida '{}'\n\n{}", value, USAGE);
+                        return ExitCode::from(2);
+                    },
+                }
+            },
+            "--lang" => {
+                let value = raw.next().unwrap_or_default();
+                match Language::parse(&value) {
+                    Some(parsed) => lang = parsed,
+                    None => {
+                        eprintln!("error: idioma desconocido '{}'\n\n{}", value, USAGE);
+                        return ExitCode::from(2);
+                    },
+                }
+            },
+            "--runs" | "--rounds" | "--seed" => {
+                let value = raw.next().unwrap_or_default();
+                let parsed = match arg.as_str() {
+                    "--runs" => value.parse().map(|n| simulation.runs = n).is_ok(),
+                    "--rounds" => value.parse().map(|n| simulation.rounds = n).is_ok(),
+                    _ => value.parse().map(|n| simulation.seed = n).is_ok(),
+                };
+                if !parsed {
+                    eprintln!("error: valor inválido '{}' para {}\n\n{}", value, arg, USAGE);
+                    return ExitCode::from(2);
+                }
+            },
+            "--profile" => {
+                let path = raw.next().unwrap_or_default();
+                match read(&path).and_then(|json| {
+                    load_selector_profiles(&json).map_err(|errors| {
+                        errors.iter()
+                            .map(|e| format!("{}: {}", path, e))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    })
+                }) {
+                    Ok(loaded) => profiles.extend(loaded),
+                    Err(e) => {
+                        eprintln!("error: {}\n\n{}", e, USAGE);
+                        return ExitCode::from(2);
+                    },
+                }
+            },
+            "-h" | "--help" => {
+                print!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            },
+            _ => args.push(arg),
+        }
+    }
+
+    simulation.language = lang;
+    let options = Options { structures, simulation, profiles, lang };
+
+    match run(&args, &options) {
+        Ok(output) => {
+            let rendered = if pretty {
+                serde_json::to_string_pretty(&output)
+            } else {
+                serde_json::to_string(&output)
+            };
+            match rendered {
+                Ok(text) => {
+                    println!("{}", text);
+                    ExitCode::SUCCESS
+                },
+                Err(e) => {
+                    eprintln!("error: no se pudo serializar la salida: {}", e);
+                    ExitCode::FAILURE
+                },
+            }
+        },
+        Err(msg) => {
+            eprintln!("error: {}\n\n{}", msg, USAGE);
+            ExitCode::from(2)
+        },
+    }
+}
+
+struct Options {
+    structures: Vec<StoryStructure>,
+    simulation: SimulationConfig,
+    profiles: Vec<SelectorProfile>,
+    lang: Language,
+}
+
+fn run(args: &[String], options: &Options) -> Result<Value, String> {
+    let (command, rest) = args.split_first()
+        .ok_or_else(|| "falta el comando".to_string())?;
+
+    match command.as_str() {
+        "parse" => {
+            require(rest, 1, "parse necesita al menos una ficha")?;
+            per_file(rest, |path| load_soul(path, options).and_then(|soul| to_value(&soul)))
+        },
+        "diagnose" => {
+            require(rest, 1, "diagnose necesita al menos una ficha HTML")?;
+            per_file(rest, |path| {
+                let html = read(path)?;
+                let chosen = parse_living_sheet_auto(&html, &options.profiles).profile;
+                let profile = options.profiles.iter()
+                    .find(|p| p.name == chosen)
+                    .cloned()
+                    .unwrap_or_else(SelectorProfile::default_profile);
+                to_value(&diagnose_living_sheet_with(&html, &profile))
+            })
+        },
+        "detect" => {
+            require(rest, 1, "detect necesita al menos una ficha HTML")?;
+            per_file(rest, |path| {
+                let detected = parse_living_sheet_auto(&read(path)?, &options.profiles);
+                Ok(json!({ "profile": detected.profile, "scores": to_value(&detected.scores)? }))
+            })
+        },
+        "render" => {
+            require(rest, 1, "render necesita al menos una ficha")?;
+            per_file(rest, |path| load_soul(path, options).map(|soul| Value::String(render_living_sheet(&soul))))
+        },
+        "tension" => {
+            require(rest, 2, "tension necesita una ficha y al menos un texto")?;
+            let soul = load_soul(&rest[0], options)?;
+            per_file(&rest[1..], |path| {
+                let text = read(path)?;
+                to_value(&calculate_soul_tension(&soul, &text, options.lang))
+            })
+        },
+        "ensemble" => {
+            require(rest, 2, "ensemble necesita un texto y al menos una ficha")?;
+            let souls = rest[1..].iter()
+                .map(|path| load_soul(path, options))
+                .collect::<Result<Vec<_>, _>>()?;
+            to_value(&calculate_souls_tension(&souls, &read(&rest[0])?, options.lang))
+        },
+        "audit" => {
+            require(rest, 2, "audit necesita una ficha y al menos un texto")?;
+            let soul = load_soul(&rest[0], options)?;
+            per_file(&rest[1..], |path| {
+                let text = read(path)?;
+                to_value(&check_soul_violations(&soul, &text, options.lang))
+            })
+        },
+        "inflections" => {
+            require(rest, 1, "inflections necesita al menos una ficha")?;
+            per_file(rest, |path| {
+                load_soul(path, options).and_then(|soul| to_value(&create_soul_inflection_points(&soul, options.lang)))
+            })
+        },
+        "evolve" => {
+            require(rest, 4, "evolve necesita una ficha, un punto (ip_N), un desenlace y un capítulo")?;
+            let soul = load_soul(&rest[0], options)?;
+            let points = create_soul_inflection_points(&soul, options.lang);
+            let point = points.iter()
+                .find(|p| p.id == rest[1])
+                .ok_or_else(|| format!("no existe el punto de inflexión '{}'", rest[1]))?;
+            let outcome = rest[2].parse::<usize>().ok()
+                .and_then(|i| point.potential_outcomes.get(i))
+                .ok_or_else(|| format!("desenlace inválido '{}' para {}", rest[2], point.id))?;
+            let chapter = rest[3].parse::<u32>()
+                .map_err(|_| format!("capítulo inválido '{}'", rest[3]))?;
+            to_value(&apply_outcome(&soul, point, outcome, chapter, options.lang))
+        },
+        "simulate" => {
+            require(rest, 1, "simulate necesita al menos una ficha")?;
+            per_file(rest, |path| {
+                load_soul(path, options).and_then(|soul| to_value(&simulate_soul_destiny(&soul, &options.simulation)))
+            })
+        },
+        "curve" => {
+            require(rest, 2, "curve necesita una ficha y al menos un manuscrito")?;
+            let soul = load_soul(&rest[0], options)?;
+            per_file(&rest[1..], |path| {
+                let chapters = split_manuscript(&read(path)?, options.lang);
+                to_value(&analyze_chapters(&soul, &chapters, &options.structures, options.lang))
+            })
+        },
+        "continuity" => {
+            require(rest, 2, "continuity necesita un manuscrito y al menos una ficha")?;
+            let souls = rest[1..].iter()
+                .map(|path| load_soul(path, options))
+                .collect::<Result<Vec<_>, _>>()?;
+            let chapters = split_manuscript(&read(&rest[0])?, options.lang);
+            to_value(&audit_chapters(&souls, &chapters, options.lang))
+        },
+        "prophecies" => {
+            require(rest, 2, "prophecies necesita un manuscrito y al menos una ficha")?;
+            let souls = rest[1..].iter()
+                .map(|path| load_soul(path, options))
+                .collect::<Result<Vec<_>, _>>()?;
+            let chapters = split_manuscript(&read(&rest[0])?, options.lang);
+            to_value(&track_chapters(&souls, &chapters, options.lang))
+        },
+        "nemesis" => {
+            require(rest, 2, "nemesis necesita un manuscrito y al menos una ficha")?;
+            let souls = rest[1..].iter()
+                .map(|path| load_soul(path, options))
+                .collect::<Result<Vec<_>, _>>()?;
+            let chapters = split_manuscript(&read(&rest[0])?, options.lang);
+            to_value(&track_nemesis_chapters(&souls, &chapters, options.lang))
+        },
+        "world" => {
+            require(rest, 1, "world necesita al menos un mundo")?;
+            per_file(rest, |path| load_world(path).and_then(|world| to_value(&world)))
+        },
+        "world-effects" => {
+            require(rest, 2, "world-effects necesita un mundo y al menos una ficha")?;
+            let world = load_world(&rest[0])?;
+            per_file(&rest[1..], |path| {
+                let soul = load_soul(path, options)?;
+                let mut standing = FactionStanding::new(&soul, &world);
+                standing.apply_chronicle(&world, &soul.chronicle);
+                Ok(json!({
+                    "attributes": to_value(&resolve_law_effects(&soul, &world, &base_attributes()))?,
+                    "standing": to_value(&standing)?,
+                }))
+            })
+        },
+        "schema" => {
+            let names: Vec<&str> = ENGINE_SCHEMAS.iter().map(|(name, _, _)| *name).collect();
+            rest.first()
+                .and_then(|name| engine_schema(name))
+                .ok_or_else(|| format!("schema necesita un tipo: {}", names.join(" | ")))
+        },
+        other => Err(format!("comando desconocido '{}'", other)),
+    }
+}
+
+/// Un solo archivo devuelve el resultado tal cual; varios, una lista `{file, result}`
+fn per_file<F>(paths: &[String], mut analyze: F) -> Result<Value, String>
+where
+    F: FnMut(&str) -> Result<Value, String>,
+{
+    if let [single] = paths {
+        return analyze(single);
+    }
+
+    let mut results = Vec::with_capacity(paths.len());
+    for path in paths {
+        results.push(json!({ "file": path, "result": analyze(path)? }));
+    }
+    Ok(Value::Array(results))
+}
+
+fn load_soul(path: &str, options: &Options) -> Result<LivingSoul, String> {
+    let content = read(path)?;
+    let extension = Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "html" | "htm" if options.profiles.is_empty() => Ok(parse_living_sheet(&content)),
+        "html" | "htm" => Ok(parse_living_sheet_auto(&content, &options.profiles).soul),
+        "md" | "markdown" => parse_markdown_sheet_strict(&content)
+            .map_err(|e| format!("{}: {}", path, e)),
+        "json" => {
+            // Primero como LivingSoul; si no encaja, como Alma del servidor
+            parse_json::<LivingSoul>("soul", &content)
+                .or_else(|e| soul_from_alma_json(&content).map_err(|_| e))
+                .map_err(|e| format!("{}: JSON de alma inválido: {}", path, e))
+        },
+        _ => Err(format!("{}: formato de ficha no soportado", path)),
+    }
+}
+
+fn load_world(path: &str) -> Result<WorldSeed, String> {
+    let content = read(path)?;
+    let format = Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .and_then(WorldFormat::from_extension)
+        .ok_or_else(|| format!("{}: formato de mundo no soportado", path))?;
+
+    load_world_seed(&content, format).map_err(|errors| {
+        errors.iter()
+            .map(|e| format!("{}: {}", path, e))
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
+}
+
+fn read(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))
+}
+
+fn require(args: &[String], min: usize, msg: &str) -> Result<(), String> {
+    if args.len() < min {
+        Err(msg.to_string())
+    } else {
+        Ok(())
+    }
+}
+
+fn to_value<T: Serialize>(value: &T) -> Result<Value, String> {
+    serde_json::to_value(value).map_err(|e| e.to_string())
+}
@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use crate::forge_error::{parse_json, ForgeError};
+use crate::i18n::{tr, Language, TemplateArg};
 use crate::soul_parser::*;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -49,123 +51,78 @@ pub enum ChangeType {
     Fulfill,    // Cumplir una profecía
 }
 
-pub fn create_inflection_points(soul_json: &str) -> Vec<InflectionPoint> {
-    let soul: LivingSoul = match serde_json::from_str(soul_json) {
-        Ok(s) => s,
-        Err(_) => return vec![],
-    };
-    
+pub fn create_inflection_points(soul_json: &str, lang: Language) -> Vec<InflectionPoint> {
+    try_create_inflection_points(soul_json, lang).unwrap_or_default()
+}
+
+/// Como `create_inflection_points`, pero un alma inválida es un error y no una lista vacía
+pub fn try_create_inflection_points(soul_json: &str, lang: Language) -> Result<Vec<InflectionPoint>, ForgeError> {
+    let soul: LivingSoul = parse_json("soul", soul_json)?;
+    Ok(create_soul_inflection_points(&soul, lang))
+}
+
+/// Puntos de inflexión para un alma ya deserializada, redactados en `lang`
+pub fn create_soul_inflection_points(soul: &LivingSoul, lang: Language) -> Vec<InflectionPoint> {
     let mut points: Vec<InflectionPoint> = Vec::new();
     let mut point_id = 0;
+    let t = |key: &str, args: &[TemplateArg]| tr(lang, key, args);
+    let outcome = |key: &str, args: &[TemplateArg], element: String, change_type, value: Option<String>, weight| Outcome {
+        description: t(key, args),
+        soul_impact: SoulImpact {
+            affected_element: element,
+            change_type,
+            new_value: value,
+        },
+        probability_weight: weight,
+    };
     
     // === PUNTOS BASADOS EN MIEDO ===
     if !soul.psyche.core_fear.is_empty() {
         point_id += 1;
+        let fear = &soul.psyche.core_fear;
         points.push(InflectionPoint {
             id: format!("ip_{}", point_id),
-            title: format!("La Prueba del Terror"),
-            description: format!(
-                "{} debe enfrentar directamente su miedo más profundo: {}. \
-                No hay escape, no hay rodeo.",
-                soul.name, soul.psyche.core_fear
-            ),
+            title: t("conflict.fear.title", &[]),
+            description: t("conflict.fear.description", &[("name", &soul.name), ("fear", fear)]),
             conflict_type: ConflictType::FearFacing,
             intensity: 0.85,
             triggered_by: vec!["psyche.core_fear".to_string()],
             potential_outcomes: vec![
-                Outcome {
-                    description: "Supera el miedo, gana control".to_string(),
-                    soul_impact: SoulImpact {
-                        affected_element: "psyche.core_fear".to_string(),
-                        change_type: ChangeType::Transform,
-                        new_value: Some("miedo superado -> fortaleza".to_string()),
-                    },
-                    probability_weight: 0.3,
-                },
-                Outcome {
-                    description: "Colapsa, el miedo lo consume temporalmente".to_string(),
-                    soul_impact: SoulImpact {
-                        affected_element: "psyche.core_fear".to_string(),
-                        change_type: ChangeType::Deepen,
-                        new_value: None,
-                    },
-                    probability_weight: 0.4,
-                },
-                Outcome {
-                    description: "Huye, pero el miedo lo perseguirá".to_string(),
-                    soul_impact: SoulImpact {
-                        affected_element: "scars".to_string(),
-                        change_type: ChangeType::Deepen,
-                        new_value: Some("Nueva cicatriz: La Huida".to_string()),
-                    },
-                    probability_weight: 0.3,
-                },
+                outcome("conflict.fear.overcome", &[], "psyche.core_fear".to_string(),
+                    ChangeType::Transform, Some(t("conflict.fear.overcome_value", &[])), 0.3),
+                outcome("conflict.fear.collapse", &[], "psyche.core_fear".to_string(),
+                    ChangeType::Deepen, None, 0.4),
+                outcome("conflict.fear.flee", &[], "scars".to_string(),
+                    ChangeType::Deepen, Some(t("conflict.fear.flee_scar", &[])), 0.3),
             ],
-            narrative_hook: format!(
-                "Y entonces, sin previo aviso, {} se materializó ante sus ojos. \
-                El único escape era atravesarlo.",
-                soul.psyche.core_fear
-            ),
+            narrative_hook: t("conflict.fear.hook", &[("fear", fear)]),
         });
     }
     
     // === PUNTOS BASADOS EN NÉMESIS ===
     if let Some(ref nemesis) = soul.nemesis {
         point_id += 1;
+        let names: [TemplateArg; 2] = [("name", &soul.name), ("nemesis", &nemesis.name)];
         points.push(InflectionPoint {
             id: format!("ip_{}", point_id),
-            title: format!("El Encuentro con {}", nemesis.name),
-            description: format!(
-                "El conflicto entre {} y {} alcanza su punto crítico. \
-                El origen de su enemistad ({}) ya no puede ignorarse.",
-                soul.name, nemesis.name, nemesis.conflict_core
-            ),
+            title: t("conflict.nemesis.title", &names),
+            description: t("conflict.nemesis.description", &[
+                ("name", &soul.name), ("nemesis", &nemesis.name), ("conflict", &nemesis.conflict_core),
+            ]),
             conflict_type: ConflictType::NemesisConfrontation,
             intensity: 0.95,
             triggered_by: vec!["nemesis".to_string()],
             potential_outcomes: vec![
-                Outcome {
-                    description: format!("Derrota a {}", nemesis.name),
-                    soul_impact: SoulImpact {
-                        affected_element: "nemesis".to_string(),
-                        change_type: ChangeType::Transform,
-                        new_value: Some("Némesis derrotada".to_string()),
-                    },
-                    probability_weight: 0.25,
-                },
-                Outcome {
-                    description: "Reconciliación inesperada".to_string(),
-                    soul_impact: SoulImpact {
-                        affected_element: "nemesis".to_string(),
-                        change_type: ChangeType::Transform,
-                        new_value: Some("Antiguo enemigo -> aliado incómodo".to_string()),
-                    },
-                    probability_weight: 0.15,
-                },
-                Outcome {
-                    description: format!("Pierde contra {}", nemesis.name),
-                    soul_impact: SoulImpact {
-                        affected_element: "scars".to_string(),
-                        change_type: ChangeType::Deepen,
-                        new_value: Some("Nueva cicatriz de derrota".to_string()),
-                    },
-                    probability_weight: 0.35,
-                },
-                Outcome {
-                    description: "Empate que deja todo más enredado".to_string(),
-                    soul_impact: SoulImpact {
-                        affected_element: "tension_markers".to_string(),
-                        change_type: ChangeType::Deepen,
-                        new_value: None,
-                    },
-                    probability_weight: 0.25,
-                },
+                outcome("conflict.nemesis.defeat", &names, "nemesis".to_string(),
+                    ChangeType::Transform, Some(t("conflict.nemesis.defeat_value", &[])), 0.25),
+                outcome("conflict.nemesis.reconcile", &names, "nemesis".to_string(),
+                    ChangeType::Transform, Some(t("conflict.nemesis.reconcile_value", &[])), 0.15),
+                outcome("conflict.nemesis.lose", &names, "scars".to_string(),
+                    ChangeType::Deepen, Some(t("conflict.nemesis.lose_scar", &[])), 0.35),
+                outcome("conflict.nemesis.draw", &names, "tension_markers".to_string(),
+                    ChangeType::Deepen, None, 0.25),
             ],
-            narrative_hook: format!(
-                "\"{}...\" La voz de {} cortó el silencio como una cuchilla. \
-                \"Sabía que este día llegaría.\"",
-                soul.name, nemesis.name
-            ),
+            narrative_hook: t("conflict.nemesis.hook", &names),
         });
     }
     
@@ -178,56 +135,30 @@ pub fn create_inflection_points(soul_json: &str) -> Vec<InflectionPoint> {
                 ProphecyType::Choice => ConflictType::MoralDilemma,
                 _ => ConflictType::ProphecyFulfillment,
             };
+            let element = format!("prophecies.{}", prophecy.id);
             
             points.push(InflectionPoint {
                 id: format!("ip_{}", point_id),
-                title: format!("El Peso del Destino"),
-                description: format!(
-                    "La profecía '{}' comienza a manifestarse. \
-                    {} debe decidir si luchar contra el destino o aceptarlo.",
-                    prophecy.text, soul.name
-                ),
+                title: t("conflict.prophecy.title", &[]),
+                description: t("conflict.prophecy.description", &[
+                    ("prophecy", &prophecy.text), ("name", &soul.name),
+                ]),
                 conflict_type,
                 intensity: match prophecy.prophecy_type {
                     ProphecyType::Doom => 0.9,
                     ProphecyType::Choice => 0.75,
                     _ => 0.6,
                 },
-                triggered_by: vec![format!("prophecies.{}", prophecy.id)],
+                triggered_by: vec![element.clone()],
                 potential_outcomes: vec![
-                    Outcome {
-                        description: "Cumple la profecía".to_string(),
-                        soul_impact: SoulImpact {
-                            affected_element: format!("prophecies.{}", prophecy.id),
-                            change_type: ChangeType::Fulfill,
-                            new_value: Some("fulfilled: true".to_string()),
-                        },
-                        probability_weight: 0.4,
-                    },
-                    Outcome {
-                        description: "Desafía y reescribe su destino".to_string(),
-                        soul_impact: SoulImpact {
-                            affected_element: format!("prophecies.{}", prophecy.id),
-                            change_type: ChangeType::Transform,
-                            new_value: Some("Destino reescrito".to_string()),
-                        },
-                        probability_weight: 0.3,
-                    },
-                    Outcome {
-                        description: "Pospone lo inevitable".to_string(),
-                        soul_impact: SoulImpact {
-                            affected_element: "tension_markers.prophecy_pressure".to_string(),
-                            change_type: ChangeType::Deepen,
-                            new_value: None,
-                        },
-                        probability_weight: 0.3,
-                    },
+                    outcome("conflict.prophecy.fulfill", &[], element.clone(),
+                        ChangeType::Fulfill, Some("fulfilled: true".to_string()), 0.4),
+                    outcome("conflict.prophecy.defy", &[], element,
+                        ChangeType::Transform, Some(t("conflict.prophecy.defy_value", &[])), 0.3),
+                    outcome("conflict.prophecy.postpone", &[], "tension_markers.prophecy_pressure".to_string(),
+                        ChangeType::Deepen, None, 0.3),
                 ],
-                narrative_hook: format!(
-                    "Las palabras del antiguo oráculo resonaron en su mente: \
-                    \"{}\". Y hoy, esas palabras cobraban vida.",
-                    prophecy.text
-                ),
+                narrative_hook: t("conflict.prophecy.hook", &[("prophecy", &prophecy.text)]),
             });
         }
     }
@@ -236,51 +167,25 @@ pub fn create_inflection_points(soul_json: &str) -> Vec<InflectionPoint> {
     for scar in &soul.scars {
         if !scar.healed && scar.narrative_weight > 0.6 {
             point_id += 1;
+            let element = format!("scars.{}", scar.id);
             points.push(InflectionPoint {
                 id: format!("ip_{}", point_id),
-                title: format!("Las Heridas que Hablan"),
-                description: format!(
-                    "La cicatriz '{}' (origen: {}) se abre de nuevo. \
-                    {} debe procesarla o será consumido.",
-                    scar.name, scar.origin_event, soul.name
-                ),
+                title: t("conflict.scar.title", &[]),
+                description: t("conflict.scar.description", &[
+                    ("scar", &scar.name), ("origin", &scar.origin_event), ("name", &soul.name),
+                ]),
                 conflict_type: ConflictType::ScarReopening,
                 intensity: scar.narrative_weight,
-                triggered_by: vec![format!("scars.{}", scar.id)],
+                triggered_by: vec![element.clone()],
                 potential_outcomes: vec![
-                    Outcome {
-                        description: "Sanación a través del dolor".to_string(),
-                        soul_impact: SoulImpact {
-                            affected_element: format!("scars.{}", scar.id),
-                            change_type: ChangeType::Heal,
-                            new_value: Some("healed: true".to_string()),
-                        },
-                        probability_weight: 0.35,
-                    },
-                    Outcome {
-                        description: "La herida se infecta más".to_string(),
-                        soul_impact: SoulImpact {
-                            affected_element: format!("scars.{}", scar.id),
-                            change_type: ChangeType::Deepen,
-                            new_value: Some("narrative_weight: increased".to_string()),
-                        },
-                        probability_weight: 0.35,
-                    },
-                    Outcome {
-                        description: "Transforma el dolor en propósito".to_string(),
-                        soul_impact: SoulImpact {
-                            affected_element: format!("scars.{}", scar.id),
-                            change_type: ChangeType::Transform,
-                            new_value: Some("Cicatriz convertida en fuerza".to_string()),
-                        },
-                        probability_weight: 0.3,
-                    },
+                    outcome("conflict.scar.heal", &[], element.clone(),
+                        ChangeType::Heal, Some("healed: true".to_string()), 0.35),
+                    outcome("conflict.scar.fester", &[], element.clone(),
+                        ChangeType::Deepen, Some("narrative_weight: increased".to_string()), 0.35),
+                    outcome("conflict.scar.purpose", &[], element,
+                        ChangeType::Transform, Some(t("conflict.scar.purpose_value", &[])), 0.3),
                 ],
-                narrative_hook: format!(
-                    "No esperaba que {} le recordara tanto a aquel día. \
-                    El día de {}. Su mano tembló involuntariamente.",
-                    "aquello", scar.origin_event
-                ),
+                narrative_hook: t("conflict.scar.hook", &[("origin", &scar.origin_event)]),
             });
         }
     }
@@ -289,105 +194,48 @@ pub fn create_inflection_points(soul_json: &str) -> Vec<InflectionPoint> {
     for bond in &soul.bonds {
         if matches!(bond.status, BondStatus::Strained) {
             point_id += 1;
+            let element = format!("bonds.{}", bond.entity_name);
             points.push(InflectionPoint {
                 id: format!("ip_{}", point_id),
-                title: format!("La Prueba del Vínculo"),
-                description: format!(
-                    "La relación entre {} y {} ({:?}) ha llegado a un punto crítico. \
-                    Una decisión los unirá para siempre o los separará definitivamente.",
-                    soul.name, bond.entity_name, bond.bond_type
-                ),
+                title: t("conflict.bond.title", &[]),
+                description: t("conflict.bond.description", &[
+                    ("name", &soul.name), ("entity", &bond.entity_name), ("bond_type", &format!("{:?}", bond.bond_type)),
+                ]),
                 conflict_type: ConflictType::BondTest,
                 intensity: 0.7,
-                triggered_by: vec![format!("bonds.{}", bond.entity_name)],
+                triggered_by: vec![element.clone()],
                 potential_outcomes: vec![
-                    Outcome {
-                        description: "Fortalecen el vínculo".to_string(),
-                        soul_impact: SoulImpact {
-                            affected_element: format!("bonds.{}", bond.entity_name),
-                            change_type: ChangeType::Strengthen,
-                            new_value: Some("status: Active, strength: increased".to_string()),
-                        },
-                        probability_weight: 0.4,
-                    },
-                    Outcome {
-                        description: "El vínculo se rompe".to_string(),
-                        soul_impact: SoulImpact {
-                            affected_element: format!("bonds.{}", bond.entity_name),
-                            change_type: ChangeType::Break,
-                            new_value: Some("status: Broken".to_string()),
-                        },
-                        probability_weight: 0.3,
-                    },
-                    Outcome {
-                        description: "Transformación de la relación".to_string(),
-                        soul_impact: SoulImpact {
-                            affected_element: format!("bonds.{}", bond.entity_name),
-                            change_type: ChangeType::Transform,
-                            new_value: Some("bond_type: changed".to_string()),
-                        },
-                        probability_weight: 0.3,
-                    },
+                    outcome("conflict.bond.strengthen", &[], element.clone(),
+                        ChangeType::Strengthen, Some("status: Active, strength: increased".to_string()), 0.4),
+                    outcome("conflict.bond.break", &[], element.clone(),
+                        ChangeType::Break, Some("status: Broken".to_string()), 0.3),
+                    outcome("conflict.bond.transform", &[], element,
+                        ChangeType::Transform, Some("bond_type: changed".to_string()), 0.3),
                 ],
-                narrative_hook: format!(
-                    "\"No puedo seguir así\", dijo {}. \
-                    \"Necesito saber si todavía significo algo para ti.\"",
-                    bond.entity_name
-                ),
+                narrative_hook: t("conflict.bond.hook", &[("entity", &bond.entity_name)]),
             });
         }
     }
     
     // === PUNTO DE DILEMA MORAL ===
-    if !soul.psyche.moral_code.is_empty() {
+    if let Some(main_rule) = soul.psyche.moral_code.first() {
         point_id += 1;
-        let main_rule = soul.psyche.moral_code.first().unwrap();
         points.push(InflectionPoint {
             id: format!("ip_{}", point_id),
-            title: "El Peso de los Principios".to_string(),
-            description: format!(
-                "{} se enfrenta a una situación donde seguir su código moral ('{}') \
-                tendrá consecuencias devastadoras. Romperlo también.",
-                soul.name, main_rule
-            ),
+            title: t("conflict.moral.title", &[]),
+            description: t("conflict.moral.description", &[("name", &soul.name), ("rule", main_rule)]),
             conflict_type: ConflictType::MoralDilemma,
             intensity: 0.8,
             triggered_by: vec!["psyche.moral_code".to_string()],
             potential_outcomes: vec![
-                Outcome {
-                    description: "Mantiene su código, paga el precio".to_string(),
-                    soul_impact: SoulImpact {
-                        affected_element: "psyche.moral_code".to_string(),
-                        change_type: ChangeType::Strengthen,
-                        new_value: None,
-                    },
-                    probability_weight: 0.4,
-                },
-                Outcome {
-                    description: "Rompe su código, gana pero se pierde".to_string(),
-                    soul_impact: SoulImpact {
-                        affected_element: "scars".to_string(),
-                        change_type: ChangeType::Deepen,
-                        new_value: Some("Nueva cicatriz: Principios Rotos".to_string()),
-                    },
-                    probability_weight: 0.35,
-                },
-                Outcome {
-                    description: "Encuentra una tercera vía".to_string(),
-                    soul_impact: SoulImpact {
-                        affected_element: "psyche.moral_code".to_string(),
-                        change_type: ChangeType::Transform,
-                        new_value: Some("Código moral evolucionado".to_string()),
-                    },
-                    probability_weight: 0.25,
-                },
+                outcome("conflict.moral.keep", &[], "psyche.moral_code".to_string(),
+                    ChangeType::Strengthen, None, 0.4),
+                outcome("conflict.moral.break", &[], "scars".to_string(),
+                    ChangeType::Deepen, Some(t("conflict.moral.break_scar", &[])), 0.35),
+                outcome("conflict.moral.third_way", &[], "psyche.moral_code".to_string(),
+                    ChangeType::Transform, Some(t("conflict.moral.third_way_value", &[])), 0.25),
             ],
-            narrative_hook: format!(
-                "La elección era imposible. Si seguía su regla de '{}', \
-                aquellos que amaba sufrirían. Si la rompía, \
-                ¿en qué se convertiría?",
-                main_rule
-            ),
+            narrative_hook: t("conflict.moral.hook", &[("rule", main_rule)]),
         });
     }
     
@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
+use crate::forge_error::{parse_json, ForgeError};
+use crate::i18n::{fill, terms, terms_in_any, tr, Language, TemplateArg};
 use crate::soul_parser::LivingSoul;
+use crate::modality::{match_context, most_assertive, most_assertive_of, ContextModifier, MatchContext};
+use crate::text_analysis::{keywords, AnalyzedText, TermMatch};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CoherenceViolation {
@@ -9,10 +13,22 @@ pub struct CoherenceViolation {
     pub conflicting_element: String,
     pub source_reference: String,
     pub suggestion: String,
-    pub position_hint: Option<usize>,
+    pub position_hint: Option<usize>,       // Offset en caracteres
+    #[serde(default)]
+    pub rationale: ViolationRationale,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Por qué se levantó una violación y cómo la matizó el contexto
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ViolationRationale {
+    pub reason: String,                     // Regla que se disparó
+    pub matched_text: String,               // Texto tal como aparece
+    pub sentence: String,                   // Oración completa
+    pub modifiers: Vec<ContextModifier>,    // Negación, hipótesis, diálogo...
+    pub base_severity: Option<ViolationSeverity>, // Antes de atenuar, si cambió
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ViolationSeverity {
     Critical,   // Contradicción directa con hechos establecidos
     Warning,    // Inconsistencia probable
@@ -29,6 +45,7 @@ pub enum ViolationType {
     DeadCharacterAppears,    // Personaje muerto aparece vivo
     TimelineError,           // Error de cronología
     MoralCodeViolation,      // Viola código moral sin justificación
+    WorldLawBreach,          // Hace lo que una ley del mundo le prohíbe
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,33 +57,69 @@ pub struct CoherenceReport {
     pub suggested_revisions: Vec<String>,
 }
 
-pub fn check_violations(soul_json: &str, new_content: &str) -> CoherenceReport {
-    let soul: LivingSoul = match serde_json::from_str(soul_json) {
-        Ok(s) => s,
-        Err(_) => return CoherenceReport::empty(),
-    };
-    
+/// Reglas morales que el director sabe vigilar. Cada una tiene en los bancos
+/// `moral.<id>.rule` (cómo aparece en el código del alma) y
+/// `moral.<id>.triggers` (acciones que la violan).
+const MORAL_RULES: &[&str] = &["never_kill", "protect_innocents", "never_lie", "honor_first", "never_steal"];
+
+/// Arquetipos con acciones que los contradicen: `drift.<id>.archetype` / `drift.<id>.actions`
+const DRIFT_ARCHETYPES: &[&str] = &["hero", "sage", "rebel", "caregiver"];
+
+pub fn check_violations(soul_json: &str, new_content: &str, lang: Language) -> CoherenceReport {
+    try_check_violations(soul_json, new_content, lang).unwrap_or_else(|_| CoherenceReport::empty())
+}
+
+/// Como `check_violations`, pero un alma inválida es un error y no un informe vacío
+pub fn try_check_violations(soul_json: &str, new_content: &str, lang: Language) -> Result<CoherenceReport, ForgeError> {
+    let soul: LivingSoul = parse_json("soul", soul_json)?;
+    Ok(check_soul_violations(&soul, new_content, lang))
+}
+
+/// Auditoría de coherencia sobre un alma ya deserializada. El informe se
+/// redacta en `lang`; las frases se buscan en el idioma del propio texto.
+pub fn check_soul_violations(soul: &LivingSoul, new_content: &str, lang: Language) -> CoherenceReport {
     let mut violations: Vec<CoherenceViolation> = Vec::new();
-    let content_lower = new_content.to_lowercase();
+    let text = AnalyzedText::new(new_content);
+    let langs = Langs { output: lang, narrative: lang.resolve(new_content) };
     
     // === VERIFICACIÓN DE CÓDIGO MORAL ===
-    violations.extend(check_moral_code(&soul, &content_lower));
+    violations.extend(check_moral_code(soul, &text, langs));
     
     // === VERIFICACIÓN DE CICATRICES NO SANADAS ===
-    violations.extend(check_scar_consistency(&soul, &content_lower));
+    violations.extend(check_scar_consistency(soul, &text, langs));
     
     // === VERIFICACIÓN DE VÍNCULOS ===
-    violations.extend(check_bond_consistency(&soul, &content_lower));
+    violations.extend(check_bond_consistency(soul, &text, langs));
     
     // === VERIFICACIÓN DE MIEDOS (si los enfrenta sin desarrollo) ===
-    violations.extend(check_fear_handling(&soul, &content_lower));
+    violations.extend(check_fear_handling(soul, &text, langs));
     
     // === VERIFICACIÓN DE PROFECÍAS CUMPLIDAS ===
-    violations.extend(check_prophecy_status(&soul, &content_lower));
+    violations.extend(check_prophecy_status(soul, &text, langs));
     
     // === VERIFICACIÓN DE CRÓNICA (eventos pasados) ===
-    violations.extend(check_chronicle_consistency(&soul, &content_lower));
+    violations.extend(check_chronicle_consistency(soul, &text, langs));
+    
+    // Detectar deriva de personaje
+    let drift_warning = detect_character_drift(soul, &text, langs);
     
+    summarize_coherence(soul, violations, drift_warning, lang)
+}
+
+/// Idioma del informe y idioma en que se buscan las frases
+#[derive(Debug, Clone, Copy)]
+struct Langs {
+    output: Language,
+    narrative: Language,
+}
+
+/// Violaciones ya detectadas -> informe con score y sugerencias
+pub(crate) fn summarize_coherence(
+    soul: &LivingSoul,
+    violations: Vec<CoherenceViolation>,
+    drift_warning: Option<String>,
+    lang: Language,
+) -> CoherenceReport {
     // Calcular score de coherencia
     let violation_weight: f32 = violations.iter()
         .map(|v| match v.severity {
@@ -78,55 +131,47 @@ pub fn check_violations(soul_json: &str, new_content: &str) -> CoherenceReport {
     
     let coherence_score = (1.0 - violation_weight).max(0.0);
     
-    // Detectar deriva de personaje
-    let drift_warning = detect_character_drift(&soul, &content_lower);
-    
     CoherenceReport {
         is_coherent: violations.iter()
             .all(|v| !matches!(v.severity, ViolationSeverity::Critical)),
         coherence_score,
         violations,
         character_drift_warning: drift_warning,
-        suggested_revisions: generate_revision_suggestions(&soul),
+        suggested_revisions: generate_revision_suggestions(soul, lang),
     }
 }
 
-fn check_moral_code(soul: &LivingSoul, content: &str) -> Vec<CoherenceViolation> {
+fn check_moral_code(soul: &LivingSoul, text: &AnalyzedText, langs: Langs) -> Vec<CoherenceViolation> {
     let mut violations = Vec::new();
     
-    // Patrones que podrían violar códigos morales comunes
-    let violation_patterns = [
-        ("nunca matar", vec!["mató", "asesinó", "ejecutó", "dio muerte"]),
-        ("proteger inocentes", vec!["abandonó al niño", "dejó morir", "ignoró el llanto"]),
-        ("nunca mentir", vec!["mintió descaradamente", "engañó", "fabricó una historia"]),
-        ("honor ante todo", vec!["huyó cobardemente", "traicionó su palabra"]),
-        ("nunca robar", vec!["robó", "hurtó", "se apropió"]),
-    ];
-    
     for moral_rule in &soul.psyche.moral_code {
-        let rule_lower = moral_rule.to_lowercase();
+        let rule = AnalyzedText::new(moral_rule);
         
-        for (pattern, triggers) in &violation_patterns {
-            if rule_lower.contains(pattern) {
-                for trigger in triggers {
-                    if content.contains(trigger) {
-                        violations.push(CoherenceViolation {
-                            severity: ViolationSeverity::Critical,
-                            violation_type: ViolationType::MoralCodeViolation,
-                            description: format!(
-                                "La acción '{}' contradice el código moral: '{}'",
-                                trigger, moral_rule
-                            ),
-                            conflicting_element: moral_rule.clone(),
-                            source_reference: "psyche.moral_code".to_string(),
-                            suggestion: format!(
-                                "Si {} va a romper su código, necesita una justificación \
-                                narrativa poderosa o mostrar el conflicto interno.",
-                                soul.name
-                            ),
-                            position_hint: content.find(trigger),
-                        });
-                    }
+        // El código del alma puede estar escrito en otro idioma que la escena
+        for id in MORAL_RULES {
+            if !terms_in_any(&format!("moral.{}.rule", id)).any(|pattern| rule.contains(pattern)) {
+                continue;
+            }
+            for trigger in terms(langs.narrative, &format!("moral.{}.triggers", id)) {
+                if let Some((found, context)) = most_assertive(text, trigger, &soul.name) {
+                    let args: [TemplateArg; 3] = [("action", trigger), ("rule", moral_rule), ("name", &soul.name)];
+                    let violation = CoherenceViolation {
+                        severity: ViolationSeverity::Critical,
+                        violation_type: ViolationType::MoralCodeViolation,
+                        description: tr(langs.output, "violation.moral.description", &args),
+                        conflicting_element: moral_rule.clone(),
+                        source_reference: "psyche.moral_code".to_string(),
+                        suggestion: tr(langs.output, "violation.moral.suggestion", &args),
+                        position_hint: Some(found.char_start),
+                        rationale: ViolationRationale::default(),
+                    };
+                    violations.push(violation.in_context(
+                        text,
+                        &found,
+                        context,
+                        tr(langs.output, "violation.moral.reason", &args),
+                        langs.output,
+                    ));
                 }
             }
         }
@@ -135,7 +180,7 @@ fn check_moral_code(soul: &LivingSoul, content: &str) -> Vec<CoherenceViolation>
     violations
 }
 
-fn check_scar_consistency(soul: &LivingSoul, content: &str) -> Vec<CoherenceViolation> {
+fn check_scar_consistency(soul: &LivingSoul, text: &AnalyzedText, langs: Langs) -> Vec<CoherenceViolation> {
     let mut violations = Vec::new();
     
     for scar in &soul.scars {
@@ -144,23 +189,28 @@ fn check_scar_consistency(soul: &LivingSoul, content: &str) -> Vec<CoherenceViol
             // que deberían activar el trauma
             match scar.trauma_type {
                 crate::soul_parser::TraumaType::Betrayal => {
-                    if content.contains("confió ciegamente") 
-                        || content.contains("sin dudar un segundo") {
-                        violations.push(CoherenceViolation {
-                            severity: ViolationSeverity::Warning,
-                            violation_type: ViolationType::ScarViolation,
-                            description: format!(
-                                "{} muestra confianza ciega, pero tiene trauma \
-                                de traición sin sanar: '{}'",
-                                soul.name, scar.name
-                            ),
-                            conflicting_element: scar.name.clone(),
-                            source_reference: format!("scars.{}", scar.id),
-                            suggestion: "Mostrar hesitación, flashback, o conflicto interno \
-                                antes de confiar.".to_string(),
-                            position_hint: None,
-                        });
-                    }
+                    let blind_trust = terms(langs.narrative, "scar.betrayal.blind_trust");
+                    let Some((found, context)) = most_assertive_of(text, blind_trust, &soul.name) else {
+                        continue;
+                    };
+                    let args: [TemplateArg; 2] = [("name", &soul.name), ("scar", &scar.name)];
+                    let violation = CoherenceViolation {
+                        severity: ViolationSeverity::Warning,
+                        violation_type: ViolationType::ScarViolation,
+                        description: tr(langs.output, "violation.scar.description", &args),
+                        conflicting_element: scar.name.clone(),
+                        source_reference: format!("scars.{}", scar.id),
+                        suggestion: tr(langs.output, "violation.scar.suggestion", &args),
+                        position_hint: Some(found.char_start),
+                        rationale: ViolationRationale::default(),
+                    };
+                    violations.push(violation.in_context(
+                        text,
+                        &found,
+                        context,
+                        tr(langs.output, "violation.scar.reason", &args),
+                        langs.output,
+                    ));
                 },
                 crate::soul_parser::TraumaType::Loss => {
                     // Similar para pérdida
@@ -173,32 +223,40 @@ fn check_scar_consistency(soul: &LivingSoul, content: &str) -> Vec<CoherenceViol
     violations
 }
 
-fn check_bond_consistency(soul: &LivingSoul, content: &str) -> Vec<CoherenceViolation> {
+fn check_bond_consistency(soul: &LivingSoul, text: &AnalyzedText, langs: Langs) -> Vec<CoherenceViolation> {
     let mut violations = Vec::new();
     
     for bond in &soul.bonds {
-        let entity_lower = bond.entity_name.to_lowercase();
+        let entity = &bond.entity_name;
         
-        if content.contains(&entity_lower) {
+        if text.contains(entity) {
             match bond.status {
+                // Si el vínculo está roto pero se trata calurosamente
                 crate::soul_parser::BondStatus::Broken => {
-                    // Si el vínculo está roto pero se trata calurosamente
-                    if content.contains(&format!("abrazó a {}", entity_lower))
-                        || content.contains(&format!("{} sonrió a", entity_lower)) {
-                        violations.push(CoherenceViolation {
-                            severity: ViolationSeverity::Warning,
-                            violation_type: ViolationType::BondInconsistency,
-                            description: format!(
-                                "Interacción cálida con {}, pero el vínculo está marcado como ROTO.",
-                                bond.entity_name
-                            ),
-                            conflicting_element: bond.entity_name.clone(),
-                            source_reference: format!("bonds.{}", bond.entity_name),
-                            suggestion: "Si hay reconciliación, mostrar el proceso. \
-                                Si no, ajustar la interacción.".to_string(),
-                            position_hint: None,
-                        });
-                    }
+                    let args: [TemplateArg; 1] = [("entity", entity)];
+                    let warmth: Vec<String> = terms(langs.narrative, "bond.broken.warmth").iter()
+                        .map(|gesture| fill(gesture, &args))
+                        .collect();
+                    let Some((found, context)) = most_assertive_of(text, &warmth, &soul.name) else {
+                        continue;
+                    };
+                    let violation = CoherenceViolation {
+                        severity: ViolationSeverity::Warning,
+                        violation_type: ViolationType::BondInconsistency,
+                        description: tr(langs.output, "violation.bond.description", &args),
+                        conflicting_element: bond.entity_name.clone(),
+                        source_reference: format!("bonds.{}", bond.entity_name),
+                        suggestion: tr(langs.output, "violation.bond.suggestion", &args),
+                        position_hint: Some(found.char_start),
+                        rationale: ViolationRationale::default(),
+                    };
+                    violations.push(violation.in_context(
+                        text,
+                        &found,
+                        context,
+                        tr(langs.output, "violation.bond.reason", &args),
+                        langs.output,
+                    ));
                 },
                 crate::soul_parser::BondStatus::Strained => {
                     // Buscar interacciones que ignoren la tensión
@@ -211,47 +269,46 @@ fn check_bond_consistency(soul: &LivingSoul, content: &str) -> Vec<CoherenceViol
     violations
 }
 
-fn check_fear_handling(soul: &LivingSoul, content: &str) -> Vec<CoherenceViolation> {
+fn check_fear_handling(soul: &LivingSoul, text: &AnalyzedText, langs: Langs) -> Vec<CoherenceViolation> {
     let mut violations = Vec::new();
-    let fear_lower = soul.psyche.core_fear.to_lowercase();
-    
-    if fear_lower.is_empty() {
+    if soul.psyche.core_fear.is_empty() {
         return violations;
     }
     
     // Si el contenido toca el miedo pero el personaje no reacciona
-    let fear_keywords: Vec<&str> = fear_lower
-        .split(|c: char| !c.is_alphanumeric())
-        .filter(|w| w.len() > 3)
-        .collect();
+    let fear_keywords = keywords(&soul.psyche.core_fear);
+    let emotional_indicators = terms(langs.narrative, "fear.reactions");
     
-    let emotional_indicators = [
-        "temblor", "sudor", "pánico", "terror", "miedo", "hesitó",
-        "retrocedió", "palideció", "corazón acelerado", "angustia"
-    ];
-    
-    for keyword in fear_keywords {
-        if content.contains(keyword) {
-            // Verificar si hay indicadores emocionales cerca
+    for keyword in &fear_keywords {
+        if let Some((found, context)) = most_assertive(text, keyword, &soul.name) {
+            // Buscar una reacción afirmada (no "no tembló") en la oración o sus vecinas
             let has_emotional_response = emotional_indicators
                 .iter()
-                .any(|ind| content.contains(ind));
+                .flat_map(|ind| text.find(ind))
+                .any(|reaction| {
+                    is_near(text, &found, &reaction)
+                        && match_context(text, &reaction, &soul.name).is_assertive()
+                });
             
             if !has_emotional_response {
-                violations.push(CoherenceViolation {
+                let args: [TemplateArg; 3] = [("name", &soul.name), ("fear", &soul.psyche.core_fear), ("keyword", keyword)];
+                let violation = CoherenceViolation {
                     severity: ViolationSeverity::Notice,
                     violation_type: ViolationType::PersonalityBreach,
-                    description: format!(
-                        "La escena toca el miedo fundamental de {} ('{}') \
-                        pero no hay respuesta emocional visible.",
-                        soul.name, soul.psyche.core_fear
-                    ),
+                    description: tr(langs.output, "violation.fear.description", &args),
                     conflicting_element: soul.psyche.core_fear.clone(),
                     source_reference: "psyche.core_fear".to_string(),
-                    suggestion: "Añadir señales sutiles de incomodidad, \
-                        o mostrar la supresión consciente del miedo.".to_string(),
-                    position_hint: content.find(keyword),
-                });
+                    suggestion: tr(langs.output, "violation.fear.suggestion", &args),
+                    position_hint: Some(found.char_start),
+                    rationale: ViolationRationale::default(),
+                };
+                violations.push(violation.in_context(
+                    text,
+                    &found,
+                    context,
+                    tr(langs.output, "violation.fear.reason", &args),
+                    langs.output,
+                ));
             }
         }
     }
@@ -259,37 +316,41 @@ fn check_fear_handling(soul: &LivingSoul, content: &str) -> Vec<CoherenceViolati
     violations
 }
 
-fn check_prophecy_status(soul: &LivingSoul, content: &str) -> Vec<CoherenceViolation> {
+fn check_prophecy_status(soul: &LivingSoul, text: &AnalyzedText, langs: Langs) -> Vec<CoherenceViolation> {
     let mut violations = Vec::new();
     
     for prophecy in &soul.prophecies {
         if prophecy.fulfilled {
             // Verificar que no se trate como pendiente
-            let prophecy_keywords: Vec<&str> = prophecy.text
-                .to_lowercase()
-                .split(|c: char| !c.is_alphanumeric())
-                .filter(|w| w.len() > 4)
+            let prophecy_keywords: Vec<String> = keywords(&prophecy.text)
+                .into_iter()
+                .filter(|w| w.chars().count() > 4)
                 .collect();
             
-            for keyword in prophecy_keywords {
-                if content.contains(keyword) 
-                    && (content.contains("algún día") 
-                        || content.contains("está destinado a")
-                        || content.contains("la profecía dice")) {
-                    violations.push(CoherenceViolation {
+            for keyword in &prophecy_keywords {
+                if !text.contains(keyword) {
+                    continue;
+                }
+                let pending_phrases = terms(langs.narrative, "prophecy.pending");
+                if let Some((found, context)) = most_assertive_of(text, pending_phrases, &soul.name) {
+                    let args: [TemplateArg; 2] = [("prophecy", &prophecy.text), ("keyword", keyword)];
+                    let violation = CoherenceViolation {
                         severity: ViolationSeverity::Warning,
                         violation_type: ViolationType::ProphecyConflict,
-                        description: format!(
-                            "Se habla de la profecía '{}' como pendiente, \
-                            pero está marcada como CUMPLIDA.",
-                            prophecy.text
-                        ),
+                        description: tr(langs.output, "violation.prophecy.description", &args),
                         conflicting_element: prophecy.id.clone(),
                         source_reference: format!("prophecies.{}", prophecy.id),
-                        suggestion: "Referirse a la profecía en pasado, \
-                            o actualizar su estado.".to_string(),
-                        position_hint: None,
-                    });
+                        suggestion: tr(langs.output, "violation.prophecy.suggestion", &args),
+                        position_hint: Some(found.char_start),
+                        rationale: ViolationRationale::default(),
+                    };
+                    violations.push(violation.in_context(
+                        text,
+                        &found,
+                        context,
+                        tr(langs.output, "violation.prophecy.reason", &args),
+                        langs.output,
+                    ));
                 }
             }
         }
@@ -298,37 +359,45 @@ fn check_prophecy_status(soul: &LivingSoul, content: &str) -> Vec<CoherenceViola
     violations
 }
 
-fn check_chronicle_consistency(soul: &LivingSoul, content: &str) -> Vec<CoherenceViolation> {
+fn check_chronicle_consistency(soul: &LivingSoul, text: &AnalyzedText, langs: Langs) -> Vec<CoherenceViolation> {
     let mut violations = Vec::new();
     
     // Revisar que no se contradigan eventos pasados
     for entry in &soul.chronicle {
         for consequence in &entry.consequences {
-            let cons_lower = consequence.to_lowercase();
+            let cons = AnalyzedText::new(consequence);
             
             // Detectar si alguien murió y aparece vivo
-            if cons_lower.contains("muerte de") || cons_lower.contains("murió") {
+            let death = terms_in_any("chronicle.death_of").find_map(|marker| cons.find(marker).first().copied());
+            if let Some(found) = death {
                 // Extraer nombre del muerto
-                let parts: Vec<&str> = cons_lower.split("muerte de").collect();
-                if parts.len() > 1 {
-                    let dead_name = parts[1].split_whitespace().next().unwrap_or("");
-                    if content.contains(dead_name) 
-                        && (content.contains(&format!("{} dijo", dead_name))
-                            || content.contains(&format!("{} apareció", dead_name))) {
-                        violations.push(CoherenceViolation {
+                let name_token = cons.tokens().get(found.token_index + found.token_count);
+                if let Some(dead_name) = name_token.map(|t| t.text.as_str()) {
+                    let chapter = entry.chapter.to_string();
+                    let args: [TemplateArg; 4] = [
+                        ("dead", &dead_name), ("name", &dead_name), ("chapter", &chapter), ("consequence", consequence),
+                    ];
+                    let alive: Vec<String> = terms(langs.narrative, "chronicle.alive").iter()
+                        .map(|action| fill(action, &args))
+                        .collect();
+                    if let Some((found, context)) = most_assertive_of(text, &alive, &soul.name) {
+                        let violation = CoherenceViolation {
                             severity: ViolationSeverity::Critical,
                             violation_type: ViolationType::DeadCharacterAppears,
-                            description: format!(
-                                "'{}' aparece activo en la narrativa, \
-                                pero murió en el Capítulo {}.",
-                                dead_name, entry.chapter
-                            ),
+                            description: tr(langs.output, "violation.dead.description", &args),
                             conflicting_element: consequence.clone(),
                             source_reference: format!("chronicle.chapter_{}", entry.chapter),
-                            suggestion: "Verificar si es flashback, visión, \
-                                o error narrativo.".to_string(),
-                            position_hint: content.find(dead_name),
-                        });
+                            suggestion: tr(langs.output, "violation.dead.suggestion", &args),
+                            position_hint: Some(found.char_start),
+                            rationale: ViolationRationale::default(),
+                        };
+                        violations.push(violation.in_context(
+                            text,
+                            &found,
+                            context,
+                            tr(langs.output, "violation.dead.reason", &args),
+                            langs.output,
+                        ));
                     }
                 }
             }
@@ -338,29 +407,23 @@ fn check_chronicle_consistency(soul: &LivingSoul, content: &str) -> Vec<Coherenc
     violations
 }
 
-fn detect_character_drift(soul: &LivingSoul, content: &str) -> Option<String> {
+fn detect_character_drift(soul: &LivingSoul, text: &AnalyzedText, langs: Langs) -> Option<String> {
     // Detectar si el personaje se está alejando mucho de su esencia
-    let archetype_lower = soul.archetype.to_lowercase();
-    
-    // Definir comportamientos esperados vs contradictorios por arquetipo
-    let archetype_conflicts: Vec<(&str, Vec<&str>)> = vec![
-        ("héroe", vec!["huyó abandonando", "sacrificó al inocente"]),
-        ("sabio", vec!["actuó sin pensar", "ignoró la evidencia"]),
-        ("rebelde", vec!["obedeció sin cuestionar", "siguió las reglas"]),
-        ("cuidador", vec!["abandonó al necesitado", "priorizó su beneficio"]),
-    ];
+    let archetype = AnalyzedText::new(&soul.archetype);
     
-    for (archetype, conflicts) in archetype_conflicts {
-        if archetype_lower.contains(archetype) {
-            for conflict in conflicts {
-                if content.contains(conflict) {
-                    return Some(format!(
-                        "⚠️ Deriva de Personaje: {} tiene arquetipo de '{}' \
-                        pero la acción '{}' sugiere un cambio fundamental. \
-                        ¿Es intencional?",
-                        soul.name, soul.archetype, conflict
-                    ));
-                }
+    // Comportamientos que contradicen cada arquetipo, en el idioma de la escena
+    for id in DRIFT_ARCHETYPES {
+        if !terms_in_any(&format!("drift.{}.archetype", id)).any(|expected| archetype.contains(expected)) {
+            continue;
+        }
+        for conflict in terms(langs.narrative, &format!("drift.{}.actions", id)) {
+            // Solo cuenta si la acción ocurre: "nunca huiría abandonando" no es deriva
+            let happens = most_assertive(text, conflict, &soul.name)
+                .is_some_and(|(_, context)| context.is_assertive());
+            if happens {
+                return Some(tr(langs.output, "drift.warning", &[
+                    ("name", &soul.name), ("archetype", &soul.archetype), ("action", conflict),
+                ]));
             }
         }
     }
@@ -368,29 +431,75 @@ fn detect_character_drift(soul: &LivingSoul, content: &str) -> Option<String> {
     None
 }
 
-fn generate_revision_suggestions(soul: &LivingSoul) -> Vec<String> {
+fn generate_revision_suggestions(soul: &LivingSoul, lang: Language) -> Vec<String> {
     let mut suggestions = Vec::new();
     
     // Sugerencias basadas en elementos del alma
     if !soul.psyche.core_fear.is_empty() {
-        suggestions.push(format!(
-            "Recuerda: el miedo fundamental de {} es '{}'. \
-            Las escenas de tensión deberían resonar con esto.",
-            soul.name, soul.psyche.core_fear
-        ));
+        suggestions.push(tr(lang, "revision.fear", &[("name", &soul.name), ("fear", &soul.psyche.core_fear)]));
     }
     
     if let Some(ref nemesis) = soul.nemesis {
-        suggestions.push(format!(
-            "La némesis {} siempre debe sentirse como una amenaza latente, \
-            incluso cuando no aparece directamente.",
-            nemesis.name
-        ));
+        suggestions.push(tr(lang, "revision.nemesis", &[("nemesis", &nemesis.name)]));
     }
     
     suggestions
 }
 
+/// Las dos coincidencias comparten oración o son oraciones contiguas
+fn is_near(text: &AnalyzedText, a: &TermMatch, b: &TermMatch) -> bool {
+    let sentences = text.sentences();
+    let index = |m: &TermMatch| sentences.iter()
+        .position(|s| s.char_start <= m.char_start && m.char_start < s.char_end);
+    match (index(a), index(b)) {
+        (Some(x), Some(y)) => x.abs_diff(y) <= 1,
+        _ => false,
+    }
+}
+
+impl ViolationSeverity {
+    /// Baja `steps` niveles sin pasar de Notice
+    pub fn softened(self, steps: usize) -> Self {
+        let levels = [ViolationSeverity::Critical, ViolationSeverity::Warning, ViolationSeverity::Notice];
+        let current = levels.iter().position(|l| *l == self).unwrap_or(0);
+        levels[(current + steps).min(levels.len() - 1)]
+    }
+}
+
+impl CoherenceViolation {
+    /// Atenúa la severidad según el contexto modal de la coincidencia
+    /// y deja constancia de por qué se levantó
+    pub(crate) fn in_context(
+        mut self,
+        text: &AnalyzedText,
+        found: &TermMatch,
+        context: MatchContext,
+        reason: String,
+        lang: Language,
+    ) -> Self {
+        let adjusted = self.severity.softened(context.severity_steps());
+        let base_severity = (adjusted != self.severity).then_some(self.severity);
+
+        if !context.modifiers.is_empty() {
+            let circumstances: Vec<String> = context.modifiers.iter().map(|m| m.describe(lang)).collect();
+            let circumstances = circumstances.join(&tr(lang, "list.separator", &[]));
+            self.description = tr(lang, "violation.softened", &[
+                ("description", &self.description), ("circumstances", &circumstances),
+            ]);
+        }
+
+        self.severity = adjusted;
+        self.rationale = ViolationRationale {
+            reason,
+            matched_text: text.slice(found.char_start, found.char_end),
+            sentence: context.sentence,
+            modifiers: context.modifiers,
+            base_severity,
+        };
+        self
+    }
+}
+
 impl CoherenceReport {
     fn empty() -> Self {
         Self {
@@ -402,3 +511,45 @@ impl CoherenceReport {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soul_parser::parse_living_sheet;
+
+    fn honorable_soul() -> LivingSoul {
+        parse_living_sheet(
+            r#"<div class="moral-code"><ul><li>Honor ante todo</li></ul></div>"#,
+        )
+    }
+
+    #[test]
+    fn test_negated_conditional_is_softened_and_explained() {
+        let soul = honorable_soul();
+
+        let asserted = check_soul_violations(&soul, "Al alba traicionó su palabra.", Language::Es);
+        assert_eq!(asserted.violations[0].severity, ViolationSeverity::Critical);
+        assert!(!asserted.is_coherent);
+
+        let negated = check_soul_violations(&soul, "Nunca traicionaría su palabra.", Language::Es);
+        let violation = &negated.violations[0];
+        assert_eq!(violation.severity, ViolationSeverity::Notice);
+        assert_eq!(violation.rationale.base_severity, Some(ViolationSeverity::Critical));
+        assert_eq!(violation.rationale.sentence, "Nunca traicionaría su palabra.");
+        assert!(negated.is_coherent);
+    }
+
+    #[test]
+    fn test_detects_english_narrative_and_reports_in_output_language() {
+        // Regla escrita en español, narrativa en inglés, informe en inglés
+        let soul = honorable_soul();
+
+        let report = check_soul_violations(&soul, "At dawn, she betrayed her word and left the city.", Language::En);
+        let violation = &report.violations[0];
+        assert_eq!(violation.severity, ViolationSeverity::Critical);
+        assert_eq!(violation.description, "The action 'betrayed her word' contradicts the moral code: 'Honor ante todo'");
+
+        let spanish = check_soul_violations(&soul, "Al alba traicionó su palabra.", Language::Jp);
+        assert!(spanish.violations[0].description.starts_with("行動「traicionó su palabra」"));
+    }
+}
@@ -0,0 +1,431 @@
+//! Simulador de destinos (Monte Carlo)
+//!
+//! Lleva un alma por N rondas: en cada una se generan sus puntos de
+//! inflexión, se elige uno (los más intensos pesan más) y se sortea su
+//! desenlace según `probability_weight`. Repetido muchas veces con una
+//! semilla fija, muestra qué arcos son probables y cuáles raros antes de
+//! comprometer la trama.
+
+use std::collections::BTreeMap;
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::conflict_forge::create_soul_inflection_points;
+use crate::forge_error::{parse_json, ForgeError};
+use crate::i18n::{tr, Language};
+use crate::soul_evolution::apply_outcome;
+use crate::soul_parser::*;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SimulationConfig {
+    pub runs: u32,              // Historias simuladas
+    pub rounds: u32,            // Puntos de inflexión por historia
+    pub seed: u64,
+    pub start_chapter: u32,     // Capítulo de la primera ronda
+    #[serde(default)]
+    pub language: Language,     // Idioma de los cambios y de las firmas de arco
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DestinySimulation {
+    pub config: SimulationConfig,
+    pub scars: Vec<ScarOdds>,
+    pub average_new_scars: f32,
+    pub bonds: Vec<BondOdds>,
+    pub prophecies: Vec<ProphecyOdds>,
+    pub nemesis: Option<NemesisOdds>,
+    pub final_tension: TensionStats,
+    pub arcs: Vec<ArcFrequency>,    // Estados finales, del más al menos probable
+}
+
+/// Destino de una cicatriz que el alma ya tenía
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScarOdds {
+    pub id: String,
+    pub name: String,
+    pub healed: f32,
+    pub deepened: f32,
+    pub unchanged: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BondOdds {
+    pub entity_name: String,
+    pub survives: f32,          // Termina sin estar roto
+    pub strengthened: f32,      // Termina más fuerte que al empezar
+    pub broken: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProphecyOdds {
+    pub id: String,
+    pub text: String,
+    pub fulfilled: f32,
+    pub rewritten: f32,         // Pasó a depender de decisiones
+    pub average_progress: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NemesisOdds {
+    pub name: String,
+    pub defeated: f32,
+    pub reconciled: f32,
+    pub stronger: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TensionStats {
+    pub mean: f32,
+    pub min: f32,
+    pub max: f32,
+    pub p10: f32,
+    pub p50: f32,
+    pub p90: f32,
+    pub histogram: Vec<u32>,    // Diez tramos de 0.1
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArcFrequency {
+    pub signature: String,
+    pub count: u32,
+    pub probability: f32,
+}
+
+/// Cuántos arcos distintos se listan
+const MAX_ARCS: usize = 10;
+const HISTOGRAM_BUCKETS: usize = 10;
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            runs: 1000,
+            rounds: 5,
+            seed: 0,
+            start_chapter: 1,
+            language: Language::Es,
+        }
+    }
+}
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+pub fn simulate_destiny(soul_json: &str, runs: u32, rounds: u32, seed: u64, lang: Language) -> DestinySimulation {
+    try_simulate_destiny(soul_json, runs, rounds, seed, lang).unwrap_or_else(|_| {
+        DestinySimulation::empty(SimulationConfig { runs, rounds, seed, language: lang, ..SimulationConfig::default() })
+    })
+}
+
+/// Como `simulate_destiny`, pero un alma inválida es un error
+pub fn try_simulate_destiny(
+    soul_json: &str,
+    runs: u32,
+    rounds: u32,
+    seed: u64,
+    lang: Language,
+) -> Result<DestinySimulation, ForgeError> {
+    let config = SimulationConfig { runs, rounds, seed, language: lang, ..SimulationConfig::default() };
+    let soul: LivingSoul = parse_json("soul", soul_json)?;
+    Ok(simulate_soul_destiny(&soul, &config))
+}
+
+/// Simulación sobre un alma ya deserializada. Misma semilla, mismo resultado.
+pub fn simulate_soul_destiny(soul: &LivingSoul, config: &SimulationConfig) -> DestinySimulation {
+    if config.runs == 0 {
+        return DestinySimulation::empty(*config);
+    }
+
+    let mut rng = ChaCha8Rng::seed_from_u64(config.seed);
+    let endings: Vec<LivingSoul> = (0..config.runs)
+        .map(|_| run_story(soul, config, &mut rng))
+        .collect();
+
+    summarize(soul, *config, &endings)
+}
+
+/// Una historia completa: `rounds` puntos de inflexión con desenlace sorteado
+fn run_story(soul: &LivingSoul, config: &SimulationConfig, rng: &mut ChaCha8Rng) -> LivingSoul {
+    let mut current = soul.clone();
+
+    for round in 0..config.rounds {
+        let points = create_soul_inflection_points(&current, config.language);
+        let Some(point) = pick_weighted(&points, |p| p.intensity, rng) else {
+            break;
+        };
+        let Some(outcome) = pick_weighted(&point.potential_outcomes, |o| o.probability_weight, rng) else {
+            continue;
+        };
+        current = apply_outcome(&current, point, outcome, config.start_chapter + round, config.language).soul;
+    }
+
+    current
+}
+
+/// Sorteo ponderado; si ningún peso es válido, uniforme
+fn pick_weighted<'a, T>(items: &'a [T], weight: impl Fn(&T) -> f32, rng: &mut ChaCha8Rng) -> Option<&'a T> {
+    if items.is_empty() {
+        return None;
+    }
+    let weights: Vec<f32> = items.iter()
+        .map(weight)
+        .map(|w| if w.is_finite() && w > 0.0 { w } else { 0.0 })
+        .collect();
+
+    match WeightedIndex::new(&weights) {
+        Ok(dist) => items.get(dist.sample(rng)),
+        Err(_) => items.get(rng.gen_range(0..items.len())),
+    }
+}
+
+// ============================================================
+// RESUMEN
+// ============================================================
+
+fn summarize(soul: &LivingSoul, config: SimulationConfig, endings: &[LivingSoul]) -> DestinySimulation {
+    let total = endings.len() as f32;
+    let share = |count: usize| count as f32 / total;
+
+    let scars = soul.scars.iter()
+        .map(|scar| {
+            let finals: Vec<&SoulScar> = endings.iter()
+                .filter_map(|e| e.scars.iter().find(|s| s.id == scar.id))
+                .collect();
+            let healed = finals.iter().filter(|s| s.healed && !scar.healed).count();
+            let deepened = finals.iter()
+                .filter(|s| !s.healed && s.narrative_weight > scar.narrative_weight)
+                .count();
+            ScarOdds {
+                id: scar.id.clone(),
+                name: scar.name.clone(),
+                healed: share(healed),
+                deepened: share(deepened),
+                unchanged: share(endings.len() - healed - deepened),
+            }
+        })
+        .collect();
+
+    let new_scars: usize = endings.iter()
+        .map(|e| e.scars.len().saturating_sub(soul.scars.len()))
+        .sum();
+
+    let bonds = soul.bonds.iter()
+        .map(|bond| {
+            let finals: Vec<Option<&SoulBond>> = endings.iter()
+                .map(|e| e.bonds.iter().find(|b| b.entity_name == bond.entity_name))
+                .collect();
+            let broken = finals.iter()
+                .filter(|b| b.is_none_or(|b| matches!(b.status, BondStatus::Broken)))
+                .count();
+            let strengthened = finals.iter()
+                .filter(|b| b.is_some_and(|b| b.strength > bond.strength && !matches!(b.status, BondStatus::Broken)))
+                .count();
+            BondOdds {
+                entity_name: bond.entity_name.clone(),
+                survives: share(endings.len() - broken),
+                strengthened: share(strengthened),
+                broken: share(broken),
+            }
+        })
+        .collect();
+
+    let prophecies = soul.prophecies.iter()
+        .map(|prophecy| {
+            let finals: Vec<&Prophecy> = endings.iter()
+                .filter_map(|e| e.prophecies.iter().find(|p| p.id == prophecy.id))
+                .collect();
+            let fulfilled = finals.iter().filter(|p| p.fulfilled).count();
+            let rewritten = finals.iter()
+                .filter(|p| !p.fulfilled
+                    && matches!(p.prophecy_type, ProphecyType::Choice)
+                    && !matches!(prophecy.prophecy_type, ProphecyType::Choice))
+                .count();
+            ProphecyOdds {
+                id: prophecy.id.clone(),
+                text: prophecy.text.clone(),
+                fulfilled: share(fulfilled),
+                rewritten: share(rewritten),
+                average_progress: finals.iter().map(|p| p.progress).sum::<f32>() / total,
+            }
+        })
+        .collect();
+
+    let nemesis = soul.nemesis.as_ref().map(|nemesis| {
+        let reconciled = endings.iter().filter(|e| e.nemesis.is_none()).count();
+        let defeated = endings.iter()
+            .filter(|e| e.nemesis.as_ref().is_some_and(|n| n.threat_level < nemesis.threat_level))
+            .count();
+        let stronger = endings.iter()
+            .filter(|e| e.nemesis.as_ref().is_some_and(|n| n.threat_level > nemesis.threat_level))
+            .count();
+        NemesisOdds {
+            name: nemesis.name.clone(),
+            defeated: share(defeated),
+            reconciled: share(reconciled),
+            stronger: share(stronger),
+        }
+    });
+
+    let tensions: Vec<f32> = endings.iter()
+        .map(|e| e.tension_markers.overall_tension)
+        .collect();
+
+    let mut arc_counts: BTreeMap<String, u32> = BTreeMap::new();
+    for ending in endings {
+        *arc_counts.entry(arc_signature(soul, ending, config.language)).or_default() += 1;
+    }
+    let mut arcs: Vec<ArcFrequency> = arc_counts.into_iter()
+        .map(|(signature, count)| ArcFrequency {
+            signature,
+            count,
+            probability: count as f32 / total,
+        })
+        .collect();
+    arcs.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.signature.cmp(&b.signature)));
+    arcs.truncate(MAX_ARCS);
+
+    DestinySimulation {
+        config,
+        scars,
+        average_new_scars: new_scars as f32 / total,
+        bonds,
+        prophecies,
+        nemesis,
+        final_tension: tension_stats(&tensions),
+        arcs,
+    }
+}
+
+/// Resumen legible de un estado final, para agrupar historias equivalentes
+fn arc_signature(start: &LivingSoul, end: &LivingSoul, lang: Language) -> String {
+    let mut parts = Vec::new();
+    let count = |key: &str, done: usize, total: usize| tr(lang, key, &[("done", &done), ("total", &total)]);
+
+    let healed = start.scars.iter()
+        .filter(|s| !s.healed && end.scars.iter().any(|e| e.id == s.id && e.healed))
+        .count();
+    let open_scars = start.scars.iter().filter(|s| !s.healed).count();
+    let new_scars = end.scars.len().saturating_sub(start.scars.len());
+    if open_scars > 0 {
+        parts.push(count("arc.scars_healed", healed, open_scars));
+    }
+    if new_scars > 0 {
+        parts.push(tr(lang, "arc.new_scars", &[("count", &new_scars)]));
+    }
+
+    if !start.bonds.is_empty() {
+        let alive = start.bonds.iter()
+            .filter(|b| end.bonds.iter()
+                .any(|e| e.entity_name == b.entity_name && !matches!(e.status, BondStatus::Broken)))
+            .count();
+        parts.push(count("arc.bonds_intact", alive, start.bonds.len()));
+    }
+
+    if !start.prophecies.is_empty() {
+        let fulfilled = end.prophecies.iter().filter(|p| p.fulfilled).count();
+        parts.push(count("arc.prophecies_fulfilled", fulfilled, start.prophecies.len()));
+    }
+
+    if let Some(nemesis) = &start.nemesis {
+        let fate = match &end.nemesis {
+            None => "arc.nemesis_reconciled",
+            Some(n) if n.threat_level < nemesis.threat_level => "arc.nemesis_defeated",
+            Some(n) if n.threat_level > nemesis.threat_level => "arc.nemesis_stronger",
+            Some(_) => "arc.nemesis_intact",
+        };
+        parts.push(tr(lang, fate, &[]));
+    }
+
+    if start.psyche.core_fear.is_empty() != end.psyche.core_fear.is_empty() {
+        parts.push(tr(lang, "arc.fear_overcome", &[]));
+    }
+
+    if parts.is_empty() {
+        tr(lang, "arc.unchanged", &[])
+    } else {
+        parts.join(" · ")
+    }
+}
+
+fn tension_stats(values: &[f32]) -> TensionStats {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let percentile = |p: f32| {
+        let index = ((sorted.len() - 1) as f32 * p).round() as usize;
+        sorted[index]
+    };
+
+    let mut histogram = vec![0; HISTOGRAM_BUCKETS];
+    for value in &sorted {
+        let bucket = ((value.clamp(0.0, 1.0) * HISTOGRAM_BUCKETS as f32) as usize).min(HISTOGRAM_BUCKETS - 1);
+        histogram[bucket] += 1;
+    }
+
+    TensionStats {
+        mean: sorted.iter().sum::<f32>() / sorted.len() as f32,
+        min: sorted[0],
+        max: sorted[sorted.len() - 1],
+        p10: percentile(0.1),
+        p50: percentile(0.5),
+        p90: percentile(0.9),
+        histogram,
+    }
+}
+
+impl DestinySimulation {
+    fn empty(config: SimulationConfig) -> Self {
+        Self {
+            config,
+            scars: vec![],
+            average_new_scars: 0.0,
+            bonds: vec![],
+            prophecies: vec![],
+            nemesis: None,
+            final_tension: TensionStats {
+                mean: 0.0,
+                min: 0.0,
+                max: 0.0,
+                p10: 0.0,
+                p50: 0.0,
+                p90: 0.0,
+                histogram: vec![0; HISTOGRAM_BUCKETS],
+            },
+            arcs: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn soul() -> LivingSoul {
+        parse_living_sheet(r#"
+            <h1 class="name">Aria Vance</h1>
+            <div data-fear>Ser abandonada</div>
+            <div data-scar data-name="La Traición" data-trauma-type="betrayal" data-weight="0.9"></div>
+            <ul class="moral-code"><li>Nunca mentir</li></ul>
+        "#)
+    }
+
+    #[test]
+    fn test_same_seed_same_distribution() {
+        let config = SimulationConfig { runs: 200, rounds: 4, seed: 42, start_chapter: 1, language: Language::Es };
+        let a = simulate_soul_destiny(&soul(), &config);
+        let b = simulate_soul_destiny(&soul(), &config);
+        assert_eq!(
+            serde_json::to_string(&a).unwrap(),
+            serde_json::to_string(&b).unwrap()
+        );
+
+        let scar = &a.scars[0];
+        assert!((scar.healed + scar.deepened + scar.unchanged - 1.0).abs() < 1e-4);
+        assert!(scar.healed > 0.0 && scar.deepened > 0.0);
+        assert_eq!(a.final_tension.histogram.iter().sum::<u32>(), 200);
+        assert!((a.arcs.iter().map(|arc| arc.probability).sum::<f32>() - 1.0).abs() < 1e-4 || a.arcs.len() == MAX_ARCS);
+    }
+}
@@ -0,0 +1,405 @@
+//! Tensión de conjunto: varias almas en un mismo pasaje
+//!
+//! `calculate_tension` mira a un solo personaje. En una escena coral la
+//! tensión interesante está entre ellos: la némesis de uno es el vínculo de
+//! otro, dos personajes comparten un miedo, un vínculo roto se sienta a la
+//! misma mesa. Aquí cada onda se atribuye a las almas que la provocan, se
+//! detectan las resonancias cruzadas y se desglosa la presión por personaje.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use crate::forge_error::{parse_souls_json, ForgeError};
+use crate::i18n::Language;
+use crate::soul_parser::*;
+use crate::tension_engine::*;
+use crate::text_analysis::{fold, keywords, AnalyzedText};
+
+/// Cuánto suma al total cada resonancia cruzada activa en el pasaje
+const RESONANCE_BONUS: f32 = 0.05;
+
+/// Una resonancia que no aparece en el texto pesa la mitad
+const DORMANT_FACTOR: f32 = 0.5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnsembleTension {
+    pub overall_score: f32,
+    pub tension_waves: Vec<EnsembleWave>,
+    pub resonances: Vec<CrossResonance>,
+    pub characters: Vec<CharacterPressure>,
+    pub narrative_pressure: NarrativePressure,
+}
+
+/// Onda de tensión con las almas que la provocan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnsembleWave {
+    pub position: usize,
+    pub intensity: f32,
+    pub wave_type: WaveType,
+    pub sources: Vec<String>,    // "Aria Vance/scar:scar_0", uno por alma
+    pub souls: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ResonanceKind {
+    Confrontation,      // La némesis de un alma es otra alma de la escena
+    DividedLoyalty,     // La némesis de un alma es vínculo de otra
+    FracturedBond,      // Dos almas presentes con un vínculo tenso o roto
+    SharedFear,         // Miedos centrales que comparten palabras
+    MirroredScars,      // Cicatrices del mismo tipo de trauma
+}
+
+/// Tensión que solo existe entre dos o más almas
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossResonance {
+    pub kind: ResonanceKind,
+    pub souls: Vec<String>,
+    pub element: String,         // Nombre, miedo o trauma que las une
+    pub positions: Vec<usize>,   // Dónde asoma en el pasaje (vacío = latente)
+    pub intensity: f32,
+}
+
+/// Desglose de la presión sobre un personaje
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharacterPressure {
+    pub soul_id: String,
+    pub present: bool,                   // Su nombre aparece en el pasaje
+    pub score: f32,                      // Tensión individual (calculate_soul_tension)
+    pub wave_count: usize,
+    pub pressure_share: f32,             // Parte de la intensidad total que le toca
+    pub by_type: BTreeMap<String, f32>,  // Intensidad por WaveType
+    pub dominant_source: Option<String>,
+    pub resonances: usize,
+}
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// `souls_json` es una lista de almas (o una sola)
+pub fn calculate_ensemble_tension(souls_json: &str, narrative: &str, lang: Language) -> EnsembleTension {
+    try_calculate_ensemble_tension(souls_json, narrative, lang).unwrap_or_else(|_| EnsembleTension::empty())
+}
+
+/// Como `calculate_ensemble_tension`, pero una lista inválida es un error
+pub fn try_calculate_ensemble_tension(souls_json: &str, narrative: &str, lang: Language) -> Result<EnsembleTension, ForgeError> {
+    let souls = parse_souls_json(souls_json)?;
+    Ok(calculate_souls_tension(&souls, narrative, lang))
+}
+
+/// Tensión de conjunto sobre almas ya deserializadas
+pub fn calculate_souls_tension(souls: &[LivingSoul], narrative: &str, lang: Language) -> EnsembleTension {
+    if souls.is_empty() {
+        return EnsembleTension::empty();
+    }
+
+    let text = AnalyzedText::new(narrative);
+    let analyses: Vec<TensionAnalysis> = souls.iter()
+        .map(|soul| calculate_analyzed_tension(soul, &text, lang))
+        .collect();
+
+    // === ATRIBUCIÓN DE ONDAS ===
+    // La misma palabra que despierta el mismo tipo de onda en varias almas es una sola onda
+    let mut waves: Vec<EnsembleWave> = Vec::new();
+    for (soul, analysis) in souls.iter().zip(&analyses) {
+        for wave in &analysis.tension_waves {
+            let source = format!("{}/{}", soul.name, wave.source);
+            match waves.iter_mut().find(|w| {
+                w.position == wave.position && same_wave_type(&w.wave_type, &wave.wave_type)
+            }) {
+                Some(shared) => {
+                    shared.intensity = shared.intensity.max(wave.intensity);
+                    shared.sources.push(source);
+                    if !shared.souls.contains(&soul.name) {
+                        shared.souls.push(soul.name.clone());
+                    }
+                },
+                None => waves.push(EnsembleWave {
+                    position: wave.position,
+                    intensity: wave.intensity,
+                    wave_type: wave.wave_type.clone(),
+                    sources: vec![source],
+                    souls: vec![soul.name.clone()],
+                }),
+            }
+        }
+    }
+    waves.sort_by_key(|w| w.position);
+
+    let resonances = detect_resonances(souls, &text);
+
+    // === DESGLOSE POR PERSONAJE ===
+    // Una onda compartida reparte su intensidad entre sus almas
+    let total_intensity: f32 = waves.iter().map(|w| w.intensity).sum();
+    let characters: Vec<CharacterPressure> = souls.iter().zip(&analyses)
+        .map(|(soul, analysis)| {
+            let mut by_type: BTreeMap<String, f32> = BTreeMap::new();
+            let mut by_source: BTreeMap<String, f32> = BTreeMap::new();
+            let mut attributed = 0.0f32;
+            let mut wave_count = 0;
+
+            for wave in waves.iter().filter(|w| w.souls.contains(&soul.name)) {
+                let share = wave.intensity / wave.souls.len() as f32;
+                attributed += share;
+                wave_count += 1;
+                *by_type.entry(format!("{:?}", wave.wave_type)).or_insert(0.0) += share;
+
+                let prefix = format!("{}/", soul.name);
+                for source in wave.sources.iter().filter_map(|s| s.strip_prefix(&prefix)) {
+                    *by_source.entry(source.to_string()).or_insert(0.0) += share;
+                }
+            }
+
+            CharacterPressure {
+                soul_id: soul.name.clone(),
+                present: is_present(soul, &text),
+                score: analysis.overall_score,
+                wave_count,
+                pressure_share: if total_intensity > 0.0 { attributed / total_intensity } else { 0.0 },
+                by_type,
+                dominant_source: by_source.into_iter()
+                    .max_by(|a, b| a.1.total_cmp(&b.1))
+                    .map(|(source, _)| source),
+                resonances: resonances.iter().filter(|r| r.souls.contains(&soul.name)).count(),
+            }
+        })
+        .collect();
+
+    // === TOTAL DE LA ESCENA ===
+    let marker_avg = souls.iter()
+        .map(|s| s.tension_markers.overall_tension)
+        .sum::<f32>() / souls.len() as f32;
+    let active_resonances = resonances.iter().filter(|r| !r.positions.is_empty()).count();
+    let base = if waves.is_empty() {
+        marker_avg
+    } else {
+        let wave_avg = total_intensity / waves.len() as f32;
+        wave_avg * 0.6 + marker_avg * 0.4
+    };
+    let overall = (base + RESONANCE_BONUS * active_resonances as f32).min(1.0);
+
+    let flat: Vec<TensionWave> = waves.iter()
+        .map(|w| TensionWave {
+            position: w.position,
+            intensity: w.intensity,
+            wave_type: w.wave_type.clone(),
+            source: w.sources.join(","),
+        })
+        .collect();
+
+    EnsembleTension {
+        overall_score: overall,
+        narrative_pressure: calculate_narrative_pressure(&flat, text.char_len(), lang),
+        tension_waves: waves,
+        resonances,
+        characters,
+    }
+}
+
+// ============================================================
+// RESONANCIAS CRUZADAS
+// ============================================================
+
+fn detect_resonances(souls: &[LivingSoul], text: &AnalyzedText) -> Vec<CrossResonance> {
+    let mut resonances = Vec::new();
+
+    for (i, soul) in souls.iter().enumerate() {
+        // === NÉMESIS FRENTE A OTRAS ALMAS ===
+        if let Some(ref nemesis) = soul.nemesis {
+            for other in souls.iter().filter(|o| o.name != soul.name) {
+                if same_name(&nemesis.name, &other.name) {
+                    resonances.push(resonance(
+                        ResonanceKind::Confrontation,
+                        vec![soul.name.clone(), other.name.clone()],
+                        &nemesis.name,
+                        name_positions(text, &nemesis.name),
+                        0.95,
+                    ));
+                }
+                if let Some(bond) = other.bonds.iter().find(|b| same_name(&b.entity_name, &nemesis.name)) {
+                    resonances.push(resonance(
+                        ResonanceKind::DividedLoyalty,
+                        vec![soul.name.clone(), other.name.clone()],
+                        &nemesis.name,
+                        name_positions(text, &nemesis.name),
+                        0.6 + 0.3 * bond.strength.clamp(0.0, 1.0),
+                    ));
+                }
+            }
+        }
+
+        for other in &souls[i + 1..] {
+            // === VÍNCULOS ROTOS ENTRE ELLAS ===
+            let strained = soul.bonds.iter()
+                .filter(|b| same_name(&b.entity_name, &other.name))
+                .chain(other.bonds.iter().filter(|b| same_name(&b.entity_name, &soul.name)))
+                .find(|b| matches!(b.status, BondStatus::Strained | BondStatus::Broken));
+            if let Some(bond) = strained {
+                let positions = if is_present(soul, text) && is_present(other, text) {
+                    let mut positions = name_positions(text, &soul.name);
+                    positions.extend(name_positions(text, &other.name));
+                    positions.sort_unstable();
+                    positions
+                } else {
+                    vec![]
+                };
+                resonances.push(resonance(
+                    ResonanceKind::FracturedBond,
+                    vec![soul.name.clone(), other.name.clone()],
+                    &format!("{:?}", bond.status),
+                    positions,
+                    0.7,
+                ));
+            }
+
+            // === MIEDO COMPARTIDO ===
+            let other_fear = keywords(&other.psyche.core_fear);
+            let shared: Vec<String> = keywords(&soul.psyche.core_fear).into_iter()
+                .filter(|k| other_fear.contains(k))
+                .collect();
+            if !shared.is_empty() {
+                let mut positions: Vec<usize> = shared.iter()
+                    .flat_map(|k| text.find(k))
+                    .map(|m| m.char_start)
+                    .collect();
+                positions.sort_unstable();
+                positions.dedup();
+                resonances.push(resonance(
+                    ResonanceKind::SharedFear,
+                    vec![soul.name.clone(), other.name.clone()],
+                    &shared.join(", "),
+                    positions,
+                    0.75,
+                ));
+            }
+
+            // === CICATRICES ESPEJO ===
+            for scar in &soul.scars {
+                let mirror = other.scars.iter()
+                    .find(|s| std::mem::discriminant(&s.trauma_type) == std::mem::discriminant(&scar.trauma_type));
+                if let Some(mirror) = mirror {
+                    let mut positions: Vec<usize> = keywords(&scar.origin_event).iter()
+                        .chain(keywords(&mirror.origin_event).iter())
+                        .flat_map(|k| text.find(k))
+                        .map(|m| m.char_start)
+                        .collect();
+                    positions.sort_unstable();
+                    positions.dedup();
+                    resonances.push(resonance(
+                        ResonanceKind::MirroredScars,
+                        vec![soul.name.clone(), other.name.clone()],
+                        &format!("{:?}", scar.trauma_type),
+                        positions,
+                        (scar.narrative_weight + mirror.narrative_weight) / 2.0,
+                    ));
+                    break;
+                }
+            }
+        }
+    }
+
+    resonances
+}
+
+fn resonance(kind: ResonanceKind, souls: Vec<String>, element: &str, positions: Vec<usize>, intensity: f32) -> CrossResonance {
+    let intensity = if positions.is_empty() { intensity * DORMANT_FACTOR } else { intensity };
+    CrossResonance {
+        kind,
+        souls,
+        element: element.to_string(),
+        positions,
+        intensity: intensity.min(1.0),
+    }
+}
+
+/// "Kael" y "Kael el Traidor" son la misma persona
+fn same_name(a: &str, b: &str) -> bool {
+    let (fa, fb) = (fold(a.trim()), fold(b.trim()));
+    if fa.is_empty() || fb.is_empty() {
+        return false;
+    }
+    fa == fb || AnalyzedText::new(a).contains(b) || AnalyzedText::new(b).contains(a)
+}
+
+fn name_positions(text: &AnalyzedText, name: &str) -> Vec<usize> {
+    let mut positions: Vec<usize> = text.find(name).iter().map(|m| m.char_start).collect();
+    // Basta el nombre de pila
+    if positions.is_empty() {
+        if let Some(first) = keywords(name).first() {
+            positions = text.find(first).iter().map(|m| m.char_start).collect();
+        }
+    }
+    positions
+}
+
+fn is_present(soul: &LivingSoul, text: &AnalyzedText) -> bool {
+    !name_positions(text, &soul.name).is_empty()
+}
+
+fn same_wave_type(a: &WaveType, b: &WaveType) -> bool {
+    std::mem::discriminant(a) == std::mem::discriminant(b)
+}
+
+impl EnsembleTension {
+    fn empty() -> Self {
+        Self {
+            overall_score: 0.0,
+            tension_waves: vec![],
+            resonances: vec![],
+            characters: vec![],
+            narrative_pressure: NarrativePressure {
+                building: false,
+                peak_predicted_at: None,
+                release_needed: false,
+                suggested_catharsis: vec![],
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nemesis_of_one_is_bond_of_another() {
+        let aria = parse_living_sheet(r#"
+            <h1 class="name">Aria Vance</h1>
+            <div data-fear>Miedo a la oscuridad eterna</div>
+            <div data-nemesis data-name="Kael"></div>
+        "#);
+        let mut lyra = parse_living_sheet(r#"
+            <h1 class="name">Lyra Moon</h1>
+            <div data-fear>La oscuridad que devora recuerdos</div>
+        "#);
+        lyra.bonds.push(SoulBond {
+            entity_name: "Kael".to_string(),
+            bond_type: BondType::Blood,
+            strength: 1.0,
+            status: BondStatus::Active,
+        });
+
+        let report = calculate_souls_tension(
+            &[aria, lyra],
+            "Aria vio a Kael cruzar la oscuridad. Lyra le tendió la mano a su hermano.",
+            Language::Es,
+        );
+
+        let loyalty = report.resonances.iter()
+            .find(|r| matches!(r.kind, ResonanceKind::DividedLoyalty))
+            .expect("lealtad dividida");
+        assert_eq!(loyalty.souls, vec!["Aria Vance", "Lyra Moon"]);
+        assert!(!loyalty.positions.is_empty());
+
+        // "oscuridad" despierta el miedo de ambas: una sola onda compartida
+        let shared = report.tension_waves.iter()
+            .find(|w| matches!(w.wave_type, WaveType::FearApproach))
+            .unwrap();
+        assert_eq!(shared.souls.len(), 2);
+        assert!(report.resonances.iter().any(|r| matches!(r.kind, ResonanceKind::SharedFear)));
+
+        assert_eq!(report.characters.len(), 2);
+        let total: f32 = report.characters.iter().map(|c| c.pressure_share).sum();
+        assert!((total - 1.0).abs() < 1e-4);
+        assert!(report.characters.iter().all(|c| c.present));
+    }
+}
@@ -0,0 +1,132 @@
+//! Errores tipados de las entradas JSON del motor
+//!
+//! Las funciones JSON de siempre (`calculate_tension`, `check_violations`...)
+//! devuelven un resultado vacío cuando el JSON no sirve. Sus variantes `try_*`
+//! devuelven en cambio un `ForgeError` que dice qué argumento falló, la ruta
+//! dentro del documento y el motivo, para distinguir "nada encontrado" de
+//! "entrada inválida".
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use crate::selector_profile::ProfileError;
+use crate::soul_parser::LivingSoul;
+use crate::world_authoring::WorldSeedError;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForgeError {
+    pub kind: ForgeErrorKind,
+    pub input: String,      // Argumento que falló: "soul", "souls", "world", "point"...
+    pub path: String,       // Ruta en el documento ("scars[0].narrative_weight"); vacía = todo
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ForgeErrorKind {
+    Syntax,         // No es JSON válido
+    Structure,      // JSON válido con la forma equivocada
+    Invalid,        // Forma correcta, valor inaceptable (rangos, índices, reglas)
+}
+
+impl ForgeError {
+    pub fn new(kind: ForgeErrorKind, input: &str, path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { kind, input: input.to_string(), path: path.into(), message: message.into() }
+    }
+
+    /// Error de serde_json sin ruta conocida
+    pub fn from_json(input: &str, error: &serde_json::Error) -> Self {
+        Self::new(json_kind(error), input, "", error.to_string())
+    }
+}
+
+impl fmt::Display for ForgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}: {}", self.input, self.message)
+        } else {
+            write!(f, "{}.{}: {}", self.input, self.path, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ForgeError {}
+
+impl From<WorldSeedError> for ForgeError {
+    fn from(error: WorldSeedError) -> Self {
+        Self::new(ForgeErrorKind::Invalid, "world", error.path, error.message)
+    }
+}
+
+impl From<ProfileError> for ForgeError {
+    fn from(error: ProfileError) -> Self {
+        Self::new(ForgeErrorKind::Invalid, "profiles", error.path, error.message)
+    }
+}
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Deserializa `json` como `T` guardando la ruta del campo que falla.
+/// `input` nombra el argumento en el error.
+pub fn parse_json<T: DeserializeOwned>(input: &str, json: &str) -> Result<T, ForgeError> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let value: T = serde_path_to_error::deserialize(&mut deserializer).map_err(|e| {
+        let path = e.path().to_string();
+        let path = if path == "." { String::new() } else { path };
+        let inner = e.into_inner();
+        ForgeError::new(json_kind(&inner), input, path, inner.to_string())
+    })?;
+    deserializer.end().map_err(|e| ForgeError::from_json(input, &e))?;
+    Ok(value)
+}
+
+/// Un `LivingSoul` o una lista de ellos
+pub fn parse_souls_json(json: &str) -> Result<Vec<LivingSoul>, ForgeError> {
+    if json.trim_start().starts_with('[') {
+        parse_json("souls", json)
+    } else {
+        parse_json("souls", json).map(|soul| vec![soul])
+    }
+}
+
+fn json_kind(error: &serde_json::Error) -> ForgeErrorKind {
+    match error.classify() {
+        serde_json::error::Category::Data => ForgeErrorKind::Structure,
+        _ => ForgeErrorKind::Syntax,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Language;
+    use crate::soul_parser::parse_living_sheet;
+    use crate::tension_engine::{calculate_tension, try_calculate_tension};
+
+    #[test]
+    fn bad_input_is_told_apart_from_empty_results() {
+        let valid = serde_json::to_value(parse_living_sheet(
+            r#"<h1 class="name">Aria</h1><div data-scar data-name="Marca"></div>"#,
+        )).unwrap();
+        let mut soul = valid.clone();
+        soul["scars"][0]["narrative_weight"] = "pesada".into();
+        let json = soul.to_string();
+
+        // La API de siempre sigue devolviendo vacío...
+        assert!(calculate_tension(&json, "texto", Language::Es).tension_waves.is_empty());
+        // ...la tipada dice dónde está el problema
+        let error = try_calculate_tension(&json, "texto", Language::Es).unwrap_err();
+        assert_eq!(error.kind, ForgeErrorKind::Structure);
+        assert_eq!(error.input, "soul");
+        assert_eq!(error.path, "scars[0].narrative_weight");
+
+        let error = try_calculate_tension("{\"name\": ", "texto", Language::Es).unwrap_err();
+        assert_eq!(error.kind, ForgeErrorKind::Syntax);
+
+        let error = parse_souls_json(&format!("[{}, {{}}]", valid)).unwrap_err();
+        // Un campo ausente se señala en el objeto que lo debía tener
+        assert_eq!(error.path, "[1]");
+        assert!(error.message.contains("name"));
+    }
+}
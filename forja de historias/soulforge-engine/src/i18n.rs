@@ -0,0 +1,186 @@
+//! Bancos de plantillas por idioma
+//!
+//! Todo el texto que el motor dirige al escritor (títulos y ganchos de
+//! inflexión, violaciones, sugerencias, cambios del alma) sale de
+//! `locales/<idioma>.toml`. Cada banco tiene `templates`, con huecos
+//! `{nombre}`, y `terms`, las frases que los auditores buscan en la narrativa.
+//! El español es el banco de referencia: una clave ausente en otro idioma cae
+//! a él. Los errores de validación (JSON, mundos, perfiles) siguen en español:
+//! van al desarrollador, no al texto del escritor.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::OnceLock;
+use crate::text_analysis::{is_cjk_char, tokenize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    Es,
+    En,
+    #[serde(alias = "ja")]
+    Jp,
+}
+
+pub const LANGUAGES: &[Language] = &[Language::Es, Language::En, Language::Jp];
+
+/// Un hueco de plantilla y su valor
+pub type TemplateArg<'a> = (&'a str, &'a dyn Display);
+
+#[derive(Debug, Default, Deserialize)]
+struct Bank {
+    #[serde(default)]
+    templates: HashMap<String, String>,
+    #[serde(default)]
+    terms: HashMap<String, Vec<String>>,
+}
+
+/// Proporción mínima de kana/kanji frente a letras latinas para leer japonés
+const JAPANESE_SHARE: usize = 2;
+
+impl Language {
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::Es => "es",
+            Language::En => "en",
+            Language::Jp => "jp",
+        }
+    }
+
+    /// "es", "en-GB", "ja", "JP"... `None` si no hay banco para ese idioma
+    pub fn parse(code: &str) -> Option<Self> {
+        let base = code.trim().split(['-', '_']).next().unwrap_or_default().to_lowercase();
+        match base.as_str() {
+            "es" => Some(Language::Es),
+            "en" => Some(Language::En),
+            "jp" | "ja" => Some(Language::Jp),
+            _ => None,
+        }
+    }
+
+    /// Idioma en que está escrito `text`: japonés por escritura, español o
+    /// inglés por sus palabras vacías. `None` si no hay señal clara.
+    pub fn detect(text: &str) -> Option<Self> {
+        let japanese = text.chars().filter(|c| is_cjk_char(*c)).count();
+        let latin = text.chars().filter(|c| c.is_alphabetic() && !is_cjk_char(*c)).count();
+        if japanese > 0 && japanese * JAPANESE_SHARE >= latin {
+            return Some(Language::Jp);
+        }
+
+        let tokens = tokenize(text);
+        let score = |lang: Language| {
+            let stopwords = terms(lang, "detect.stopwords");
+            tokens.iter().filter(|t| stopwords.contains(&t.folded)).count()
+        };
+        let (es, en) = (score(Language::Es), score(Language::En));
+        match es.cmp(&en) {
+            std::cmp::Ordering::Greater => Some(Language::Es),
+            std::cmp::Ordering::Less => Some(Language::En),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// El idioma de `text` si se reconoce; si no, `self`
+    pub fn resolve(self, text: &str) -> Self {
+        Self::detect(text).unwrap_or(self)
+    }
+}
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Plantilla `key` en `lang` con sus huecos rellenos.
+/// Una clave que no existe en ningún banco se devuelve tal cual.
+pub fn tr(lang: Language, key: &str, args: &[TemplateArg]) -> String {
+    let template = bank(lang).templates.get(key)
+        .or_else(|| bank(Language::Es).templates.get(key));
+    match template {
+        Some(template) => fill(template, args),
+        None => key.to_string(),
+    }
+}
+
+/// Frases de detección `key` en `lang` (pueden llevar huecos)
+pub fn terms(lang: Language, key: &str) -> &'static [String] {
+    bank(lang).terms.get(key)
+        .or_else(|| bank(Language::Es).terms.get(key))
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// Frases de detección `key` de todos los idiomas, para datos del alma
+/// que pueden estar escritos en cualquiera de ellos
+pub fn terms_in_any(key: &str) -> impl Iterator<Item = &'static String> + '_ {
+    LANGUAGES.iter().flat_map(move |lang| bank(*lang).terms.get(key).into_iter().flatten())
+}
+
+/// Sustituye cada `{nombre}` de `template` por su valor
+pub fn fill(template: &str, args: &[TemplateArg]) -> String {
+    args.iter().fold(template.to_string(), |text, (name, value)| {
+        text.replace(&format!("{{{}}}", name), &value.to_string())
+    })
+}
+
+fn bank(lang: Language) -> &'static Bank {
+    static ES: OnceLock<Bank> = OnceLock::new();
+    static EN: OnceLock<Bank> = OnceLock::new();
+    static JP: OnceLock<Bank> = OnceLock::new();
+
+    let (cell, source) = match lang {
+        Language::Es => (&ES, include_str!("../locales/es.toml")),
+        Language::En => (&EN, include_str!("../locales/en.toml")),
+        Language::Jp => (&JP, include_str!("../locales/jp.toml")),
+    };
+    // Los bancos se validan en los tests; uno roto no debe tumbar el motor
+    cell.get_or_init(|| toml::from_str(source).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn placeholders(template: &str) -> BTreeSet<&str> {
+        template.split('{').skip(1).filter_map(|rest| rest.split_once('}')).map(|(name, _)| name).collect()
+    }
+
+    #[test]
+    fn banks_share_keys_and_placeholders() {
+        let sources = [
+            include_str!("../locales/es.toml"),
+            include_str!("../locales/en.toml"),
+            include_str!("../locales/jp.toml"),
+        ];
+        let banks: Vec<Bank> = sources.iter().map(|s| toml::from_str(s).unwrap()).collect();
+        let reference = &banks[0];
+        assert!(!reference.templates.is_empty());
+
+        for (lang, bank) in LANGUAGES.iter().zip(&banks) {
+            let keys: BTreeSet<_> = bank.templates.keys().collect();
+            assert_eq!(keys, reference.templates.keys().collect(), "plantillas de {:?}", lang);
+            let term_keys: BTreeSet<_> = bank.terms.keys().collect();
+            assert_eq!(term_keys, reference.terms.keys().collect(), "términos de {:?}", lang);
+
+            for (key, template) in &bank.templates {
+                assert_eq!(placeholders(template), placeholders(&reference.templates[key]), "{:?} {}", lang, key);
+            }
+            for (key, list) in &bank.terms {
+                assert!(!list.is_empty(), "{:?} {} vacío", lang, key);
+            }
+        }
+    }
+
+    #[test]
+    fn detects_the_narrative_language() {
+        assert_eq!(Language::detect("Aria desenvainó la espada y miró a su hermano."), Some(Language::Es));
+        assert_eq!(Language::detect("Aria drew the sword and looked at her brother."), Some(Language::En));
+        assert_eq!(Language::detect("アリアは剣を抜いた。"), Some(Language::Jp));
+        assert_eq!(Language::detect("Aria"), None);
+        assert_eq!(Language::parse("ja-JP"), Some(Language::Jp));
+        assert_eq!(tr(Language::En, "drift.warning", &[("name", &"Aria"), ("archetype", &"Hero"), ("action", &"fled")]),
+            "⚠️ Character drift: Aria has the 'Hero' archetype, but the action 'fled' suggests a fundamental change. Is it intentional?");
+    }
+}
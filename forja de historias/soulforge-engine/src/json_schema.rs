@@ -0,0 +1,406 @@
+//! JSON Schemas (draft 2020-12) de los tipos que cruzan la frontera del motor
+//!
+//! Igual que `world_seed_schema`, se escriben a mano junto a los tipos y se
+//! publican en `schemas/` con `soulforge schema <tipo>`. Los tests comparan
+//! los archivos publicados con lo que genera el motor y validan un valor real
+//! de cada tipo contra su esquema, así que un campo nuevo sin esquema falla.
+
+use serde_json::{json, Value};
+use crate::world_authoring::world_seed_schema;
+
+/// Generador de un esquema
+pub type SchemaFn = fn() -> Value;
+
+/// Esquemas publicados: nombre corto, archivo en `schemas/` y generador
+pub const ENGINE_SCHEMAS: &[(&str, &str, SchemaFn)] = &[
+    ("soul", "living_soul.schema.json", living_soul_schema),
+    ("world", "world_seed.schema.json", world_seed_schema),
+    ("coherence", "coherence_report.schema.json", coherence_report_schema),
+    ("tension", "tension_analysis.schema.json", tension_analysis_schema),
+];
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Esquema por nombre corto ("soul", "world", "coherence", "tension")
+pub fn engine_schema(name: &str) -> Option<Value> {
+    ENGINE_SCHEMAS.iter()
+        .find(|(short, _, _)| *short == name)
+        .map(|(_, _, schema)| schema())
+}
+
+/// Esquema de `LivingSoul`
+pub fn living_soul_schema() -> Value {
+    let unit = json!({ "type": "number", "minimum": 0.0, "maximum": 1.0 });
+    let strings = json!({ "type": "array", "items": { "type": "string" } });
+    let count = json!({ "type": "integer", "minimum": 0 });
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "LivingSoul",
+        "description": "Alma viva de SoulForge: identidad, psique, marcas del destino, vínculos y crónica",
+        "type": "object",
+        "required": [
+            "name", "titles", "archetype", "psyche", "scars", "prophecies", "nemesis",
+            "bonds", "chronicle", "tension_markers", "raw_attributes"
+        ],
+        "properties": {
+            "name": { "type": "string" },
+            "titles": strings,
+            "archetype": { "type": "string" },
+            "psyche": { "$ref": "#/$defs/SoulPsyche" },
+            "scars": { "type": "array", "items": { "$ref": "#/$defs/SoulScar" } },
+            "prophecies": { "type": "array", "items": { "$ref": "#/$defs/Prophecy" } },
+            "nemesis": { "oneOf": [{ "type": "null" }, { "$ref": "#/$defs/Nemesis" }] },
+            "bonds": { "type": "array", "items": { "$ref": "#/$defs/SoulBond" } },
+            "chronicle": { "type": "array", "items": { "$ref": "#/$defs/ChronicleEntry" } },
+            "tension_markers": { "$ref": "#/$defs/TensionMarkers" },
+            "raw_attributes": { "type": "object", "additionalProperties": { "type": "string" } }
+        },
+        "$defs": {
+            "SoulPsyche": {
+                "type": "object",
+                "required": ["core_fear", "deepest_desire", "fatal_flaw", "moral_code", "breaking_point", "shadow_self"],
+                "properties": {
+                    "core_fear": { "type": "string" },
+                    "deepest_desire": { "type": "string" },
+                    "fatal_flaw": { "type": "string" },
+                    "moral_code": strings,
+                    "breaking_point": { "type": "string" },
+                    "shadow_self": { "type": "string" }
+                }
+            },
+            "SoulScar": {
+                "type": "object",
+                "required": ["id", "name", "origin_event", "trauma_type", "healed", "narrative_weight"],
+                "properties": {
+                    "id": { "type": "string" },
+                    "name": { "type": "string" },
+                    "origin_event": { "type": "string" },
+                    "trauma_type": open_enum(&["Betrayal", "Loss", "Failure", "Violence", "Abandonment", "Corruption"], "Custom"),
+                    "healed": { "type": "boolean" },
+                    "narrative_weight": unit
+                }
+            },
+            "Prophecy": {
+                "type": "object",
+                "required": ["id", "text", "prophecy_type", "fulfilled", "progress"],
+                "properties": {
+                    "id": { "type": "string" },
+                    "text": { "type": "string" },
+                    "prophecy_type": { "enum": ["Doom", "Glory", "Ambiguous", "Choice"] },
+                    "fulfilled": { "type": "boolean" },
+                    "progress": unit
+                }
+            },
+            "Nemesis": {
+                "type": "object",
+                "required": ["name", "relationship", "conflict_core", "threat_level", "encounters"],
+                "properties": {
+                    "name": { "type": "string" },
+                    "relationship": { "type": "string" },
+                    "conflict_core": { "type": "string" },
+                    "threat_level": { "type": "integer", "minimum": 0, "maximum": 255 },
+                    "encounters": strings,
+                    "ledger": { "type": "array", "items": { "$ref": "#/$defs/NemesisEncounter" } }
+                }
+            },
+            "NemesisEncounter": {
+                "type": "object",
+                "required": ["chapter", "outcome", "upper_hand", "passage", "threat_level"],
+                "properties": {
+                    "chapter": count,
+                    "outcome": { "enum": ["Victory", "Escape", "Truce", "Standoff"] },
+                    "upper_hand": { "enum": ["Soul", "Nemesis", "Neither"] },
+                    "passage": { "type": "string" },
+                    "threat_level": { "type": "integer", "minimum": 0, "maximum": 255 }
+                }
+            },
+            "SoulBond": {
+                "type": "object",
+                "required": ["entity_name", "bond_type", "strength", "status"],
+                "properties": {
+                    "entity_name": { "type": "string" },
+                    "bond_type": open_enum(
+                        &["Love", "Friendship", "Rivalry", "Mentorship", "Blood", "Oath", "Debt", "Hatred"],
+                        "Complex",
+                    ),
+                    "strength": unit,
+                    "status": { "enum": ["Active", "Strained", "Broken", "Dormant", "Evolving"] }
+                }
+            },
+            "ChronicleEntry": {
+                "type": "object",
+                "required": ["chapter", "event_summary", "souls_involved", "consequences", "tension_delta"],
+                "properties": {
+                    "chapter": count,
+                    "event_summary": { "type": "string" },
+                    "souls_involved": strings,
+                    "consequences": strings,
+                    "tension_delta": { "type": "number" }
+                }
+            },
+            "TensionMarkers": {
+                "type": "object",
+                "required": [
+                    "unresolved_conflicts", "broken_promises", "pending_revenge",
+                    "prophecy_pressure", "relationship_strain", "overall_tension"
+                ],
+                "properties": {
+                    "unresolved_conflicts": count,
+                    "broken_promises": count,
+                    "pending_revenge": count,
+                    "prophecy_pressure": { "type": "number" },
+                    "relationship_strain": { "type": "number" },
+                    "overall_tension": { "type": "number" }
+                }
+            }
+        }
+    })
+}
+
+/// Esquema de `CoherenceReport` (salida de `check_violations`)
+pub fn coherence_report_schema() -> Value {
+    let severity = json!({ "enum": ["Critical", "Warning", "Notice"] });
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "CoherenceReport",
+        "description": "Auditoría de coherencia de un texto frente a un alma",
+        "type": "object",
+        "required": ["is_coherent", "coherence_score", "violations", "character_drift_warning", "suggested_revisions"],
+        "properties": {
+            "is_coherent": { "type": "boolean" },
+            "coherence_score": { "type": "number", "minimum": 0.0, "maximum": 1.0 },
+            "violations": { "type": "array", "items": { "$ref": "#/$defs/CoherenceViolation" } },
+            "character_drift_warning": { "type": ["string", "null"] },
+            "suggested_revisions": { "type": "array", "items": { "type": "string" } }
+        },
+        "$defs": {
+            "CoherenceViolation": {
+                "type": "object",
+                "required": [
+                    "severity", "violation_type", "description", "conflicting_element",
+                    "source_reference", "suggestion", "position_hint"
+                ],
+                "properties": {
+                    "severity": severity,
+                    "violation_type": {
+                        "enum": [
+                            "BiographyContradiction", "PersonalityBreach", "ScarViolation", "BondInconsistency",
+                            "ProphecyConflict", "DeadCharacterAppears", "TimelineError", "MoralCodeViolation",
+                            "WorldLawBreach"
+                        ]
+                    },
+                    "description": { "type": "string" },
+                    "conflicting_element": { "type": "string" },
+                    "source_reference": { "type": "string" },
+                    "suggestion": { "type": "string" },
+                    "position_hint": { "type": ["integer", "null"], "minimum": 0 },
+                    "rationale": { "$ref": "#/$defs/ViolationRationale" }
+                }
+            },
+            "ViolationRationale": {
+                "type": "object",
+                "required": ["reason", "matched_text", "sentence", "modifiers", "base_severity"],
+                "properties": {
+                    "reason": { "type": "string" },
+                    "matched_text": { "type": "string" },
+                    "sentence": { "type": "string" },
+                    "modifiers": { "type": "array", "items": { "$ref": "#/$defs/ContextModifier" } },
+                    "base_severity": { "oneOf": [{ "type": "null" }, severity] }
+                }
+            },
+            "ContextModifier": {
+                "oneOf": [
+                    { "enum": ["QuotedDialogue"] },
+                    variant("Negated", "cue"),
+                    variant("Hypothetical", "cue"),
+                    variant("OtherSpeaker", "speaker")
+                ]
+            }
+        }
+    })
+}
+
+/// Esquema de `TensionAnalysis` (salida de `calculate_tension`)
+pub fn tension_analysis_schema() -> Value {
+    let unit = json!({ "type": "number", "minimum": 0.0, "maximum": 1.0 });
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "TensionAnalysis",
+        "description": "Ondas de tensión que un texto despierta en un alma",
+        "type": "object",
+        "required": ["overall_score", "tension_waves", "triggered_elements", "narrative_pressure", "recommended_escalation"],
+        "properties": {
+            "overall_score": unit,
+            "tension_waves": { "type": "array", "items": { "$ref": "#/$defs/TensionWave" } },
+            "triggered_elements": { "type": "array", "items": { "$ref": "#/$defs/TriggeredElement" } },
+            "narrative_pressure": { "$ref": "#/$defs/NarrativePressure" },
+            "recommended_escalation": { "type": ["string", "null"] }
+        },
+        "$defs": {
+            "TensionWave": {
+                "type": "object",
+                "required": ["position", "intensity", "wave_type", "source"],
+                "properties": {
+                    "position": { "type": "integer", "minimum": 0 },
+                    "intensity": unit,
+                    "wave_type": {
+                        "enum": ["FearApproach", "DesireConflict", "ScarResonance", "ProphecyPulse", "NemesisShadow", "BondStrain"]
+                    },
+                    "source": { "type": "string" }
+                }
+            },
+            "TriggeredElement": {
+                "type": "object",
+                "required": ["element_type", "element_id", "trigger_phrase", "resonance_strength"],
+                "properties": {
+                    "element_type": { "type": "string" },
+                    "element_id": { "type": "string" },
+                    "trigger_phrase": { "type": "string" },
+                    "resonance_strength": unit
+                }
+            },
+            "NarrativePressure": {
+                "type": "object",
+                "required": ["building", "peak_predicted_at", "release_needed", "suggested_catharsis"],
+                "properties": {
+                    "building": { "type": "boolean" },
+                    "peak_predicted_at": { "type": ["integer", "null"], "minimum": 0 },
+                    "release_needed": { "type": "boolean" },
+                    "suggested_catharsis": { "type": "array", "items": { "type": "string" } }
+                }
+            }
+        }
+    })
+}
+
+/// Enum de serde con variantes fijas más una libre (`{ "Custom": "texto" }`)
+fn open_enum(variants: &[&str], free: &str) -> Value {
+    json!({
+        "oneOf": [
+            { "enum": variants },
+            {
+                "type": "object",
+                "required": [free],
+                "properties": { free: { "type": "string" } },
+                "additionalProperties": false
+            }
+        ]
+    })
+}
+
+/// Variante con un único campo de texto (`{ "Negated": { "cue": "no" } }`)
+fn variant(name: &str, field: &str) -> Value {
+    json!({
+        "type": "object",
+        "required": [name],
+        "properties": {
+            name: {
+                "type": "object",
+                "required": [field],
+                "properties": { field: { "type": "string" } }
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::destiny_director::check_soul_violations;
+    use crate::i18n::Language;
+    use crate::soul_parser::parse_living_sheet;
+    use crate::tension_engine::calculate_soul_tension;
+
+    /// Validador mínimo: tipos, required, enum, oneOf, $ref y propiedades conocidas
+    fn conforms(schema: &Value, value: &Value, root: &Value, path: &str) -> Result<(), String> {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let name = reference.trim_start_matches("#/$defs/");
+            return conforms(&root["$defs"][name], value, root, path);
+        }
+        if let Some(options) = schema.get("oneOf").and_then(Value::as_array) {
+            let matching = options.iter().filter(|o| conforms(o, value, root, path).is_ok()).count();
+            return if matching == 1 { Ok(()) } else { Err(format!("{}: {} opciones de oneOf encajan", path, matching)) };
+        }
+        if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+            return if allowed.contains(value) { Ok(()) } else { Err(format!("{}: {} fuera del enum", path, value)) };
+        }
+        if let Some(types) = schema.get("type") {
+            let types: Vec<&str> = match types {
+                Value::Array(list) => list.iter().filter_map(Value::as_str).collect(),
+                other => other.as_str().into_iter().collect(),
+            };
+            let actual = match value {
+                Value::Null => "null",
+                Value::Bool(_) => "boolean",
+                Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+                Value::Number(_) => "number",
+                Value::String(_) => "string",
+                Value::Array(_) => "array",
+                Value::Object(_) => "object",
+            };
+            if !types.iter().any(|t| *t == actual || (*t == "number" && actual == "integer")) {
+                return Err(format!("{}: se esperaba {:?}, llegó {}", path, types, actual));
+            }
+        }
+        if let (Some(object), Some(properties)) = (value.as_object(), schema.get("properties")) {
+            for required in schema["required"].as_array().into_iter().flatten() {
+                let key = required.as_str().unwrap();
+                if !object.contains_key(key) {
+                    return Err(format!("{}.{}: falta", path, key));
+                }
+            }
+            for (key, item) in object {
+                let Some(property) = properties.get(key) else {
+                    return Err(format!("{}.{}: no está en el esquema", path, key));
+                };
+                conforms(property, item, root, &format!("{}.{}", path, key))?;
+            }
+        } else if let (Some(object), Some(values)) = (value.as_object(), schema.get("additionalProperties")) {
+            for (key, item) in object {
+                conforms(values, item, root, &format!("{}.{}", path, key))?;
+            }
+        }
+        if let (Some(items), Some(list)) = (schema.get("items"), value.as_array()) {
+            for (idx, item) in list.iter().enumerate() {
+                conforms(items, item, root, &format!("{}[{}]", path, idx))?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn published_schemas_match_and_describe_real_values() {
+        let soul = parse_living_sheet(r#"
+            <h1 class="name">Aria</h1>
+            <div data-fear>La oscuridad</div>
+            <ul class="moral-code"><li>Nunca matar</li></ul>
+            <div data-scar data-name="Marca" data-trauma-type="exilio"></div>
+            <div data-prophecy data-prophecy-type="doom">La oscuridad caerá</div>
+            <div data-nemesis data-name="Kael"></div>
+            <div data-bond data-entity="Lyra" data-bond-type="oath" data-status="broken"></div>
+        "#);
+        let text = "Si Aria mató a Kael, nadie lo supo. Aria mató al guardia. La oscuridad llegó con Lyra.";
+        let samples = [
+            ("soul", serde_json::to_value(&soul).unwrap()),
+            ("coherence", serde_json::to_value(check_soul_violations(&soul, text, Language::Es)).unwrap()),
+            ("tension", serde_json::to_value(calculate_soul_tension(&soul, text, Language::Es)).unwrap()),
+        ];
+
+        for (name, sample) in samples {
+            let schema = engine_schema(name).unwrap();
+            conforms(&schema, &sample, &schema, name).unwrap();
+        }
+
+        for (name, file, schema) in ENGINE_SCHEMAS {
+            let path = format!("{}/schemas/{}", env!("CARGO_MANIFEST_DIR"), file);
+            let published: Value = serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+            assert_eq!(published, schema(), "schemas/{} desactualizado ({})", file, name);
+        }
+    }
+}
@@ -1,50 +1,63 @@
-use wasm_bindgen::prelude::*;
+//! SoulForge Engine - Motor narrativo de almas vivas
+//!
+//! El núcleo (parseo, tensión, coherencia, inflexiones, mundos) es Rust puro.
+//! Los bindings para el navegador viven en `wasm` y se activan con la feature
+//! `wasm` (activa por defecto).
 
+mod text_analysis;
+mod i18n;
+mod modality;
+mod forge_error;
+mod json_schema;
 mod soul_parser;
+mod selector_profile;
+mod markdown_sheet;
+mod sheet_renderer;
 mod tension_engine;
+mod ensemble_tension;
 mod destiny_director;
 mod conflict_forge;
+mod soul_evolution;
+mod destiny_simulator;
 mod world_seed;
+mod world_authoring;
+mod world_mechanics;
+mod alma_bridge;
+mod story_structure;
+mod manuscript_auditor;
+mod narrative_session;
+mod manuscript_workspace;
+mod prophecy_tracker;
+mod nemesis_ledger;
 
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use text_analysis::*;
+pub use i18n::*;
+pub use modality::*;
+pub use forge_error::*;
+pub use json_schema::*;
 pub use soul_parser::*;
+pub use selector_profile::*;
+pub use markdown_sheet::*;
+pub use sheet_renderer::*;
 pub use tension_engine::*;
+pub use ensemble_tension::*;
 pub use destiny_director::*;
 pub use conflict_forge::*;
+pub use soul_evolution::*;
+pub use destiny_simulator::*;
 pub use world_seed::*;
+pub use world_authoring::*;
+pub use world_mechanics::*;
+pub use alma_bridge::*;
+pub use story_structure::*;
+pub use manuscript_auditor::*;
+pub use narrative_session::*;
+pub use manuscript_workspace::*;
+pub use prophecy_tracker::*;
+pub use nemesis_ledger::*;
 
-/// Inicialización del Motor SoulForge
-#[wasm_bindgen(start)]
-pub fn ignite_forge() {
-    #[cfg(feature = "console_error_panic_hook")]
-    console_error_panic_hook::set_once();
-    
-    web_sys::console::log_1(&"⚔️ SoulForge Engine Ignited".into());
-}
-
-/// API Principal - Forja completa desde HTML
-#[wasm_bindgen]
-pub fn forge_soul_from_html(html_content: &str) -> JsValue {
-    let soul = soul_parser::parse_living_sheet(html_content);
-    serde_wasm_bindgen::to_value(&soul).unwrap_or(JsValue::NULL)
-}
-
-/// Análisis de tensión en tiempo real
-#[wasm_bindgen]
-pub fn analyze_narrative_tension(soul_json: &str, narrative_text: &str) -> JsValue {
-    let result = tension_engine::calculate_tension(soul_json, narrative_text);
-    serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
-}
-
-/// Auditoría de coherencia
-#[wasm_bindgen]
-pub fn audit_coherence(soul_json: &str, new_content: &str) -> JsValue {
-    let violations = destiny_director::check_violations(soul_json, new_content);
-    serde_wasm_bindgen::to_value(&violations).unwrap_or(JsValue::NULL)
-}
-
-/// Generación de puntos de inflexión
-#[wasm_bindgen]
-pub fn generate_inflection_points(soul_json: &str) -> JsValue {
-    let points = conflict_forge::create_inflection_points(soul_json);
-    serde_wasm_bindgen::to_value(&points).unwrap_or(JsValue::NULL)
-}
+#[cfg(feature = "wasm")]
+pub use wasm::*;
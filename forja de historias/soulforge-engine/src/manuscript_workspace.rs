@@ -0,0 +1,437 @@
+//! Proyecto de manuscrito para editores (la base de `soulforge-lsp`)
+//!
+//! Un proyecto son los capítulos Markdown y las fichas vivas de una carpeta.
+//! Cada ficha aporta un alma y cada capítulo tiene su `NarrativeSession` con
+//! todas las almas, así que editar un capítulo solo re-analiza sus párrafos y
+//! editar una ficha re-analiza todos los capítulos. Las posiciones son offsets
+//! en caracteres; `LineIndex` las traduce a líneas y columnas UTF-16, que es
+//! lo que piden los editores.
+//!
+//! Un `.md` es ficha si su front matter declara el nombre del alma; si no, es
+//! un capítulo. Los `.html`/`.htm` son siempre fichas. Los comentarios
+//! `<!-- -->` de un capítulo (entre ellos las notas de revisión) no se analizan.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use crate::destiny_director::CoherenceViolation;
+use crate::i18n::{tr, Language, TemplateArg};
+use crate::markdown_sheet::{declares_soul, parse_soul_sheet};
+use crate::narrative_session::{Finding, NarrativeSession};
+use crate::soul_parser::LivingSoul;
+use crate::tension_engine::TensionWave;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DocumentKind {
+    Sheet,
+    Chapter,
+}
+
+/// Violación de un capítulo con el tramo de texto que la provoca
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChapterDiagnostic {
+    pub soul: String,
+    pub start: usize,           // Offset en caracteres
+    pub end: usize,
+    pub violation: CoherenceViolation,
+}
+
+/// Tensión de una línea del capítulo para un alma
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TensionHint {
+    pub soul: String,
+    pub line: usize,
+    pub offset: usize,          // Fin de la línea, donde va la pista
+    pub intensity: f32,         // La onda más fuerte de la línea
+    pub label: String,
+    pub sources: Vec<String>,
+}
+
+/// Nota de revisión lista para insertar como comentario Markdown
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevisionNote {
+    pub title: String,
+    pub offset: usize,          // Inicio de la línea donde se inserta
+    pub note: String,           // Comentario completo, con su salto de línea
+    pub violation: Option<usize>,   // Índice en `diagnostics`, si viene de una violación
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ManuscriptWorkspace {
+    language: Language,
+    sheets: BTreeMap<String, LivingSoul>,       // Ruta -> alma
+    chapters: BTreeMap<String, NarrativeSession>,
+}
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Papel de un documento según su extensión y contenido; `None` si no es del proyecto
+pub fn document_kind(path: &str, content: &str) -> Option<DocumentKind> {
+    let extension = Path::new(path).extension()?.to_str()?.to_lowercase();
+    match extension.as_str() {
+        "html" | "htm" => Some(DocumentKind::Sheet),
+        "md" | "markdown" if declares_soul(content) => Some(DocumentKind::Sheet),
+        "md" | "markdown" => Some(DocumentKind::Chapter),
+        _ => None,
+    }
+}
+
+impl ManuscriptWorkspace {
+    pub fn new(lang: Language) -> Self {
+        Self { language: lang, ..Self::default() }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Cambia el idioma de los hallazgos. Devuelve los capítulos re-analizados.
+    pub fn set_language(&mut self, lang: Language) -> Vec<String> {
+        self.language = lang;
+        for session in self.chapters.values_mut() {
+            session.set_language(lang);
+        }
+        self.chapter_paths()
+    }
+
+    pub fn chapter_paths(&self) -> Vec<String> {
+        self.chapters.keys().cloned().collect()
+    }
+
+    pub fn souls(&self) -> impl Iterator<Item = &LivingSoul> {
+        self.sheets.values()
+    }
+
+    pub fn kind(&self, path: &str) -> Option<DocumentKind> {
+        if self.sheets.contains_key(path) {
+            Some(DocumentKind::Sheet)
+        } else if self.chapters.contains_key(path) {
+            Some(DocumentKind::Chapter)
+        } else {
+            None
+        }
+    }
+
+    /// Texto vigente de un capítulo, con los comentarios en blanco
+    pub fn text(&self, path: &str) -> Option<&str> {
+        self.chapters.get(path).map(|session| session.text())
+    }
+
+    /// Abre o actualiza un documento. Devuelve los capítulos cuyos hallazgos
+    /// pueden haber cambiado (los que hay que volver a publicar).
+    pub fn update(&mut self, path: &str, content: &str) -> Vec<String> {
+        match document_kind(path, content) {
+            Some(DocumentKind::Sheet) => {
+                // Un capítulo que gana front matter con nombre pasa a ser ficha
+                let mut touched: Vec<String> = self.chapters.remove(path).map(|_| path.to_string()).into_iter().collect();
+                let soul = parse_soul_sheet(content);
+                let replaced = self.sheets.insert(path.to_string(), soul.clone());
+                for session in self.chapters.values_mut() {
+                    if let Some(old) = replaced.as_ref().filter(|old| old.name != soul.name) {
+                        session.remove_soul(&old.name);
+                    }
+                    session.add_soul(soul.clone());
+                }
+                touched.extend(self.chapter_paths());
+                touched
+            },
+            Some(DocumentKind::Chapter) => {
+                let mut touched = self.remove_sheet(path);
+                let language = self.language;
+                let souls: Vec<LivingSoul> = self.sheets.values().cloned().collect();
+                let session = self.chapters.entry(path.to_string()).or_insert_with(|| {
+                    let mut session = NarrativeSession::new(language);
+                    for soul in souls {
+                        session.add_soul(soul);
+                    }
+                    session
+                });
+                session.set_text(&mask_comments(content));
+                if !touched.iter().any(|p| p == path) {
+                    touched.push(path.to_string());
+                }
+                touched
+            },
+            None => self.remove(path),
+        }
+    }
+
+    /// Quita un documento del proyecto. Devuelve los capítulos afectados
+    /// (incluido el propio, si lo era, para limpiar sus diagnósticos).
+    pub fn remove(&mut self, path: &str) -> Vec<String> {
+        if self.chapters.remove(path).is_some() {
+            return vec![path.to_string()];
+        }
+        self.remove_sheet(path)
+    }
+
+    /// Violaciones vigentes de un capítulo, por posición
+    pub fn diagnostics(&self, path: &str) -> Vec<ChapterDiagnostic> {
+        let Some(session) = self.chapters.get(path) else {
+            return vec![];
+        };
+        let len = session.text().chars().count();
+
+        session.findings().into_iter()
+            .filter_map(|f| match f.finding {
+                Finding::Violation(violation) => {
+                    let start = f.position.min(len);
+                    let width = violation.rationale.matched_text.chars().count().max(1);
+                    Some(ChapterDiagnostic { soul: f.soul, start, end: (start + width).min(len), violation })
+                },
+                Finding::Tension(_) => None,
+            })
+            .collect()
+    }
+
+    /// Una pista por línea y alma con la onda de tensión más fuerte
+    pub fn tension_hints(&self, path: &str) -> Vec<TensionHint> {
+        let Some(session) = self.chapters.get(path) else {
+            return vec![];
+        };
+        let index = LineIndex::new(session.text());
+
+        let mut hints: Vec<TensionHint> = Vec::new();
+        for finding in session.findings() {
+            let Finding::Tension(wave) = &finding.finding else {
+                continue;
+            };
+            let line = index.line_of(wave.position);
+            match hints.iter_mut().find(|h| h.line == line && h.soul == finding.soul) {
+                Some(hint) => {
+                    hint.intensity = hint.intensity.max(wave.intensity);
+                    if !hint.sources.contains(&wave.source) {
+                        hint.sources.push(wave.source.clone());
+                    }
+                },
+                None => hints.push(TensionHint {
+                    soul: finding.soul.clone(),
+                    line,
+                    offset: index.line_end(line),
+                    intensity: wave.intensity,
+                    label: String::new(),
+                    sources: vec![wave.source.clone()],
+                }),
+            }
+        }
+
+        for hint in &mut hints {
+            let args: [TemplateArg; 2] = [("name", &hint.soul), ("intensity", &percent(hint.intensity))];
+            hint.label = tr(self.language, "workspace.hint", &args);
+        }
+        hints.sort_by_key(|h| (h.line, h.soul.clone()));
+        hints
+    }
+
+    /// Texto Markdown para el cursor en `offset`: la tensión global de cada
+    /// alma en el capítulo y las ondas de esa línea
+    pub fn hover(&self, path: &str, offset: usize) -> Option<String> {
+        let session = self.chapters.get(path)?;
+        if session.souls().is_empty() {
+            return None;
+        }
+        let index = LineIndex::new(session.text());
+        let line = index.line_of(offset);
+        let findings = session.findings();
+
+        let mut blocks = Vec::new();
+        for (soul, analysis) in session.souls().iter().zip(session.tension()) {
+            let score = percent(analysis.overall_score);
+            let mut lines = vec![tr(self.language, "workspace.hover.soul", &[("name", &soul.name), ("score", &score)])];
+            let waves = findings.iter()
+                .filter(|f| f.soul == soul.name)
+                .filter_map(|f| match &f.finding {
+                    Finding::Tension(wave) if index.line_of(wave.position) == line => Some(wave),
+                    _ => None,
+                });
+            lines.extend(waves.map(|wave: &TensionWave| {
+                tr(self.language, "workspace.hover.wave", &[("source", &wave.source), ("intensity", &percent(wave.intensity))])
+            }));
+            lines.extend(analysis.recommended_escalation);
+            blocks.push(lines.join("\n"));
+        }
+        Some(blocks.join("\n\n"))
+    }
+
+    /// Notas de revisión para el tramo `[start, end]`: la sugerencia de cada
+    /// violación que lo toca y las revisiones generales de cada alma
+    pub fn revisions(&self, path: &str, start: usize, end: usize) -> Vec<RevisionNote> {
+        let Some(session) = self.chapters.get(path) else {
+            return vec![];
+        };
+        let index = LineIndex::new(session.text());
+
+        let mut notes: Vec<RevisionNote> = self.diagnostics(path).into_iter().enumerate()
+            .filter(|(_, d)| d.start <= end && start <= d.end)
+            .map(|(idx, d)| RevisionNote {
+                title: tr(self.language, "workspace.action.suggestion", &[("suggestion", &d.violation.suggestion)]),
+                offset: index.line_start(index.line_of(d.start)),
+                note: revision_comment(&d.violation.suggestion),
+                violation: Some(idx),
+            })
+            .collect();
+
+        let line_start = index.line_start(index.line_of(start));
+        for report in session.coherence() {
+            for revision in report.suggested_revisions {
+                if notes.iter().any(|n| n.note == revision_comment(&revision)) {
+                    continue;
+                }
+                notes.push(RevisionNote {
+                    title: tr(self.language, "workspace.action.revision", &[("revision", &revision)]),
+                    offset: line_start,
+                    note: revision_comment(&revision),
+                    violation: None,
+                });
+            }
+        }
+        notes
+    }
+
+    // ============================================================
+    // INTERNOS
+    // ============================================================
+
+    fn remove_sheet(&mut self, path: &str) -> Vec<String> {
+        let Some(soul) = self.sheets.remove(path) else {
+            return vec![];
+        };
+        // Otra ficha puede declarar la misma alma
+        let fallback = self.sheets.values().find(|s| s.name == soul.name).cloned();
+        for session in self.chapters.values_mut() {
+            match &fallback {
+                Some(other) => session.add_soul(other.clone()),
+                None => session.remove_soul(&soul.name),
+            };
+        }
+        self.chapter_paths()
+    }
+}
+
+/// Líneas de un texto: offsets en caracteres <-> (línea, columna UTF-16)
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    chars: Vec<char>,
+    starts: Vec<usize>,         // Offset del primer carácter de cada línea
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        let chars: Vec<char> = text.chars().collect();
+        let starts = std::iter::once(0)
+            .chain(chars.iter().enumerate().filter(|(_, c)| **c == '\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { chars, starts }
+    }
+
+    pub fn line_of(&self, offset: usize) -> usize {
+        self.starts.partition_point(|start| *start <= offset).saturating_sub(1)
+    }
+
+    pub fn line_start(&self, line: usize) -> usize {
+        self.starts.get(line).copied().unwrap_or(self.chars.len())
+    }
+
+    /// Offset del final de la línea, antes de su `\n` o `\r\n`
+    pub fn line_end(&self, line: usize) -> usize {
+        let mut end = self.starts.get(line + 1).map(|next| next - 1).unwrap_or(self.chars.len());
+        if end > self.line_start(line) && self.chars.get(end - 1) == Some(&'\r') {
+            end -= 1;
+        }
+        end
+    }
+
+    /// Offset en caracteres -> (línea, columna en unidades UTF-16)
+    pub fn position(&self, offset: usize) -> (usize, usize) {
+        let offset = offset.min(self.chars.len());
+        let line = self.line_of(offset);
+        let column = self.chars[self.line_start(line)..offset].iter().map(|c| c.len_utf16()).sum();
+        (line, column)
+    }
+
+    /// (línea, columna UTF-16) -> offset en caracteres, recortado a la línea
+    pub fn offset(&self, line: usize, column: usize) -> usize {
+        if line >= self.starts.len() {
+            return self.chars.len();
+        }
+        let (start, end) = (self.line_start(line), self.line_end(line));
+        let mut units = 0;
+        for (idx, c) in self.chars[start..end].iter().enumerate() {
+            if units >= column {
+                return start + idx;
+            }
+            units += c.len_utf16();
+        }
+        end
+    }
+}
+
+/// Sustituye cada comentario HTML por espacios (conserva los saltos de línea
+/// para que los offsets y las líneas no cambien)
+fn mask_comments(text: &str) -> String {
+    let mut masked = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(open) = rest.find("<!--") {
+        masked.push_str(&rest[..open]);
+        let close = rest[open..].find("-->").map(|end| open + end + 3).unwrap_or(rest.len());
+        masked.extend(rest[open..close].chars().map(|c| if c == '\n' { c } else { ' ' }));
+        rest = &rest[close..];
+    }
+    masked.push_str(rest);
+    masked
+}
+
+fn percent(value: f32) -> String {
+    format!("{:.0}", (value * 100.0).clamp(0.0, 100.0))
+}
+
+fn revision_comment(text: &str) -> String {
+    format!("<!-- soulforge: {} -->\n", text.replace("--", "—"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHEET: &str = "---\nname: Aria\nmoral_code:\n  - Honor ante todo\n---\n## Miedo\nla oscuridad\n";
+
+    #[test]
+    fn sheets_feed_every_chapter_and_chapters_report_violations() {
+        let mut workspace = ManuscriptWorkspace::new(Language::Es);
+        let chapter = "# Capítulo 1\n\nAl alba traicionó su palabra.\n\nAria entró en la oscuridad.\n<!-- soulforge: traicionó su palabra -->\n";
+        assert_eq!(workspace.update("cap1.md", chapter), vec!["cap1.md"]);
+        assert!(workspace.diagnostics("cap1.md").is_empty());
+
+        assert_eq!(workspace.update("aria.md", SHEET), vec!["cap1.md"]);
+        assert_eq!(workspace.kind("aria.md"), Some(DocumentKind::Sheet));
+        let diagnostics = workspace.diagnostics("cap1.md");
+        let moral = &diagnostics[0];
+        let moral_count = diagnostics.iter().filter(|d| d.violation.source_reference == moral.violation.source_reference).count();
+        assert_eq!(moral_count, 1, "el comentario no se analiza");
+        assert_eq!(moral.soul, "Aria");
+        assert_eq!(chapter.chars().skip(moral.start).take(moral.end - moral.start).collect::<String>(), "traicionó su palabra");
+
+        let hints = workspace.tension_hints("cap1.md");
+        assert!(hints.iter().any(|h| h.line == 4 && h.sources.iter().any(|s| s.contains("oscuridad"))));
+        assert!(workspace.hover("cap1.md", moral.start).unwrap().contains("**Aria**"));
+        let notes = workspace.revisions("cap1.md", moral.start, moral.end);
+        assert_eq!(notes[0].violation, Some(0));
+        assert_eq!(notes[0].offset, LineIndex::new(chapter).line_start(2));
+        assert_eq!(workspace.text("cap1.md").unwrap().chars().count(), chapter.chars().count());
+
+        assert_eq!(workspace.remove("aria.md"), vec!["cap1.md"]);
+        assert!(workspace.diagnostics("cap1.md").is_empty());
+    }
+
+    #[test]
+    fn line_index_speaks_utf16_columns() {
+        let index = LineIndex::new("🗡️ Aria\r\nla espada\n");
+        assert_eq!(index.position(3), (0, 4));
+        assert_eq!(index.offset(0, 4), 3);
+        assert_eq!(index.line_end(0), 7);
+        assert_eq!(index.position(index.line_start(1) + 3), (1, 3));
+        assert_eq!(index.offset(7, 0), 19);
+    }
+}
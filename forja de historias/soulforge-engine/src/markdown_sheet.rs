@@ -0,0 +1,553 @@
+//! Fichas vivas en Markdown con front matter YAML (`---`) o TOML (`+++`)
+//!
+//! El front matter lleva la identidad y la psique; las secciones con título
+//! (Miedo, Cicatrices, Profecías, Némesis, Vínculos...) llevan el resto. Los
+//! detalles de cada elemento van en un bloque `{clave: valor}` al final de la
+//! línea, con las mismas claves y valores por defecto que los atributos
+//! `data-*` de la ficha HTML, así que ambas rutas producen el mismo alma:
+//!
+//! ```markdown
+//! ---
+//! name: Aria Vance
+//! archetype: Guerrera
+//! ---
+//! ## Cicatrices
+//! - Marca de Kael — Kael la vendió {trauma: betrayal, weight: 0.9}
+//! ## Némesis
+//! Kael {threat: 8, relationship: hermano}
+//! ```
+//!
+//! Las claves del front matter que no son campos del alma van a `raw_attributes`.
+
+use pulldown_cmark::{Event, HeadingLevel, Parser, Tag, TagEnd};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use crate::soul_parser::*;
+use crate::text_analysis::fold;
+
+/// Secciones reconocidas: alias plegados (sin acentos, minúsculas)
+const SECTION_ALIASES: &[(Section, &[&str])] = &[
+    (Section::Titles, &["titulos", "titles"]),
+    (Section::Archetype, &["arquetipo", "archetype", "clase"]),
+    (Section::Fear, &["miedo", "temor", "fear"]),
+    (Section::Desire, &["deseo", "anhelo", "desire"]),
+    (Section::Flaw, &["defecto", "fatal flaw", "flaw"]),
+    (Section::MoralCode, &["codigo moral", "codigo", "moral code"]),
+    (Section::BreakingPoint, &["punto de quiebre", "breaking point"]),
+    (Section::ShadowSelf, &["yo sombra", "sombra", "shadow"]),
+    (Section::Scars, &["cicatrices", "cicatriz", "heridas", "scars"]),
+    (Section::Prophecies, &["profecias", "profecia", "prophecies", "prophecy"]),
+    (Section::Nemesis, &["nemesis", "enemigo", "archenemy"]),
+    (Section::Bonds, &["vinculos", "vinculo", "relaciones", "bonds"]),
+];
+
+/// Claves del front matter que son campos del alma
+const FRONT_MATTER_FIELDS: &[(&str, &[&str])] = &[
+    ("name", &["name", "nombre"]),
+    ("archetype", &["archetype", "arquetipo", "clase"]),
+    ("titles", &["titles", "titulos"]),
+    ("core_fear", &["core_fear", "fear", "miedo"]),
+    ("deepest_desire", &["deepest_desire", "desire", "deseo"]),
+    ("fatal_flaw", &["fatal_flaw", "flaw", "defecto"]),
+    ("moral_code", &["moral_code", "codigo_moral"]),
+    ("breaking_point", &["breaking_point", "punto_de_quiebre"]),
+    ("shadow_self", &["shadow_self", "yo_sombra", "sombra"]),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Titles,
+    Archetype,
+    Fear,
+    Desire,
+    Flaw,
+    MoralCode,
+    BreakingPoint,
+    ShadowSelf,
+    Scars,
+    Prophecies,
+    Nemesis,
+    Bonds,
+}
+
+/// Bloque de Markdown bajo un título
+#[derive(Default)]
+struct MdSection {
+    heading: String,
+    paragraphs: Vec<String>,
+    items: Vec<String>,
+}
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Ficha Markdown -> `LivingSoul`. Un front matter inválido se ignora;
+/// usa `parse_markdown_sheet_strict` para recibir el error.
+pub fn parse_markdown_sheet(markdown: &str) -> LivingSoul {
+    let (front, body) = match split_front_matter(markdown) {
+        Ok(parts) => parts,
+        Err(_) => (Map::new(), strip_front_matter(markdown)),
+    };
+    build_soul(front, body)
+}
+
+/// Como `parse_markdown_sheet`, pero falla si el front matter no se puede leer
+pub fn parse_markdown_sheet_strict(markdown: &str) -> Result<LivingSoul, String> {
+    let (front, body) = split_front_matter(markdown)?;
+    Ok(build_soul(front, body))
+}
+
+/// ¿Parece una ficha Markdown y no HTML? Toda ficha HTML empieza por una etiqueta
+pub fn is_markdown_sheet(content: &str) -> bool {
+    !content.trim_start_matches('\u{feff}').trim_start().starts_with('<')
+}
+
+/// ¿Declara un alma este Markdown? Las fichas llevan el nombre en el front
+/// matter; un capítulo no tiene front matter o solo metadatos propios (título, fecha...)
+pub fn declares_soul(markdown: &str) -> bool {
+    split_front_matter(markdown)
+        .map(|(mut front, _)| take_front_matter(&mut front, "name").is_some())
+        .unwrap_or(false)
+}
+
+/// Ficha en cualquier formato de texto: HTML o Markdown según el contenido
+pub fn parse_soul_sheet(content: &str) -> LivingSoul {
+    if is_markdown_sheet(content) {
+        parse_markdown_sheet(content)
+    } else {
+        parse_living_sheet(content)
+    }
+}
+
+fn build_soul(mut front: Map<String, Value>, body: &str) -> LivingSoul {
+    let (title, sections) = read_markdown(body);
+    let section = |wanted: Section| sections.iter()
+        .find(|s| section_kind(&s.heading) == Some(wanted));
+    let section_text = |wanted: Section| section(wanted)
+        .map(|s| s.paragraphs.iter().chain(&s.items).cloned().collect::<Vec<_>>().join(" "))
+        .filter(|text| !text.is_empty());
+    let section_list = |wanted: Section| section(wanted)
+        .map(|s| if s.items.is_empty() { s.paragraphs.clone() } else { s.items.clone() })
+        .unwrap_or_default();
+
+    let mut take = |field: &str| take_front_matter(&mut front, field);
+
+    let name = take("name").and_then(|v| as_text(&v))
+        .or(title)
+        .unwrap_or_else(|| "Alma Sin Nombre".to_string());
+    let archetype = take("archetype").and_then(|v| as_text(&v))
+        .or_else(|| section_text(Section::Archetype))
+        .unwrap_or_default();
+    let titles = take("titles").map(|v| as_list(&v))
+        .unwrap_or_else(|| section_list(Section::Titles));
+
+    let psyche = SoulPsyche {
+        core_fear: take("core_fear").and_then(|v| as_text(&v))
+            .or_else(|| section_text(Section::Fear))
+            .unwrap_or_default(),
+        deepest_desire: take("deepest_desire").and_then(|v| as_text(&v))
+            .or_else(|| section_text(Section::Desire))
+            .unwrap_or_default(),
+        fatal_flaw: take("fatal_flaw").and_then(|v| as_text(&v))
+            .or_else(|| section_text(Section::Flaw))
+            .unwrap_or_default(),
+        moral_code: take("moral_code").map(|v| as_list(&v))
+            .unwrap_or_else(|| section_list(Section::MoralCode)),
+        breaking_point: take("breaking_point").and_then(|v| as_text(&v))
+            .or_else(|| section_text(Section::BreakingPoint))
+            .unwrap_or_default(),
+        shadow_self: take("shadow_self").and_then(|v| as_text(&v))
+            .or_else(|| section_text(Section::ShadowSelf))
+            .unwrap_or_default(),
+    };
+
+    let scars: Vec<SoulScar> = section(Section::Scars)
+        .map(|s| s.items.iter().enumerate().map(|(idx, item)| parse_scar(idx, item)).collect())
+        .unwrap_or_default();
+    let prophecies: Vec<Prophecy> = section(Section::Prophecies)
+        .map(|s| s.items.iter().enumerate().map(|(idx, item)| parse_prophecy(idx, item)).collect())
+        .unwrap_or_default();
+    let nemesis = section(Section::Nemesis).and_then(parse_nemesis);
+    let bonds: Vec<SoulBond> = section(Section::Bonds)
+        .map(|s| s.items.iter().filter_map(|item| parse_bond(item)).collect())
+        .unwrap_or_default();
+
+    // === RAW: psique + front matter sobrante (como data-soul-attr) ===
+    let mut raw_attributes: HashMap<String, String> = HashMap::new();
+    raw_attributes.insert("core_fear".to_string(), psyche.core_fear.clone());
+    raw_attributes.insert("deepest_desire".to_string(), psyche.deepest_desire.clone());
+    raw_attributes.insert("fatal_flaw".to_string(), psyche.fatal_flaw.clone());
+    for (key, value) in front {
+        raw_attributes.insert(key, as_text(&value).unwrap_or_else(|| value.to_string()));
+    }
+
+    LivingSoul {
+        name,
+        titles,
+        archetype,
+        psyche,
+        tension_markers: calculate_initial_tension(&scars, &prophecies, &bonds),
+        scars,
+        prophecies,
+        nemesis,
+        bonds,
+        chronicle: Vec::new(),
+        raw_attributes,
+    }
+}
+
+// ============================================================
+// FRONT MATTER
+// ============================================================
+
+/// Separa el front matter (YAML entre `---` o TOML entre `+++`) del cuerpo
+fn split_front_matter(markdown: &str) -> Result<(Map<String, Value>, &str), String> {
+    let Some((fence, front, body)) = front_matter_parts(markdown) else {
+        return Ok((Map::new(), markdown));
+    };
+
+    let value: Value = if fence == "+++" {
+        toml::from_str(front).map_err(|e| format!("front matter TOML inválido: {}", e.message()))?
+    } else {
+        serde_yaml::from_str(front).map_err(|e| format!("front matter YAML inválido: {}", e))?
+    };
+
+    match value {
+        Value::Object(map) => Ok((map, body)),
+        Value::Null => Ok((Map::new(), body)),
+        _ => Err("el front matter debe ser un mapa de claves".to_string()),
+    }
+}
+
+fn strip_front_matter(markdown: &str) -> &str {
+    front_matter_parts(markdown).map(|(_, _, body)| body).unwrap_or(markdown)
+}
+
+fn front_matter_parts(markdown: &str) -> Option<(&'static str, &str, &str)> {
+    let text = markdown.trim_start_matches('\u{feff}');
+    let fence = ["---", "+++"].into_iter().find(|f| text.starts_with(f))?;
+    let first_line_end = text.find('\n')?;
+    if text[..first_line_end].trim_end() != fence {
+        return None;
+    }
+
+    let rest = &text[first_line_end + 1..];
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == fence {
+            return Some((fence, &rest[..offset], &rest[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+fn take_front_matter(front: &mut Map<String, Value>, field: &str) -> Option<Value> {
+    let aliases = FRONT_MATTER_FIELDS.iter()
+        .find(|(name, _)| *name == field)
+        .map(|(_, aliases)| *aliases)
+        .unwrap_or(&[]);
+    let key = front.keys()
+        .find(|k| aliases.contains(&normalize_key(k).as_str()))
+        .cloned()?;
+    front.remove(&key)
+}
+
+fn as_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.trim().to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn as_list(value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(items) => items.iter().filter_map(as_text).filter(|s| !s.is_empty()).collect(),
+        Value::String(s) => s.split(',').map(|x| x.trim().to_string()).filter(|x| !x.is_empty()).collect(),
+        _ => Vec::new(),
+    }
+}
+
+// ============================================================
+// CUERPO MARKDOWN
+// ============================================================
+
+/// Título H1 inicial y secciones con sus párrafos y elementos de lista
+fn read_markdown(body: &str) -> (Option<String>, Vec<MdSection>) {
+    let mut title: Option<String> = None;
+    let mut sections: Vec<MdSection> = vec![MdSection::default()];
+    let mut buffer = String::new();
+    let mut in_heading = false;
+    let mut open_items: Vec<(usize, String)> = Vec::new();   // (hueco reservado, texto)
+
+    for event in Parser::new(body) {
+        match event {
+            Event::Start(Tag::Heading { .. }) => {
+                in_heading = true;
+                buffer.clear();
+            },
+            Event::End(TagEnd::Heading(level)) => {
+                in_heading = false;
+                let heading = buffer.trim().to_string();
+                buffer.clear();
+                let is_title = level == HeadingLevel::H1 && title.is_none()
+                    && section_kind(&heading).is_none();
+                if is_title {
+                    title = Some(heading);
+                } else {
+                    sections.push(MdSection { heading, ..MdSection::default() });
+                }
+            },
+            Event::Start(Tag::Item) => {
+                let section = sections.last_mut().unwrap();
+                section.items.push(String::new());
+                open_items.push((section.items.len() - 1, String::new()));
+            },
+            Event::End(TagEnd::Item) => {
+                if let Some((slot, text)) = open_items.pop() {
+                    let section = sections.last_mut().unwrap();
+                    section.items[slot] = clean(&text);
+                }
+            },
+            Event::Start(Tag::Paragraph) if open_items.is_empty() => buffer.clear(),
+            Event::End(TagEnd::Paragraph) if open_items.is_empty() => {
+                let text = clean(&buffer);
+                buffer.clear();
+                if !text.is_empty() {
+                    sections.last_mut().unwrap().paragraphs.push(text);
+                }
+            },
+            Event::Text(text) | Event::Code(text) => {
+                match open_items.last_mut() {
+                    Some((_, item)) if !in_heading => item.push_str(&text),
+                    _ => buffer.push_str(&text),
+                }
+            },
+            Event::SoftBreak | Event::HardBreak => {
+                match open_items.last_mut() {
+                    Some((_, item)) if !in_heading => item.push(' '),
+                    _ => buffer.push(' '),
+                }
+            },
+            _ => {},
+        }
+    }
+
+    for section in &mut sections {
+        section.items.retain(|item| !item.is_empty());
+    }
+    (title, sections)
+}
+
+fn section_kind(heading: &str) -> Option<Section> {
+    let folded = fold(heading.trim().trim_end_matches(':'));
+    SECTION_ALIASES.iter()
+        .find(|(_, aliases)| aliases.iter().any(|alias| {
+            folded == *alias
+                || folded.strip_prefix(alias).is_some_and(|rest| rest.starts_with([' ', ':', '(', '/']))
+        }))
+        .map(|(section, _)| *section)
+}
+
+fn clean(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Separa el bloque final `{clave: valor, ...}` del texto del elemento
+fn split_attributes(item: &str) -> (String, BTreeMap<String, String>) {
+    let trimmed = item.trim();
+    let mut attributes = BTreeMap::new();
+    let (Some(open), true) = (trimmed.rfind('{'), trimmed.ends_with('}')) else {
+        return (trimmed.to_string(), attributes);
+    };
+
+    for pair in trimmed[open + 1..trimmed.len() - 1].split(',') {
+        let Some((key, value)) = pair.split_once([':', '=']) else {
+            continue;
+        };
+        let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+        attributes.insert(normalize_key(key), value.to_string());
+    }
+    (trimmed[..open].trim().to_string(), attributes)
+}
+
+fn normalize_key(key: &str) -> String {
+    fold(key.trim()).replace(['-', ' '], "_")
+}
+
+fn attribute<'a>(attributes: &'a BTreeMap<String, String>, keys: &[&str]) -> Option<&'a str> {
+    keys.iter().find_map(|k| attributes.get(*k).map(String::as_str))
+}
+
+// ============================================================
+// ELEMENTOS
+// ============================================================
+
+fn parse_scar(idx: usize, item: &str) -> SoulScar {
+    let (body, attrs) = split_attributes(item);
+    let (name, origin) = split_name(&body);
+
+    SoulScar {
+        id: attribute(&attrs, &["id"])
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| format!("scar_{}", idx)),
+        name: attribute(&attrs, &["name", "nombre"])
+            .map(|s| s.to_string())
+            .or(name)
+            .unwrap_or_else(|| format!("Cicatriz {}", idx + 1)),
+        origin_event: attribute(&attrs, &["origin", "origen"])
+            .map(|s| s.to_string())
+            .or(origin)
+            .unwrap_or_default(),
+        trauma_type: parse_trauma_type(attribute(&attrs, &["trauma", "trauma_type", "tipo", "type"]).unwrap_or("custom")),
+        healed: attribute(&attrs, &["healed", "sanada", "curada"])
+            .map(|s| s == "true" || fold(s) == "si")
+            .unwrap_or(false),
+        narrative_weight: attribute(&attrs, &["weight", "peso"])
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0.5),
+    }
+}
+
+fn parse_prophecy(idx: usize, item: &str) -> Prophecy {
+    let (text, attrs) = split_attributes(item);
+    Prophecy {
+        id: attribute(&attrs, &["id"])
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| format!("prophecy_{}", idx)),
+        text,
+        prophecy_type: parse_prophecy_type(attribute(&attrs, &["type", "tipo", "prophecy_type"]).unwrap_or("ambiguous")),
+        fulfilled: attribute(&attrs, &["fulfilled", "cumplida"])
+            .map(|s| s == "true" || fold(s) == "si")
+            .unwrap_or(false),
+        progress: attribute(&attrs, &["progress", "progreso"])
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0.0),
+    }
+}
+
+/// Primer párrafo (o primer elemento) = nombre y atributos; el resto de
+/// párrafos es el núcleo del conflicto y la lista, los encuentros
+fn parse_nemesis(section: &MdSection) -> Option<Nemesis> {
+    let mut paragraphs = section.paragraphs.iter();
+    let mut items = section.items.iter();
+    let head = paragraphs.next().or_else(|| items.next())?;
+    let (name, attrs) = split_attributes(head);
+    let rest: Vec<String> = paragraphs.cloned().collect();
+
+    Some(Nemesis {
+        name: attribute(&attrs, &["name", "nombre"]).map(|s| s.to_string()).unwrap_or(name),
+        relationship: attribute(&attrs, &["relationship", "relacion"])
+            .unwrap_or("desconocida")
+            .to_string(),
+        conflict_core: attribute(&attrs, &["conflict", "conflicto"])
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| rest.join(" ")),
+        threat_level: attribute(&attrs, &["threat", "amenaza"])
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(5),
+        encounters: items.cloned().collect(),
+        ledger: Vec::new(),
+    })
+}
+
+fn parse_bond(item: &str) -> Option<SoulBond> {
+    let (body, attrs) = split_attributes(item);
+    let entity_name = attribute(&attrs, &["entity", "name", "nombre"])
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| split_name(&body).0.unwrap_or_default());
+    if entity_name.is_empty() {
+        return None;
+    }
+
+    Some(SoulBond {
+        entity_name,
+        bond_type: parse_bond_type(attribute(&attrs, &["type", "tipo", "bond_type"]).unwrap_or("complex")),
+        strength: attribute(&attrs, &["strength", "fuerza"])
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0.5),
+        status: parse_bond_status(attribute(&attrs, &["status", "estado"]).unwrap_or("active")),
+    })
+}
+
+/// "Nombre — detalle", "Nombre: detalle" o solo "Nombre"
+fn split_name(body: &str) -> (Option<String>, Option<String>) {
+    for separator in [" — ", " – ", ": ", " - "] {
+        if let Some((name, detail)) = body.split_once(separator) {
+            return (Some(name.trim().to_string()), Some(detail.trim().to_string()));
+        }
+    }
+    let body = body.trim();
+    ((!body.is_empty()).then(|| body.to_string()), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML: &str = r#"
+        <h1 class="name">Aria Vance</h1>
+        <div data-archetype>Guerrera</div>
+        <div data-fear>Perder a su hermana</div>
+        <ul class="moral-code"><li>Honor ante todo</li><li>Nunca huir</li></ul>
+        <div data-scar data-name="Marca de Kael" data-origin="Kael la vendió" data-trauma-type="betrayal" data-weight="0.9"></div>
+        <div data-scar data-name="Exilio" data-trauma-type="loss"></div>
+        <div data-prophecy data-prophecy-type="doom" data-progress="0.25">La espada caerá</div>
+        <div data-nemesis data-name="Kael" data-relationship="hermano" data-threat="8"></div>
+        <div data-bond data-entity="Lyra" data-bond-type="oath" data-strength="0.8" data-status="strained"></div>
+        <span data-soul-attr="origen" data-value="Norte"></span>
+    "#;
+
+    #[test]
+    fn markdown_and_html_sheets_forge_the_same_soul() {
+        let yaml = r#"---
+name: Aria Vance
+archetype: Guerrera
+moral_code:
+  - Honor ante todo
+  - Nunca huir
+origen: Norte
+---
+# Ficha de Aria
+
+## Miedo
+Perder a su hermana
+
+## Cicatrices
+- **Marca de Kael** — Kael la vendió {trauma: betrayal, weight: 0.9}
+- Exilio {trauma: loss}
+
+## Profecías
+- La espada caerá {type: doom, progress: 0.25}
+
+## Némesis
+Kael {relationship: hermano, threat: 8}
+
+## Vínculos
+- Lyra {type: oath, strength: 0.8, status: strained}
+"#;
+        let toml = yaml
+            .replacen("---\nname: Aria Vance\narchetype: Guerrera\nmoral_code:\n  - Honor ante todo\n  - Nunca huir\norigen: Norte\n---",
+                "+++\nname = \"Aria Vance\"\narchetype = \"Guerrera\"\nmoral_code = [\"Honor ante todo\", \"Nunca huir\"]\norigen = \"Norte\"\n+++", 1);
+
+        let expected = serde_json::to_value(parse_living_sheet(HTML)).unwrap();
+        for markdown in [yaml, toml.as_str()] {
+            let soul = parse_markdown_sheet_strict(markdown).unwrap();
+            assert_eq!(serde_json::to_value(&soul).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn broken_front_matter_is_reported_in_strict_mode() {
+        let markdown = "---\nname: [sin cerrar\n---\n# Aria\n## Miedo\nEl olvido\n";
+        assert!(parse_markdown_sheet_strict(markdown).is_err());
+
+        // El modo tolerante sigue leyendo el cuerpo
+        let soul = parse_markdown_sheet(markdown);
+        assert_eq!(soul.name, "Aria");
+        assert_eq!(soul.psyche.core_fear, "El olvido");
+    }
+}
@@ -0,0 +1,394 @@
+//! Contexto modal de una coincidencia
+//!
+//! Que una frase aparezca en el texto no significa que ocurra en la historia:
+//! "Nunca traicionaría su palabra" niega la acción, "si huyera cobardemente"
+//! la plantea como hipótesis y «Mataste a mi padre» puede ser una acusación
+//! en boca de otro personaje. Este módulo clasifica cada coincidencia dentro
+//! de su oración para que los auditores ajusten la severidad.
+
+use serde::{Deserialize, Serialize};
+use crate::i18n::{tr, Language};
+use crate::text_analysis::{fold, AnalyzedText, TermMatch, Token};
+
+/// Circunstancia que rebaja la fuerza narrativa de una coincidencia
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContextModifier {
+    Negated { cue: String },        // "no", "nunca", "never"...
+    Hypothetical { cue: String },   // "si", condicional, "would"...
+    QuotedDialogue,                 // Dentro de comillas o raya de diálogo
+    OtherSpeaker { speaker: String }, // Diálogo atribuido a otro personaje
+}
+
+/// Oración de una coincidencia y las circunstancias que la rodean
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchContext {
+    pub sentence: String,
+    pub modifiers: Vec<ContextModifier>,
+}
+
+/// Palabras que niegan lo que viene detrás (ya plegadas)
+const NEGATION_CUES: &[&str] = &[
+    "no", "nunca", "jamas", "ni", "tampoco",
+    "not", "never", "nor", "neither", "cannot",
+];
+
+/// Solo niegan el verbo que llevan justo detrás: "sin dudarlo, traicionó"
+/// o "nadie lo vio" no niegan la acción
+const GOVERNING_NEGATION_CUES: &[&str] = &["sin", "nadie", "without", "nobody"];
+
+/// Cuántas palabras antes de la coincidencia puede estar la negación
+const NEGATION_WINDOW: usize = 4;
+
+/// Signos que cierran una cláusula dentro de la oración
+const CLAUSE_BREAKS: &[char] = &[',', ':', ';', '、'];
+
+/// Conjunciones adversativas: abren una cláusula nueva (ya plegadas)
+const ADVERSATIVES: &[&str] = &["pero", "sino", "mas", "but", "yet"];
+
+/// Negación japonesa tras el verbo
+const JP_NEGATION_CUES: &[&str] = &["ない", "なかっ", "ません"];
+
+/// Marcadores de hipótesis o deseo (ya plegados, pueden ser frases)
+const HYPOTHETICAL_CUES: &[&str] = &[
+    "quizas", "quiza", "tal vez", "acaso", "ojala", "podria", "podrian",
+    "supongamos", "imagina", "if", "would", "could", "might", "perhaps",
+    "maybe", "unless", "suppose", "imagine",
+];
+
+const JP_HYPOTHETICAL_CUES: &[&str] = &["もし", "なら", "たら"];
+
+/// Terminaciones del condicional español ("traicionaría", "matarían")
+const CONDITIONAL_ENDINGS: &[&str] = &["ria", "rias", "riamos", "rian"];
+
+/// Longitud mínima para leer "-ría" como condicional y no como "María" o "historia"
+const MIN_CONDITIONAL_CHARS: usize = 8;
+
+/// Verbos de habla para atribuir diálogos (ya plegados)
+pub(crate) const SPEECH_VERBS: &[&str] = &[
+    "dijo", "decia", "grito", "susurro", "respondio", "pregunto", "murmuro",
+    "exclamo", "replico", "anadio", "contesto", "espeto", "said", "says",
+    "asked", "replied", "whispered", "shouted", "answered", "cried",
+];
+
+/// Cuántas palabras a cada lado del diálogo se buscan para atribuirlo
+const ATTRIBUTION_WINDOW: usize = 4;
+
+const QUOTE_PAIRS: &[(char, char)] = &[
+    ('«', '»'), ('“', '”'), ('「', '」'), ('『', '』'), ('"', '"'),
+];
+
+const DIALOGUE_DASHES: &[char] = &['—', '―'];
+
+impl ContextModifier {
+    /// Cuántos niveles de severidad rebaja
+    pub fn severity_steps(&self) -> usize {
+        match self {
+            ContextModifier::Negated { .. } => 2,
+            ContextModifier::Hypothetical { .. } => 1,
+            ContextModifier::QuotedDialogue => 1,
+            ContextModifier::OtherSpeaker { .. } => 1, // Se suma al de QuotedDialogue
+        }
+    }
+
+    pub fn describe(&self, lang: Language) -> String {
+        match self {
+            ContextModifier::Negated { cue } => tr(lang, "modifier.negated", &[("cue", cue)]),
+            ContextModifier::Hypothetical { cue } => tr(lang, "modifier.hypothetical", &[("cue", cue)]),
+            ContextModifier::QuotedDialogue => tr(lang, "modifier.dialogue", &[]),
+            ContextModifier::OtherSpeaker { speaker } => tr(lang, "modifier.other_speaker", &[("speaker", speaker)]),
+        }
+    }
+}
+
+impl MatchContext {
+    pub fn severity_steps(&self) -> usize {
+        self.modifiers.iter().map(|m| m.severity_steps()).sum()
+    }
+
+    /// Sin negación ni hipótesis: la acción ocurre en la historia
+    pub fn is_assertive(&self) -> bool {
+        !self.modifiers.iter().any(|m| matches!(m,
+            ContextModifier::Negated { .. } | ContextModifier::Hypothetical { .. }
+        ))
+    }
+}
+
+/// Clasifica una coincidencia. `subject` es el nombre del personaje auditado:
+/// sus propios diálogos cuentan como diálogo, los de otros además como ajenos.
+pub fn match_context(text: &AnalyzedText, found: &TermMatch, subject: &str) -> MatchContext {
+    let sentence = text.sentence_at(found.char_start)
+        .map(|s| (s.char_start, s.char_end))
+        .unwrap_or((found.char_start, found.char_end));
+
+    let mut modifiers = Vec::new();
+
+    if let Some(cue) = negation_cue(text, found, sentence) {
+        modifiers.push(ContextModifier::Negated { cue });
+    }
+    if let Some(cue) = hypothetical_cue(text, found, sentence) {
+        modifiers.push(ContextModifier::Hypothetical { cue });
+    }
+    if let Some(span) = quote_spans(text.chars()).into_iter()
+        .find(|(start, end)| *start <= found.char_start && found.char_end <= *end)
+    {
+        modifiers.push(ContextModifier::QuotedDialogue);
+        if let Some(speaker) = attribute_speaker(text, span) {
+            if !is_subject(&speaker, subject) {
+                modifiers.push(ContextModifier::OtherSpeaker { speaker });
+            }
+        }
+    }
+
+    MatchContext {
+        sentence: text.slice(sentence.0, sentence.1).trim().to_string(),
+        modifiers,
+    }
+}
+
+/// La aparición de `term` con menos atenuantes, o `None` si no aparece
+pub fn most_assertive(text: &AnalyzedText, term: &str, subject: &str) -> Option<(TermMatch, MatchContext)> {
+    text.find(term)
+        .into_iter()
+        .map(|found| (found, match_context(text, &found, subject)))
+        .min_by_key(|(_, context)| context.severity_steps())
+}
+
+/// Como `most_assertive`, eligiendo entre varias frases equivalentes
+pub fn most_assertive_of<S: AsRef<str>>(
+    text: &AnalyzedText,
+    terms: &[S],
+    subject: &str,
+) -> Option<(TermMatch, MatchContext)> {
+    terms.iter()
+        .filter_map(|term| most_assertive(text, term.as_ref(), subject))
+        .min_by_key(|(_, context)| context.severity_steps())
+}
+
+fn negation_cue(text: &AnalyzedText, found: &TermMatch, sentence: (usize, usize)) -> Option<String> {
+    let tokens = text.tokens();
+    let clause = clause_start(text, found.token_index, sentence.0);
+    let window_start = found.token_index.saturating_sub(NEGATION_WINDOW).max(clause);
+
+    let before = (window_start..found.token_index).rev()
+        .map(|i| (i, &tokens[i]))
+        .find(|(i, t)| {
+            NEGATION_CUES.contains(&t.folded.as_str())
+                || t.folded.ends_with("n't")
+                || t.folded.ends_with("n’t")
+                || (GOVERNING_NEGATION_CUES.contains(&t.folded.as_str()) && i + 1 == found.token_index)
+        });
+    if let Some((_, token)) = before {
+        return Some(token.text.clone());
+    }
+
+    // Japonés: la negación va pegada detrás del verbo
+    let after = text.slice(found.char_end, (found.char_end + 6).min(sentence.1));
+    JP_NEGATION_CUES.iter()
+        .find(|cue| after.contains(*cue))
+        .map(|cue| cue.to_string())
+}
+
+/// Índice del primer token de la cláusula de `token_index`: se corta en
+/// `,` `:` `;`, en una adversativa o al principio de la oración
+fn clause_start(text: &AnalyzedText, token_index: usize, sentence_start: usize) -> usize {
+    let tokens = text.tokens();
+    let chars = text.chars();
+    let mut start = token_index;
+    while start > 0 {
+        let prev = &tokens[start - 1];
+        let gap = &chars[prev.char_end..tokens[start].char_start];
+        if prev.char_start < sentence_start
+            || gap.iter().any(|c| CLAUSE_BREAKS.contains(c))
+            || ADVERSATIVES.contains(&prev.folded.as_str())
+        {
+            break;
+        }
+        start -= 1;
+    }
+    start
+}
+
+/// Hipótesis que precede a la coincidencia en su cláusula, o una prótasis
+/// que abre la oración ("Si huyera, traicionaría..."). Un "si" posterior
+/// ("traicionó su palabra, si es que la tuvo") no la vuelve hipotética.
+fn hypothetical_cue(text: &AnalyzedText, found: &TermMatch, sentence: (usize, usize)) -> Option<String> {
+    let tokens = text.tokens();
+    let clause = clause_start(text, found.token_index, sentence.0);
+    let before = &tokens[clause..found.token_index];
+    let opening = tokens[text.token_range(sentence.0, sentence.1)].first()
+        .filter(|t| t.char_start < found.char_start);
+
+    // "si" sin tilde: se mira el original porque "sí" también pliega a "si"
+    let is_si = |t: &Token| t.text == "si" || t.text == "Si";
+    if let Some(token) = before.iter().find(|t| is_si(t)).or(opening.filter(|t| is_si(t) || t.folded == "if")) {
+        return Some(token.text.clone());
+    }
+
+    for cue in HYPOTHETICAL_CUES {
+        let words: Vec<&str> = cue.split(' ').collect();
+        let hit = before.windows(words.len())
+            .find(|w| w.iter().zip(&words).all(|(t, c)| t.folded == *c));
+        if let Some(window) = hit {
+            return Some(window.iter().map(|t| t.text.as_str()).collect::<Vec<_>>().join(" "));
+        }
+    }
+
+    let matched = &tokens[found.token_index..found.token_index + found.token_count];
+    if let Some(token) = matched.iter().find(|t| is_conditional(&t.folded)) {
+        return Some(token.text.clone());
+    }
+
+    // Japonés: "もし" va delante; "なら" / "たら" cierran la condición antes del verbo
+    let before_text = text.slice(sentence.0, found.char_start);
+    JP_HYPOTHETICAL_CUES.iter()
+        .find(|cue| before_text.contains(*cue))
+        .map(|cue| cue.to_string())
+}
+
+fn is_conditional(folded: &str) -> bool {
+    folded.chars().count() >= MIN_CONDITIONAL_CHARS
+        && folded.chars().all(|c| c.is_ascii_alphabetic())
+        && CONDITIONAL_ENDINGS.iter().any(|e| folded.ends_with(e))
+}
+
+/// Tramos de diálogo en offsets de caracteres: comillas de todo tipo y
+/// párrafos que abren con raya, donde cada raya alterna habla e inciso.
+fn quote_spans(chars: &[char]) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+
+    for (open, close) in QUOTE_PAIRS {
+        let mut start: Option<usize> = None;
+        for (i, c) in chars.iter().enumerate() {
+            match start {
+                None if c == open => start = Some(i),
+                Some(s) if c == close => {
+                    spans.push((s, i + 1));
+                    start = None;
+                },
+                _ => {},
+            }
+        }
+    }
+
+    let mut line_start = 0;
+    while line_start < chars.len() {
+        let line_end = chars[line_start..].iter()
+            .position(|c| *c == '\n')
+            .map(|p| line_start + p)
+            .unwrap_or(chars.len());
+        let first = chars[line_start..line_end].iter()
+            .position(|c| !c.is_whitespace())
+            .map(|p| line_start + p);
+
+        if let Some(first) = first.filter(|f| DIALOGUE_DASHES.contains(&chars[*f])) {
+            let mut speaking = true;
+            let mut span_start = first;
+            for (i, c) in chars.iter().enumerate().take(line_end).skip(first + 1) {
+                if DIALOGUE_DASHES.contains(c) {
+                    if speaking {
+                        spans.push((span_start, i));
+                    } else {
+                        span_start = i;
+                    }
+                    speaking = !speaking;
+                }
+            }
+            if speaking {
+                spans.push((span_start, line_end));
+            }
+        }
+
+        line_start = line_end + 1;
+    }
+
+    spans
+}
+
+/// Busca "dijo Marcos" / "Marcos said" pegado al diálogo, en la misma línea
+fn attribute_speaker(text: &AnalyzedText, span: (usize, usize)) -> Option<String> {
+    let chars = text.chars();
+    let line_start = chars[..span.0].iter().rposition(|c| *c == '\n').map(|p| p + 1).unwrap_or(0);
+    let line_end = chars[span.1..].iter().position(|c| *c == '\n').map(|p| span.1 + p).unwrap_or(chars.len());
+
+    let before_range = text.token_range(line_start, span.0);
+    let after_range = text.token_range(span.1, line_end);
+    let before = &text.tokens()[before_range.start.max(before_range.end.saturating_sub(ATTRIBUTION_WINDOW))..before_range.end];
+    let after = &text.tokens()[after_range.start..after_range.end.min(after_range.start + ATTRIBUTION_WINDOW)];
+
+    [after, before].into_iter().find_map(|window| {
+        let verb = window.iter().position(|t| SPEECH_VERBS.contains(&t.folded.as_str()))?;
+        [verb + 1, verb.wrapping_sub(1)].into_iter()
+            .filter_map(|i| window.get(i))
+            .find(|t| t.text.chars().next().is_some_and(|c| c.is_uppercase()))
+            .map(|t| t.text.clone())
+    })
+}
+
+fn is_subject(speaker: &str, subject: &str) -> bool {
+    let speaker = fold(speaker);
+    subject.split_whitespace().any(|part| fold(part) == speaker)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context_of(text: &str, term: &str) -> MatchContext {
+        let analyzed = AnalyzedText::new(text);
+        let found = analyzed.find(term)[0];
+        match_context(&analyzed, &found, "Aria Vance")
+    }
+
+    #[test]
+    fn test_negation_and_conditional() {
+        let plain = context_of("Aria traicionó su palabra al amanecer.", "traicionó su palabra");
+        assert!(plain.modifiers.is_empty());
+
+        let negated = context_of("Aria nunca traicionaría su palabra.", "traicionó su palabra");
+        assert!(negated.modifiers.contains(&ContextModifier::Negated { cue: "nunca".into() }));
+        assert!(negated.modifiers.iter().any(|m| matches!(m, ContextModifier::Hypothetical { .. })));
+        assert!(!negated.is_assertive());
+
+        // La negación de otra oración no cuenta
+        let other = context_of("No dormía. Mató al guardia.", "mató");
+        assert!(other.modifiers.is_empty());
+    }
+
+    #[test]
+    fn test_dialogue_attribution() {
+        let own = context_of("—Mataste a mi padre —dijo Aria—. Lo sé.", "mataste");
+        assert_eq!(own.modifiers, vec![ContextModifier::QuotedDialogue]);
+
+        let other = context_of("«Ella mató al guardia», susurró Marcos.", "mató");
+        assert!(other.modifiers.contains(&ContextModifier::OtherSpeaker { speaker: "Marcos".into() }));
+
+        // Se queda con la aparición narrada, no con la citada
+        let text = AnalyzedText::new("—Aria robó el mapa —dijo Marcos.\nAria robó el mapa.");
+        let (_, narrated) = most_assertive(&text, "robó", "Aria Vance").unwrap();
+        assert!(narrated.modifiers.is_empty());
+    }
+
+    #[test]
+    fn test_negation_stays_in_its_clause() {
+        for text in [
+            "Sin dudarlo, traicionó su palabra.",
+            "No dudó: traicionó su palabra.",
+            "Nadie lo vio, pero traicionó su palabra.",
+        ] {
+            let context = context_of(text, "traicionó su palabra");
+            assert!(context.is_assertive(), "{}: {:?}", text, context.modifiers);
+        }
+        // Pegadas al verbo sí niegan
+        let governed = context_of("Nadie traicionó su palabra.", "traicionó su palabra");
+        assert!(governed.modifiers.contains(&ContextModifier::Negated { cue: "Nadie".into() }));
+    }
+
+    #[test]
+    fn test_condition_after_the_match_is_not_hypothetical() {
+        let after = context_of("Traicionó su palabra, si es que alguna vez la tuvo.", "traicionó su palabra");
+        assert!(after.modifiers.is_empty());
+
+        let protasis = context_of("Si lo descubren, traicionó su palabra.", "traicionó su palabra");
+        assert!(protasis.modifiers.contains(&ContextModifier::Hypothetical { cue: "Si".into() }));
+    }
+}
@@ -0,0 +1,443 @@
+//! Sesión narrativa incremental para editores en vivo
+//!
+//! Guarda almas y mundos ya deserializados junto al texto partido en párrafos
+//! (bloques separados por líneas en blanco). Cada edición por rango vuelve a
+//! segmentar el texto, que es barato, pero solo re-analiza los párrafos que
+//! cambiaron. Los que quedan detrás de la edición se desplazan sin re-analizar.
+//! Todas las posiciones son offsets en caracteres, como en el resto del motor.
+//!
+//! Cada hallazgo (onda de tensión o violación) recibe un `id` que se mantiene
+//! mientras su párrafo no cambie, así el editor puede mover sus marcas con
+//! `shifted_from`/`shift` y solo tocar las de `added` y `removed`.
+//!
+//! El análisis es local a cada párrafo: una coincidencia que necesite dos
+//! párrafos a la vez no se detecta aquí (usa `check_soul_violations` sobre el
+//! texto completo para eso).
+
+use serde::{Deserialize, Serialize};
+use crate::destiny_director::*;
+use crate::i18n::Language;
+use crate::soul_parser::LivingSoul;
+use crate::tension_engine::*;
+use crate::world_mechanics::check_world_law_breaches;
+use crate::world_seed::WorldSeed;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionFinding {
+    pub id: u64,                // Estable mientras su párrafo no cambie
+    pub soul: String,           // Alma a la que pertenece
+    pub position: usize,        // Offset en caracteres
+    pub finding: Finding,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "data")]
+pub enum Finding {
+    Tension(TensionWave),
+    Violation(CoherenceViolation),
+}
+
+/// Cambios de hallazgos tras una edición
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SessionDiff {
+    pub added: Vec<SessionFinding>,
+    pub removed: Vec<SessionFinding>,     // Con sus posiciones de antes de la edición
+    pub shifted_from: usize,              // Hallazgos en o tras este offset (previo)...
+    pub shift: i64,                       // ...se mueven estos caracteres
+    pub reanalyzed_paragraphs: usize,
+}
+
+/// Análisis cacheado de un párrafo para un alma
+#[derive(Debug, Clone)]
+struct SoulParagraph {
+    triggered: Vec<TriggeredElement>,
+    drift_warning: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct Paragraph {
+    start: usize,               // Offset en caracteres
+    text: String,
+    findings: Vec<SessionFinding>,
+    per_soul: Vec<SoulParagraph>,   // Mismo orden que `souls`
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NarrativeSession {
+    souls: Vec<LivingSoul>,
+    worlds: Vec<WorldSeed>,
+    text: String,
+    paragraphs: Vec<Paragraph>,
+    next_id: u64,
+    language: Language,         // Idioma de los hallazgos
+}
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+impl NarrativeSession {
+    /// Sesión vacía cuyos hallazgos se redactan en `lang`
+    pub fn new(lang: Language) -> Self {
+        Self { language: lang, ..Self::default() }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Cambia el idioma de los hallazgos; re-analiza todo el texto
+    pub fn set_language(&mut self, lang: Language) -> SessionDiff {
+        self.language = lang;
+        self.reanalyze_all()
+    }
+
+    pub fn souls(&self) -> &[LivingSoul] {
+        &self.souls
+    }
+
+    pub fn worlds(&self) -> &[WorldSeed] {
+        &self.worlds
+    }
+
+    /// Añade o reemplaza (por nombre) un alma; re-analiza todo el texto
+    pub fn add_soul(&mut self, soul: LivingSoul) -> SessionDiff {
+        match self.souls.iter().position(|s| s.name == soul.name) {
+            Some(idx) => self.souls[idx] = soul,
+            None => self.souls.push(soul),
+        }
+        self.reanalyze_all()
+    }
+
+    pub fn remove_soul(&mut self, name: &str) -> SessionDiff {
+        self.souls.retain(|s| s.name != name);
+        self.reanalyze_all()
+    }
+
+    /// Añade o reemplaza (por id) un mundo; re-analiza todo el texto
+    pub fn add_world(&mut self, world: WorldSeed) -> SessionDiff {
+        match self.worlds.iter().position(|w| w.id == world.id) {
+            Some(idx) => self.worlds[idx] = world,
+            None => self.worlds.push(world),
+        }
+        self.reanalyze_all()
+    }
+
+    pub fn remove_world(&mut self, id: &str) -> SessionDiff {
+        self.worlds.retain(|w| w.id != id);
+        self.reanalyze_all()
+    }
+
+    /// Reemplaza el texto completo
+    pub fn set_text(&mut self, text: &str) -> SessionDiff {
+        let len = self.text.chars().count();
+        self.edit(0, len, text)
+    }
+
+    /// Como `edit`, pero con el rango en unidades UTF-16 (los índices de un
+    /// string de JavaScript)
+    pub fn edit_utf16(&mut self, start: usize, end: usize, replacement: &str) -> SessionDiff {
+        let start = char_offset(&self.text, start);
+        let end = char_offset(&self.text, end);
+        self.edit(start, end, replacement)
+    }
+
+    /// Sustituye los caracteres `[start, end)` por `replacement`.
+    /// Los rangos fuera del texto se recortan al final.
+    pub fn edit(&mut self, start: usize, end: usize, replacement: &str) -> SessionDiff {
+        let len = self.text.chars().count();
+        let start = start.min(len);
+        let end = end.clamp(start, len);
+
+        let byte_start = byte_offset(&self.text, start);
+        let byte_end = byte_offset(&self.text, end);
+        self.text.replace_range(byte_start..byte_end, replacement);
+        let shift = replacement.chars().count() as i64 - (end - start) as i64;
+
+        let segments = split_paragraphs(&self.text);
+        let old = std::mem::take(&mut self.paragraphs);
+
+        // Párrafos intactos delante de la edición (mismo texto y posición)
+        let prefix = old.iter().zip(&segments)
+            .take_while(|(p, (s_start, s_text))| p.start == *s_start && p.text == *s_text)
+            .count();
+        // Párrafos intactos detrás (mismo texto, posición desplazada)
+        let suffix = old[prefix..].iter().rev().zip(segments[prefix..].iter().rev())
+            .take_while(|(p, (s_start, s_text))| {
+                p.start as i64 + shift == *s_start as i64 && p.text == *s_text
+            })
+            .count();
+
+        let shifted_from = old.get(old.len() - suffix).map(|p| p.start).unwrap_or(len);
+        let mut diff = SessionDiff { shifted_from, shift, ..SessionDiff::default() };
+        let mut old = old.into_iter();
+        let mut paragraphs: Vec<Paragraph> = old.by_ref().take(prefix).collect();
+
+        let changed = old.len() - suffix;
+        for removed in old.by_ref().take(changed) {
+            diff.removed.extend(removed.findings);
+        }
+        for (s_start, s_text) in &segments[prefix..segments.len() - suffix] {
+            let paragraph = self.analyze(*s_start, s_text);
+            diff.added.extend(paragraph.findings.iter().cloned());
+            diff.reanalyzed_paragraphs += 1;
+            paragraphs.push(paragraph);
+        }
+        for mut kept in old {
+            kept.shift(shift);
+            paragraphs.push(kept);
+        }
+
+        self.paragraphs = paragraphs;
+        diff
+    }
+
+    /// Todos los hallazgos vigentes, por posición
+    pub fn findings(&self) -> Vec<SessionFinding> {
+        let mut findings: Vec<SessionFinding> = self.paragraphs.iter()
+            .flat_map(|p| p.findings.iter().cloned())
+            .collect();
+        findings.sort_by_key(|f| (f.position, f.id));
+        findings
+    }
+
+    /// Análisis de tensión de cada alma, montado desde la caché de párrafos
+    pub fn tension(&self) -> Vec<TensionAnalysis> {
+        self.souls.iter().enumerate()
+            .map(|(idx, soul)| {
+                let waves = self.soul_findings(soul)
+                    .filter_map(|f| match &f.finding {
+                        Finding::Tension(wave) => Some(wave.clone()),
+                        Finding::Violation(_) => None,
+                    })
+                    .collect();
+                let triggered = self.paragraphs.iter()
+                    .flat_map(|p| p.per_soul[idx].triggered.iter().cloned())
+                    .collect();
+                summarize_tension(soul, waves, triggered, self.text.chars().count(), self.language)
+            })
+            .collect()
+    }
+
+    /// Informe de coherencia de cada alma, montado desde la caché de párrafos
+    pub fn coherence(&self) -> Vec<CoherenceReport> {
+        self.souls.iter().enumerate()
+            .map(|(idx, soul)| {
+                let violations = self.soul_findings(soul)
+                    .filter_map(|f| match &f.finding {
+                        Finding::Violation(violation) => Some(violation.clone()),
+                        Finding::Tension(_) => None,
+                    })
+                    .collect();
+                let drift = self.paragraphs.iter()
+                    .find_map(|p| p.per_soul[idx].drift_warning.clone());
+                summarize_coherence(soul, violations, drift, self.language)
+            })
+            .collect()
+    }
+
+    // ============================================================
+    // INTERNOS
+    // ============================================================
+
+    fn soul_findings<'a>(&'a self, soul: &'a LivingSoul) -> impl Iterator<Item = &'a SessionFinding> + 'a {
+        self.paragraphs.iter()
+            .flat_map(|p| p.findings.iter())
+            .filter(move |f| f.soul == soul.name)
+    }
+
+    fn reanalyze_all(&mut self) -> SessionDiff {
+        let old = std::mem::take(&mut self.paragraphs);
+        let mut diff = SessionDiff {
+            shifted_from: self.text.chars().count(),
+            ..SessionDiff::default()
+        };
+        diff.removed = old.into_iter().flat_map(|p| p.findings).collect();
+
+        for (start, text) in split_paragraphs(&self.text) {
+            let paragraph = self.analyze(start, &text);
+            diff.added.extend(paragraph.findings.iter().cloned());
+            diff.reanalyzed_paragraphs += 1;
+            self.paragraphs.push(paragraph);
+        }
+        diff
+    }
+
+    fn analyze(&mut self, start: usize, text: &str) -> Paragraph {
+        let mut findings = Vec::new();
+        let mut per_soul = Vec::new();
+
+        for soul in &self.souls {
+            let tension = calculate_soul_tension(soul, text, self.language);
+            let mut report = check_soul_violations(soul, text, self.language);
+            for world in &self.worlds {
+                report.violations.extend(check_world_law_breaches(soul, world, text, self.language));
+            }
+
+            for mut wave in tension.tension_waves {
+                wave.position += start;
+                findings.push((soul.name.clone(), wave.position, Finding::Tension(wave)));
+            }
+            for mut violation in report.violations {
+                let position = start + violation.position_hint.unwrap_or(0);
+                violation.position_hint = Some(position);
+                findings.push((soul.name.clone(), position, Finding::Violation(violation)));
+            }
+            per_soul.push(SoulParagraph {
+                triggered: tension.triggered_elements,
+                drift_warning: report.character_drift_warning,
+            });
+        }
+
+        let findings = findings.into_iter()
+            .map(|(soul, position, finding)| {
+                self.next_id += 1;
+                SessionFinding { id: self.next_id, soul, position, finding }
+            })
+            .collect();
+
+        Paragraph { start, text: text.to_string(), findings, per_soul }
+    }
+}
+
+impl Paragraph {
+    fn shift(&mut self, shift: i64) {
+        let moved = |position: usize| (position as i64 + shift).max(0) as usize;
+        self.start = moved(self.start);
+        for finding in &mut self.findings {
+            finding.position = moved(finding.position);
+            match &mut finding.finding {
+                Finding::Tension(wave) => wave.position = moved(wave.position),
+                Finding::Violation(violation) => {
+                    violation.position_hint = violation.position_hint.map(moved);
+                },
+            }
+        }
+    }
+}
+
+/// Párrafos como `(inicio en caracteres, texto)`. Cada párrafo incluye las
+/// líneas en blanco que lo siguen, así que los párrafos cubren todo el texto.
+fn split_paragraphs(text: &str) -> Vec<(usize, String)> {
+    let mut paragraphs: Vec<(usize, String)> = Vec::new();
+    let mut current = String::new();
+    let mut current_start = 0;
+    let mut offset = 0;
+    let mut has_content = false;
+    let mut in_gap = false;
+
+    for line in text.split_inclusive('\n') {
+        let blank = line.trim().is_empty();
+        if !blank && in_gap {
+            paragraphs.push((current_start, std::mem::take(&mut current)));
+            current_start = offset;
+            in_gap = false;
+        }
+        if blank && has_content {
+            in_gap = true;
+        }
+        has_content |= !blank;
+        current.push_str(line);
+        offset += line.chars().count();
+    }
+    if !current.is_empty() {
+        paragraphs.push((current_start, current));
+    }
+    paragraphs
+}
+
+fn byte_offset(text: &str, chars: usize) -> usize {
+    text.char_indices().nth(chars).map(|(i, _)| i).unwrap_or(text.len())
+}
+
+/// Offset en unidades UTF-16 -> offset en caracteres (a mitad de un par
+/// sustituto cuenta el carácter entero)
+fn char_offset(text: &str, utf16: usize) -> usize {
+    let mut units = 0;
+    for (idx, c) in text.chars().enumerate() {
+        if units >= utf16 {
+            return idx;
+        }
+        units += c.len_utf16();
+    }
+    text.chars().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soul_parser::parse_living_sheet;
+
+    fn session() -> NarrativeSession {
+        let mut session = NarrativeSession::new(Language::Es);
+        session.add_soul(parse_living_sheet(r#"
+            <h1 class="name">Aria</h1>
+            <div data-fear>La oscuridad eterna</div>
+            <ul class="moral-code"><li>Nunca matar</li></ul>
+        "#));
+        session.set_text("Aria caminó por el bosque.\n\nLa oscuridad la rodeaba.\n\nAmaneció tranquila.\n");
+        session
+    }
+
+    #[test]
+    fn edits_only_reanalyze_touched_paragraphs() {
+        let mut session = session();
+        let before = session.findings();
+        assert!(before.iter().any(|f| matches!(f.finding, Finding::Tension(_))));
+
+        // Nueva violación en el último párrafo: el resto no se toca
+        let byte = session.text().find("tranquila").unwrap();
+        let at = session.text()[..byte].chars().count();
+        let diff = session.edit(at, at + "tranquila".chars().count(), "y Aria mató al guardia");
+        assert_eq!(diff.reanalyzed_paragraphs, 1);
+        assert!(diff.removed.is_empty());
+        assert_eq!(diff.added.len(), 1);
+        assert!(matches!(diff.added[0].finding, Finding::Violation(_)));
+
+        // Insertar al principio desplaza los hallazgos sin cambiar sus ids
+        let diff = session.edit(0, 0, "Prólogo.\n\n");
+        assert_eq!(diff.reanalyzed_paragraphs, 1);
+        assert!(diff.added.is_empty() && diff.removed.is_empty());
+        assert_eq!(diff.shift, 10);
+        let after = session.findings();
+        for finding in &before {
+            let moved = after.iter().find(|f| f.id == finding.id).unwrap();
+            assert_eq!(moved.position, finding.position + 10);
+        }
+    }
+
+    #[test]
+    fn session_matches_full_text_analysis() {
+        let mut session = session();
+        session.edit(0, 0, "Aria mató sin piedad.\n\n");
+
+        let soul = &session.souls()[0];
+        let full = check_soul_violations(soul, session.text(), Language::Es);
+        let incremental = &session.coherence()[0];
+        assert_eq!(full.violations.len(), incremental.violations.len());
+        assert_eq!(full.violations[0].position_hint, incremental.violations[0].position_hint);
+        assert_eq!(full.coherence_score, incremental.coherence_score);
+
+        let waves: Vec<usize> = calculate_soul_tension(soul, session.text(), Language::Es)
+            .tension_waves.iter().map(|w| w.position).collect();
+        let cached: Vec<usize> = session.tension()[0]
+            .tension_waves.iter().map(|w| w.position).collect();
+        assert_eq!(waves, cached);
+    }
+
+    #[test]
+    fn utf16_edits_land_on_the_same_characters() {
+        let mut session = session();
+        session.edit(0, 0, "🗡️ ");
+        // Índices de JavaScript: el emoji ocupa dos unidades
+        let js = session.text().encode_utf16().collect::<Vec<_>>();
+        let word: Vec<u16> = "tranquila".encode_utf16().collect();
+        let at = js.windows(word.len()).position(|w| w == word.as_slice()).unwrap();
+        session.edit_utf16(at, at + word.len(), "serena");
+        assert!(session.text().ends_with("Amaneció serena.\n"));
+    }
+}
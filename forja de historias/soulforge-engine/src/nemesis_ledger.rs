@@ -0,0 +1,445 @@
+//! Libro de encuentros con la némesis
+//!
+//! `Nemesis::threat_level` sale fijo de la ficha y `encounters` solo crece a
+//! mano. El `NemesisTracker` lee los capítulos en orden, detecta cuándo el
+//! alma y su némesis se enfrentan en la misma oración, decide el desenlace y
+//! quién llevó la ventaja, y sube o baja la amenaza en consecuencia.
+//!
+//! Un capítulo cuenta como un encuentro: lo decide la última oración con
+//! desenlace (victoria, huida o tregua); si solo hay choque, queda en tablas.
+//! Además avisa cuando la némesis lleva demasiados capítulos sin aparecer
+//! para la tensión del alma (`TensionMarkers`) y su amenaza.
+
+use serde::{Deserialize, Serialize};
+use crate::forge_error::{parse_souls_json, ForgeError};
+use crate::i18n::{terms, tr, Language, TemplateArg};
+use crate::modality::match_context;
+use crate::soul_parser::{EncounterOutcome, LivingSoul, NemesisEncounter, TensionMarkers, UpperHand};
+use crate::story_structure::{split_manuscript, Chapter};
+use crate::text_analysis::{AnalyzedText, Sentence, TermMatch};
+
+/// Un encuentro detectado, con la amenaza antes y después
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NemesisEntry {
+    pub soul: String,
+    pub nemesis: String,
+    pub threat_before: u8,
+    pub encounter: NemesisEncounter,
+    pub description: String,
+}
+
+/// La némesis lleva más capítulos ausente de lo que la tensión admite
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NemesisAbsence {
+    pub soul: String,
+    pub nemesis: String,
+    pub last_seen: Option<u32>,     // `None` si aún no ha aparecido
+    pub chapters_absent: u32,
+    pub limit: u32,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChapterNemesis {
+    pub chapter: u32,
+    pub title: String,
+    pub encounters: Vec<NemesisEntry>,
+    pub absences: Vec<NemesisAbsence>,
+}
+
+/// Estado de una némesis tras los capítulos leídos
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NemesisState {
+    pub soul: String,
+    pub nemesis: String,
+    pub initial_threat: u8,
+    pub threat_level: u8,
+    pub last_seen: Option<u32>,
+    pub ledger: Vec<NemesisEncounter>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NemesisReport {
+    pub chapters: Vec<ChapterNemesis>,
+    pub nemeses: Vec<NemesisState>,
+}
+
+/// Seguidor con memoria: alimentar los capítulos en orden
+#[derive(Debug, Clone)]
+pub struct NemesisTracker {
+    tracked: Vec<Tracked>,
+    chapter: u32,
+    lang: Language,
+}
+
+#[derive(Debug, Clone)]
+struct Tracked {
+    state: NemesisState,
+    markers: TensionMarkers,
+    descriptions: Vec<String>,  // Para `Nemesis::encounters`
+    warned: bool,               // Ya se avisó de esta ausencia
+}
+
+/// Una oración que decide (o solo plantea) el encuentro
+struct Beat {
+    outcome: EncounterOutcome,
+    upper_hand: UpperHand,
+    sentence: Sentence,
+}
+
+/// Ausencia máxima, en capítulos, con tensión y amenaza mínimas
+const MAX_ABSENCE_CHAPTERS: f32 = 6.0;
+
+/// Por mucha tensión que haya, una némesis puede faltar este tiempo
+const MIN_ABSENCE_CHAPTERS: u32 = 2;
+
+/// Cuánto mueve la amenaza una victoria o una tregua
+const DECISIVE_SHIFT: i32 = 2;
+
+/// Cuánto la mueve una huida
+const ESCAPE_SHIFT: i32 = 1;
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Seguimiento desde JSON. `souls_json` es un `LivingSoul` o una lista de
+/// ellos; el manuscrito se separa por encabezados de capítulo.
+pub fn track_nemeses(souls_json: &str, manuscript: &str, lang: Language) -> NemesisReport {
+    try_track_nemeses(souls_json, manuscript, lang)
+        .unwrap_or_else(|_| NemesisReport { chapters: vec![], nemeses: vec![] })
+}
+
+/// Como `track_nemeses`, pero una lista de almas inválida es un error
+pub fn try_track_nemeses(souls_json: &str, manuscript: &str, lang: Language) -> Result<NemesisReport, ForgeError> {
+    let souls = parse_souls_json(souls_json)?;
+    Ok(track_nemesis_chapters(&souls, &split_manuscript(manuscript, lang), lang))
+}
+
+/// Sigue a las némesis de varias almas a lo largo de capítulos ya separados
+pub fn track_nemesis_chapters(souls: &[LivingSoul], chapters: &[Chapter], lang: Language) -> NemesisReport {
+    let mut tracker = NemesisTracker::new(souls, lang);
+    let tracked = chapters.iter().map(|chapter| tracker.track_chapter(chapter)).collect();
+    tracker.report(tracked)
+}
+
+impl NemesisTracker {
+    /// Solo se siguen las almas que tienen némesis
+    pub fn new(souls: &[LivingSoul], lang: Language) -> Self {
+        let tracked = souls.iter()
+            .filter_map(|soul| soul.nemesis.as_ref().map(|nemesis| (soul, nemesis)))
+            .map(|(soul, nemesis)| Tracked {
+                state: NemesisState {
+                    soul: soul.name.clone(),
+                    nemesis: nemesis.name.clone(),
+                    initial_threat: nemesis.threat_level,
+                    threat_level: nemesis.threat_level,
+                    last_seen: None,
+                    ledger: Vec::new(),
+                },
+                markers: soul.tension_markers.clone(),
+                descriptions: Vec::new(),
+                warned: false,
+            })
+            .collect();
+
+        Self { tracked, chapter: 0, lang }
+    }
+
+    /// Lee el siguiente capítulo: encuentros, amenaza y ausencias
+    pub fn track_chapter(&mut self, chapter: &Chapter) -> ChapterNemesis {
+        self.chapter += 1;
+        let text = AnalyzedText::new(&chapter.text);
+        let narrative = self.lang.resolve(&chapter.text);
+        let mut tracked = ChapterNemesis {
+            chapter: self.chapter,
+            title: chapter.title.clone(),
+            encounters: Vec::new(),
+            absences: Vec::new(),
+        };
+
+        for index in 0..self.tracked.len() {
+            let state = &self.tracked[index].state;
+            let soul_at = name_matches(&text, &state.soul);
+            let nemesis_at = name_matches(&text, &state.nemesis);
+
+            if nemesis_at.is_empty() {
+                if let Some(absence) = self.absence(index) {
+                    tracked.absences.push(absence);
+                }
+                continue;
+            }
+
+            self.tracked[index].state.last_seen = Some(self.chapter);
+            self.tracked[index].warned = false;
+            if let Some(beat) = decisive_beat(&text, &soul_at, &nemesis_at, &self.tracked[index].state.soul, narrative) {
+                tracked.encounters.push(self.record(index, &text, beat));
+            }
+        }
+
+        tracked
+    }
+
+    pub fn nemeses(&self) -> impl Iterator<Item = &NemesisState> {
+        self.tracked.iter().map(|t| &t.state)
+    }
+
+    /// Vuelca amenaza y encuentros sobre la némesis del alma
+    pub fn apply_to(&self, soul: &mut LivingSoul) {
+        let Some(nemesis) = soul.nemesis.as_mut() else {
+            return;
+        };
+        let Some(tracked) = self.tracked.iter()
+            .find(|t| t.state.soul == soul.name && t.state.nemesis == nemesis.name)
+        else {
+            return;
+        };
+        nemesis.threat_level = tracked.state.threat_level;
+        nemesis.ledger.extend(tracked.state.ledger.iter().cloned());
+        nemesis.encounters.extend(tracked.descriptions.iter().cloned());
+    }
+
+    /// Cierra el seguimiento con los capítulos ya procesados
+    pub fn report(&self, chapters: Vec<ChapterNemesis>) -> NemesisReport {
+        NemesisReport { chapters, nemeses: self.nemeses().cloned().collect() }
+    }
+
+    // ============================================================
+    // INTERNOS
+    // ============================================================
+
+    fn record(&mut self, index: usize, text: &AnalyzedText, beat: Beat) -> NemesisEntry {
+        let chapter = self.chapter;
+        let lang = self.lang;
+        let tracked = &mut self.tracked[index];
+        let threat_before = tracked.state.threat_level;
+        let threat = (threat_before as i32 + threat_shift(beat.outcome, beat.upper_hand)).clamp(1, 10) as u8;
+        tracked.state.threat_level = threat;
+
+        let encounter = NemesisEncounter {
+            chapter,
+            outcome: beat.outcome,
+            upper_hand: beat.upper_hand,
+            passage: text.slice(beat.sentence.char_start, beat.sentence.char_end).trim().to_string(),
+            threat_level: threat,
+        };
+        let args: [TemplateArg; 4] = [
+            ("name", &tracked.state.soul), ("nemesis", &tracked.state.nemesis),
+            ("chapter", &chapter), ("threat", &threat),
+        ];
+        let description = tr(lang, encounter_key(beat.outcome, beat.upper_hand), &args);
+
+        tracked.state.ledger.push(encounter.clone());
+        tracked.descriptions.push(description.clone());
+        NemesisEntry {
+            soul: tracked.state.soul.clone(),
+            nemesis: tracked.state.nemesis.clone(),
+            threat_before,
+            encounter,
+            description,
+        }
+    }
+
+    /// Aviso la primera vez que la ausencia supera el límite
+    fn absence(&mut self, index: usize) -> Option<NemesisAbsence> {
+        let chapter = self.chapter;
+        let tracked = &mut self.tracked[index];
+        let state = &tracked.state;
+        let chapters_absent = chapter - state.last_seen.unwrap_or(0);
+        let limit = absence_limit(&tracked.markers, state.threat_level);
+        if tracked.warned || chapters_absent <= limit {
+            return None;
+        }
+        tracked.warned = true;
+
+        let tension = format!("{:.0}", tracked.markers.overall_tension.clamp(0.0, 1.0) * 100.0);
+        let args: [TemplateArg; 6] = [
+            ("name", &state.soul), ("nemesis", &state.nemesis), ("chapters", &chapters_absent),
+            ("tension", &tension), ("threat", &state.threat_level), ("limit", &limit),
+        ];
+        Some(NemesisAbsence {
+            soul: state.soul.clone(),
+            nemesis: state.nemesis.clone(),
+            last_seen: state.last_seen,
+            chapters_absent,
+            limit,
+            description: tr(self.lang, "nemesis.absence", &args),
+        })
+    }
+}
+
+/// Cuántos capítulos puede faltar la némesis: menos cuanto más tensa está el
+/// alma y más peligrosa es la némesis, y uno menos si hay venganza pendiente
+fn absence_limit(markers: &TensionMarkers, threat: u8) -> u32 {
+    let pressure = markers.overall_tension.clamp(0.0, 1.0) * 0.5 + threat.min(10) as f32 / 20.0;
+    let mut limit = (MAX_ABSENCE_CHAPTERS * (1.0 - pressure)).round() as u32;
+    if markers.pending_revenge > 0 {
+        limit = limit.saturating_sub(1);
+    }
+    limit.max(MIN_ABSENCE_CHAPTERS)
+}
+
+fn threat_shift(outcome: EncounterOutcome, upper_hand: UpperHand) -> i32 {
+    match (outcome, upper_hand) {
+        (EncounterOutcome::Victory, UpperHand::Soul) => -DECISIVE_SHIFT,
+        (EncounterOutcome::Victory, UpperHand::Nemesis) => DECISIVE_SHIFT,
+        (EncounterOutcome::Escape, UpperHand::Soul) => -ESCAPE_SHIFT,      // Huye la némesis
+        (EncounterOutcome::Escape, UpperHand::Nemesis) => ESCAPE_SHIFT,    // Huye el alma
+        (EncounterOutcome::Truce, _) => -DECISIVE_SHIFT,
+        _ => 0,
+    }
+}
+
+fn encounter_key(outcome: EncounterOutcome, upper_hand: UpperHand) -> &'static str {
+    match (outcome, upper_hand) {
+        (EncounterOutcome::Victory, UpperHand::Soul) => "nemesis.encounter.soul_prevails",
+        (EncounterOutcome::Victory, UpperHand::Nemesis) => "nemesis.encounter.nemesis_prevails",
+        (EncounterOutcome::Escape, UpperHand::Soul) => "nemesis.encounter.nemesis_escapes",
+        (EncounterOutcome::Escape, UpperHand::Nemesis) => "nemesis.encounter.soul_escapes",
+        (EncounterOutcome::Truce, _) => "nemesis.encounter.truce",
+        _ => "nemesis.encounter.standoff",
+    }
+}
+
+/// Apariciones del nombre completo o, si no hay, del nombre de pila
+fn name_matches(text: &AnalyzedText, name: &str) -> Vec<TermMatch> {
+    let found = text.find(name);
+    if !found.is_empty() {
+        return found;
+    }
+    name.split_whitespace().next()
+        .filter(|first| *first != name.trim())
+        .map(|first| text.find(first))
+        .unwrap_or_default()
+}
+
+/// La última oración con desenlace del capítulo o, si no hay, el primer choque
+fn decisive_beat(
+    text: &AnalyzedText,
+    soul_at: &[TermMatch],
+    nemesis_at: &[TermMatch],
+    soul: &str,
+    narrative: Language,
+) -> Option<Beat> {
+    let within = |m: &TermMatch, s: &Sentence| s.char_start <= m.char_start && m.char_end <= s.char_end;
+    let mut clash: Option<Beat> = None;
+    let mut decisive: Option<Beat> = None;
+
+    for sentence in text.sentences() {
+        let souls: Vec<&TermMatch> = soul_at.iter().filter(|m| within(m, sentence)).collect();
+        let nemeses: Vec<&TermMatch> = nemesis_at.iter().filter(|m| within(m, sentence)).collect();
+        if souls.is_empty() || nemeses.is_empty() {
+            continue;
+        }
+
+        // El verbo afirmado más tardío de la oración decide
+        let verb = [
+            (EncounterOutcome::Truce, "nemesis.truce"),
+            (EncounterOutcome::Escape, "nemesis.escape"),
+            (EncounterOutcome::Victory, "nemesis.victory"),
+        ].into_iter()
+            .flat_map(|(outcome, key)| terms(narrative, key).iter().map(move |term| (outcome, term)))
+            .flat_map(|(outcome, term)| text.find(term).into_iter().map(move |m| (outcome, m)))
+            .filter(|(_, m)| within(m, sentence) && match_context(text, m, soul).is_assertive())
+            .max_by_key(|(_, m)| m.char_start);
+
+        if let Some((outcome, found)) = verb {
+            let upper_hand = match (outcome, actor(&found, &souls, &nemeses, narrative)) {
+                (EncounterOutcome::Truce, _) => UpperHand::Neither,
+                // Quien huye cede la ventaja al otro
+                (EncounterOutcome::Escape, UpperHand::Soul) => UpperHand::Nemesis,
+                (EncounterOutcome::Escape, UpperHand::Nemesis) => UpperHand::Soul,
+                (_, side) => side,
+            };
+            decisive = Some(Beat { outcome, upper_hand, sentence: *sentence });
+            continue;
+        }
+
+        let clashed = terms(narrative, "nemesis.clash").iter()
+            .flat_map(|term| text.find(term))
+            .any(|m| within(&m, sentence) && match_context(text, &m, soul).is_assertive());
+        if clashed && clash.is_none() {
+            clash = Some(Beat { outcome: EncounterOutcome::Standoff, upper_hand: UpperHand::Neither, sentence: *sentence });
+        }
+    }
+
+    decisive.or(clash)
+}
+
+/// Quién realiza la acción: el nombre más cercano antes del verbo (SVO) o,
+/// en japonés (SOV), el primero de la oración
+fn actor(verb: &TermMatch, souls: &[&TermMatch], nemeses: &[&TermMatch], narrative: Language) -> UpperHand {
+    let names = souls.iter().map(|m| (m.char_start, UpperHand::Soul))
+        .chain(nemeses.iter().map(|m| (m.char_start, UpperHand::Nemesis)));
+    let subject = if narrative == Language::Jp {
+        names.min_by_key(|(start, _)| *start)
+    } else {
+        let (before, after): (Vec<_>, Vec<_>) = names.partition(|(start, _)| *start < verb.char_start);
+        before.into_iter().max_by_key(|(start, _)| *start)
+            .or_else(|| after.into_iter().min_by_key(|(start, _)| *start))
+    };
+    subject.map(|(_, side)| side).unwrap_or(UpperHand::Neither)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::markdown_sheet::parse_markdown_sheet;
+
+    fn chapter(title: &str, text: &str) -> Chapter {
+        Chapter { title: title.to_string(), text: text.to_string() }
+    }
+
+    fn aria(threat: u8) -> LivingSoul {
+        parse_markdown_sheet(&format!("---\nname: Aria Vance\n---\n## Némesis\nKael {{relationship: hermano, threat: {}}}\n", threat))
+    }
+
+    #[test]
+    fn encounters_are_recorded_and_move_the_threat() {
+        let soul = aria(5);
+        let mut tracker = NemesisTracker::new(std::slice::from_ref(&soul), Language::Es);
+        let chapters: Vec<ChapterNemesis> = [
+            chapter("I", "Aria enfrentó a Kael en el puente. La lluvia no cesaba."),
+            chapter("II", "Kael desarmó a Aria y se rió. Aria huyó del castillo de Kael."),
+            chapter("III", "Aria pensó en Kael toda la noche."),
+            chapter("IV", "Al amanecer Aria derrotó a Kael, que nunca escapó."),
+        ].iter().map(|c| tracker.track_chapter(c)).collect();
+        let report = tracker.report(chapters);
+
+        let ledger = &report.nemeses[0].ledger;
+        let summary: Vec<(u32, EncounterOutcome, UpperHand, u8)> = ledger.iter()
+            .map(|e| (e.chapter, e.outcome, e.upper_hand, e.threat_level))
+            .collect();
+        assert_eq!(summary, vec![
+            (1, EncounterOutcome::Standoff, UpperHand::Neither, 5),
+            (2, EncounterOutcome::Escape, UpperHand::Nemesis, 6),
+            (4, EncounterOutcome::Victory, UpperHand::Soul, 4),
+        ]);
+        assert_eq!(ledger[1].passage, "Aria huyó del castillo de Kael.");
+        assert!(report.chapters[2].encounters.is_empty());
+        assert_eq!(report.nemeses[0].last_seen, Some(4));
+
+        let mut evolved = soul;
+        tracker.apply_to(&mut evolved);
+        let nemesis = evolved.nemesis.unwrap();
+        assert_eq!(nemesis.threat_level, 4);
+        assert_eq!(nemesis.ledger.len(), 3);
+        assert!(nemesis.encounters.last().unwrap().contains("se impone a Kael"));
+    }
+
+    #[test]
+    fn long_absence_is_flagged_once_for_the_current_tension() {
+        let mut tense = aria(8);
+        tense.tension_markers.overall_tension = 0.8;
+        let quiet = (1..=5).map(|n| chapter(&n.to_string(), "Aria cabalgó hacia el norte.")).collect::<Vec<_>>();
+
+        let report = track_nemesis_chapters(&[tense, aria(2)], &quiet, Language::Es);
+        let absences: Vec<(u32, &str)> = report.chapters.iter()
+            .flat_map(|c| c.absences.iter().map(move |a| (c.chapter, a.description.as_str())))
+            .collect();
+        // Con tensión 0.8 y amenaza 8 bastan dos capítulos; con amenaza 2 y sin tensión, cinco
+        assert_eq!(absences.len(), 1);
+        assert_eq!(absences[0].0, 3);
+        assert!(absences[0].1.contains("Kael lleva 3 capítulos"));
+    }
+}
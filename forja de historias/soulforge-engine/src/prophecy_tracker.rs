@@ -0,0 +1,412 @@
+//! Avance de las profecías a partir de la narrativa
+//!
+//! `Prophecy::progress` y `fulfilled` salen fijos de la ficha. El
+//! `ProphecyTracker` lee los capítulos en orden y hace avanzar cada profecía
+//! cuando una oración repite sus condiciones (las palabras clave de su texto)
+//! de forma afirmada. Esa oración queda como evidencia. Al llegar al umbral la
+//! profecía se cumple; desde entonces, tratarla como pendiente o incumplida
+//! levanta `ProphecyConflict`.
+//!
+//! El tipo pesa el avance: la condena se precipita, la gloria avanza a ritmo
+//! normal, la elección solo cuenta cuando el propio personaje está en la
+//! oración (y retrocede si la rechaza) y la ambigua se resiste.
+
+use serde::{Deserialize, Serialize};
+use crate::destiny_director::{CoherenceViolation, ViolationRationale, ViolationSeverity, ViolationType};
+use crate::forge_error::{parse_souls_json, ForgeError};
+use crate::i18n::{terms, tr, Language, TemplateArg};
+use crate::modality::{match_context, ContextModifier, MatchContext};
+use crate::soul_parser::{LivingSoul, ProphecyType};
+use crate::story_structure::{split_manuscript, Chapter};
+use crate::text_analysis::{keywords, AnalyzedText, Sentence, TermMatch};
+
+/// Una oración que repite las condiciones de una profecía
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProphecyEvidence {
+    pub chapter: u32,
+    pub passage: String,
+    pub position: usize,        // Offset en caracteres dentro del capítulo
+    pub echoed: Vec<String>,    // Condiciones repetidas
+    pub gain: f32,              // Negativo si la profecía retrocede
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProphecyState {
+    pub soul: String,
+    pub prophecy_id: String,
+    pub text: String,
+    pub prophecy_type: ProphecyType,
+    pub conditions: Vec<String>,
+    pub progress: f32,
+    pub fulfilled: bool,
+    pub fulfilled_in: Option<u32>,  // Capítulo; `None` si ya venía cumplida o no se cumplió
+    pub evidence: Vec<ProphecyEvidence>,
+}
+
+/// Cambio de una profecía en un capítulo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProphecyAdvance {
+    pub soul: String,
+    pub prophecy_id: String,
+    pub progress_before: f32,
+    pub progress: f32,
+    pub fulfilled: bool,        // Se cumplió en este capítulo
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChapterProphecies {
+    pub chapter: u32,
+    pub title: String,
+    pub advances: Vec<ProphecyAdvance>,
+    pub violations: Vec<CoherenceViolation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProphecyReport {
+    pub chapters: Vec<ChapterProphecies>,
+    pub prophecies: Vec<ProphecyState>,
+}
+
+/// Seguidor con memoria: alimentar los capítulos en orden
+#[derive(Debug, Clone)]
+pub struct ProphecyTracker {
+    prophecies: Vec<ProphecyState>,
+    chapter: u32,
+    lang: Language,
+}
+
+/// Avance de una oración que repite todas las condiciones (antes del peso del tipo)
+const ECHO_GAIN: f32 = 0.4;
+
+/// Progreso a partir del cual la profecía se da por cumplida
+const FULFILLMENT_THRESHOLD: f32 = 1.0;
+
+/// Condiciones más cortas que esto son demasiado comunes para contar
+const MIN_CONDITION_CHARS: usize = 5;
+
+/// Una elección sin el personaje en escena apenas avanza
+const ABSENT_CHOOSER_FACTOR: f32 = 0.25;
+
+/// Lo que se cuenta en un diálogo es rumor: avanza a medias
+const DIALOGUE_FACTOR: f32 = 0.5;
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Seguimiento desde JSON. `souls_json` es un `LivingSoul` o una lista de
+/// ellos; el manuscrito se separa por encabezados de capítulo.
+pub fn track_prophecies(souls_json: &str, manuscript: &str, lang: Language) -> ProphecyReport {
+    try_track_prophecies(souls_json, manuscript, lang)
+        .unwrap_or_else(|_| ProphecyReport { chapters: vec![], prophecies: vec![] })
+}
+
+/// Como `track_prophecies`, pero una lista de almas inválida es un error
+pub fn try_track_prophecies(souls_json: &str, manuscript: &str, lang: Language) -> Result<ProphecyReport, ForgeError> {
+    let souls = parse_souls_json(souls_json)?;
+    Ok(track_chapters(&souls, &split_manuscript(manuscript, lang), lang))
+}
+
+/// Sigue las profecías de varias almas a lo largo de capítulos ya separados
+pub fn track_chapters(souls: &[LivingSoul], chapters: &[Chapter], lang: Language) -> ProphecyReport {
+    let mut tracker = ProphecyTracker::new(souls, lang);
+    let tracked = chapters.iter().map(|chapter| tracker.track_chapter(chapter)).collect();
+    tracker.report(tracked)
+}
+
+impl ProphecyTracker {
+    /// Parte del progreso y el estado que traen las fichas
+    pub fn new(souls: &[LivingSoul], lang: Language) -> Self {
+        let prophecies = souls.iter()
+            .flat_map(|soul| soul.prophecies.iter().map(move |p| (soul, p)))
+            .map(|(soul, prophecy)| ProphecyState {
+                soul: soul.name.clone(),
+                prophecy_id: prophecy.id.clone(),
+                text: prophecy.text.clone(),
+                prophecy_type: prophecy.prophecy_type.clone(),
+                conditions: conditions(&prophecy.text),
+                progress: prophecy.progress.clamp(0.0, 1.0),
+                fulfilled: prophecy.fulfilled,
+                fulfilled_in: None,
+                evidence: Vec::new(),
+            })
+            .collect();
+
+        Self { prophecies, chapter: 0, lang }
+    }
+
+    /// Lee el siguiente capítulo y actualiza cada profecía
+    pub fn track_chapter(&mut self, chapter: &Chapter) -> ChapterProphecies {
+        self.chapter += 1;
+        let text = AnalyzedText::new(&chapter.text);
+        let narrative = self.lang.resolve(&chapter.text);
+        let mut tracked = ChapterProphecies {
+            chapter: self.chapter,
+            title: chapter.title.clone(),
+            advances: Vec::new(),
+            violations: Vec::new(),
+        };
+
+        for index in 0..self.prophecies.len() {
+            let before = self.prophecies[index].progress;
+            let was_fulfilled = self.prophecies[index].fulfilled;
+
+            for sentence in text.sentences() {
+                self.read_sentence(index, &text, sentence, narrative, &mut tracked);
+            }
+
+            let state = &self.prophecies[index];
+            if state.progress != before || state.fulfilled != was_fulfilled {
+                tracked.advances.push(self.advance(state, before, !was_fulfilled && state.fulfilled));
+            }
+        }
+
+        tracked.violations.sort_by_key(|v| v.position_hint);
+        tracked
+    }
+
+    pub fn prophecies(&self) -> &[ProphecyState] {
+        &self.prophecies
+    }
+
+    /// Vuelca el progreso y el cumplimiento seguidos sobre las profecías del alma
+    pub fn apply_to(&self, soul: &mut LivingSoul) {
+        for state in self.prophecies.iter().filter(|s| s.soul == soul.name) {
+            if let Some(prophecy) = soul.prophecies.iter_mut().find(|p| p.id == state.prophecy_id) {
+                prophecy.progress = state.progress;
+                prophecy.fulfilled = state.fulfilled;
+            }
+        }
+    }
+
+    /// Cierra el seguimiento con los capítulos ya procesados
+    pub fn report(&self, chapters: Vec<ChapterProphecies>) -> ProphecyReport {
+        ProphecyReport { chapters, prophecies: self.prophecies.clone() }
+    }
+
+    // ============================================================
+    // INTERNOS
+    // ============================================================
+
+    fn read_sentence(
+        &mut self,
+        index: usize,
+        text: &AnalyzedText,
+        sentence: &Sentence,
+        narrative: Language,
+        tracked: &mut ChapterProphecies,
+    ) {
+        let chapter = self.chapter;
+        let lang = self.lang;
+        let state = &mut self.prophecies[index];
+
+        let echoes: Vec<(String, TermMatch, MatchContext)> = state.conditions.iter()
+            .filter_map(|condition| {
+                let found = text.find(condition).into_iter().find(|m| within(m, sentence))?;
+                Some((condition.clone(), found, match_context(text, &found, &state.soul)))
+            })
+            .collect();
+        let Some((first_condition, _, _)) = echoes.first() else {
+            return;
+        };
+
+        // Hablar de la profecía como algo por venir no es que se cumpla
+        let pending = terms(narrative, "prophecy.pending").iter()
+            .chain(terms(narrative, "prophecy.unfulfilled"))
+            .find_map(|phrase| text.find(phrase).into_iter().find(|m| within(m, sentence)));
+
+        if let Some(found) = pending {
+            if state.fulfilled {
+                let context = match_context(text, &found, &state.soul);
+                tracked.violations.push(contradiction(state, text, &found, context, first_condition, lang));
+            }
+            return;
+        }
+        if state.fulfilled {
+            return;
+        }
+
+        let asserted: Vec<&(String, TermMatch, MatchContext)> = echoes.iter().filter(|(_, _, c)| c.is_assertive()).collect();
+        let share = asserted.len() as f32 / state.conditions.len() as f32;
+        let in_dialogue = asserted.iter().any(|(_, _, c)| c.modifiers.contains(&ContextModifier::QuotedDialogue));
+        let chooser_present = mentions(text, sentence, &state.soul);
+
+        let mut gain = ECHO_GAIN * share * type_weight(&state.prophecy_type);
+        if in_dialogue {
+            gain *= DIALOGUE_FACTOR;
+        }
+        if matches!(state.prophecy_type, ProphecyType::Choice) {
+            if !chooser_present {
+                gain *= ABSENT_CHOOSER_FACTOR;
+            }
+            // El personaje rechaza su destino: la elección retrocede
+            if asserted.is_empty() && chooser_present {
+                gain = -ECHO_GAIN * echoes.len() as f32 / state.conditions.len() as f32 / 2.0;
+            }
+        }
+        if gain == 0.0 {
+            return;
+        }
+
+        let echoed: Vec<String> = if gain > 0.0 {
+            asserted.iter().map(|(condition, _, _)| condition.clone()).collect()
+        } else {
+            echoes.iter().map(|(condition, _, _)| condition.clone()).collect()
+        };
+        state.progress = (state.progress + gain).clamp(0.0, 1.0);
+        state.evidence.push(ProphecyEvidence {
+            chapter,
+            passage: text.slice(sentence.char_start, sentence.char_end).trim().to_string(),
+            position: sentence.char_start,
+            echoed,
+            gain,
+        });
+        if state.progress >= FULFILLMENT_THRESHOLD {
+            state.fulfilled = true;
+            state.fulfilled_in = Some(chapter);
+        }
+    }
+
+    fn advance(&self, state: &ProphecyState, before: f32, fulfilled: bool) -> ProphecyAdvance {
+        let progress = format!("{:.0}", state.progress * 100.0);
+        let args: [TemplateArg; 2] = [("prophecy", &state.text), ("progress", &progress)];
+        let key = if fulfilled {
+            "evolution.prophecy.advance_fulfill"
+        } else if state.progress >= before {
+            "evolution.prophecy.advance"
+        } else {
+            "evolution.prophecy.recede"
+        };
+        ProphecyAdvance {
+            soul: state.soul.clone(),
+            prophecy_id: state.prophecy_id.clone(),
+            progress_before: before,
+            progress: state.progress,
+            fulfilled,
+            description: tr(self.lang, key, &args),
+        }
+    }
+}
+
+/// Peso del avance según el tipo de profecía
+fn type_weight(prophecy_type: &ProphecyType) -> f32 {
+    match prophecy_type {
+        ProphecyType::Doom => 1.25,     // La condena se precipita
+        ProphecyType::Glory => 1.0,
+        ProphecyType::Choice => 1.0,    // Sujeta a que el personaje esté presente
+        ProphecyType::Ambiguous => 0.6, // Se resiste a una sola lectura
+    }
+}
+
+/// Palabras clave de la profecía que la narrativa debe repetir
+fn conditions(text: &str) -> Vec<String> {
+    let all = keywords(text);
+    let long: Vec<String> = all.iter()
+        .filter(|w| w.chars().count() >= MIN_CONDITION_CHARS)
+        .cloned()
+        .collect();
+    if long.is_empty() { all } else { long }
+}
+
+fn within(found: &TermMatch, sentence: &Sentence) -> bool {
+    sentence.char_start <= found.char_start && found.char_end <= sentence.char_end
+}
+
+/// ¿Aparece el personaje (nombre completo o de pila) en la oración?
+fn mentions(text: &AnalyzedText, sentence: &Sentence, name: &str) -> bool {
+    let first = name.split_whitespace().next().unwrap_or(name);
+    !name.trim().is_empty() && [name, first].iter()
+        .any(|alias| text.find(alias).iter().any(|m| within(m, sentence)))
+}
+
+fn contradiction(
+    state: &ProphecyState,
+    text: &AnalyzedText,
+    found: &TermMatch,
+    context: MatchContext,
+    condition: &str,
+    lang: Language,
+) -> CoherenceViolation {
+    let chapter = state.fulfilled_in.unwrap_or_default();
+    let args: [TemplateArg; 3] = [("prophecy", &state.text), ("keyword", &condition), ("chapter", &chapter)];
+    let description = match state.fulfilled_in {
+        Some(_) => tr(lang, "violation.prophecy.fulfilled_in", &args),
+        None => tr(lang, "violation.prophecy.description", &args),
+    };
+    let violation = CoherenceViolation {
+        severity: ViolationSeverity::Warning,
+        violation_type: ViolationType::ProphecyConflict,
+        description,
+        conflicting_element: state.prophecy_id.clone(),
+        source_reference: format!("prophecies.{}", state.prophecy_id),
+        suggestion: tr(lang, "violation.prophecy.suggestion", &args),
+        position_hint: Some(found.char_start),
+        rationale: ViolationRationale::default(),
+    };
+    violation.in_context(text, found, context, tr(lang, "violation.prophecy.reason", &args), lang)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::markdown_sheet::parse_markdown_sheet;
+
+    fn chapter(title: &str, text: &str) -> Chapter {
+        Chapter { title: title.to_string(), text: text.to_string() }
+    }
+
+    fn soul(prophecy: &str) -> LivingSoul {
+        parse_markdown_sheet(&format!("---\nname: Aria\n---\n## Profecías\n- {}\n", prophecy))
+    }
+
+    #[test]
+    fn echoes_advance_the_prophecy_until_fulfilled_and_later_contradictions_are_flagged() {
+        let aria = soul("La corona caerá en el fuego {type: glory, progress: 0.4}");
+        let mut tracker = ProphecyTracker::new(std::slice::from_ref(&aria), Language::Es);
+        let chapters: Vec<ChapterProphecies> = [
+            chapter("I", "Aria soñó con la corona entre las llamas. Nadie habló."),
+            chapter("II", "La corona rodó hacia el fuego del salón."),
+            chapter("III", "La corona cayó al fuego y se fundió. Aria no tocaría la corona."),
+            chapter("IV", "Algún día la corona caerá en el fuego, susurró el bardo."),
+        ].iter().map(|c| tracker.track_chapter(c)).collect();
+        let report = tracker.report(chapters);
+
+        let state = &report.prophecies[0];
+        assert_eq!(state.conditions, vec!["corona", "caera", "fuego"]);
+        assert!(state.fulfilled);
+        assert_eq!(state.fulfilled_in, Some(3));
+        assert_eq!(state.evidence.len(), 3);
+        assert_eq!(state.evidence[1].passage, "La corona rodó hacia el fuego del salón.");
+        assert_eq!(state.evidence[1].echoed, vec!["corona", "fuego"]);
+        assert!(report.chapters[2].advances[0].fulfilled);
+
+        let violations = &report.chapters[3].violations;
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(violations[0].violation_type, ViolationType::ProphecyConflict));
+        assert!(violations[0].description.contains("capítulo 3"));
+
+        let mut evolved = aria;
+        tracker.apply_to(&mut evolved);
+        assert!(evolved.prophecies[0].fulfilled);
+        assert_eq!(evolved.prophecies[0].progress, 1.0);
+    }
+
+    #[test]
+    fn prophecy_type_weights_progress() {
+        let text = [chapter("I", "La espada se quebró en la batalla.")];
+        let progress = |kind: &str| {
+            let report = track_chapters(&[soul(&format!("La espada se quebrará {{type: {}}}", kind))], &text, Language::Es);
+            report.prophecies[0].progress
+        };
+        assert!(progress("doom") > progress("glory"));
+        assert!(progress("glory") > progress("ambiguous"));
+        // La elección necesita al personaje en la escena
+        assert!(progress("choice") < progress("ambiguous"));
+
+        let refusal = [chapter("I", "Aria nunca quebraría la espada.")];
+        let report = track_chapters(&[soul("La espada se quebrará {type: choice, progress: 0.5}")], &refusal, Language::Es);
+        assert!(report.prophecies[0].progress < 0.5);
+        assert!(report.chapters[0].advances[0].description.contains("retrocede"));
+    }
+}
@@ -0,0 +1,394 @@
+//! Perfiles de selectores: fichas de otras herramientas
+//!
+//! Un perfil dice, campo a campo, qué selectores CSS buscar y cómo extraer
+//! el valor (atributo o texto). La matriz de SoulForge es el perfil
+//! `default`; los demás se cargan desde JSON y pueden heredar de él con
+//! `"extends": "default"`. Con varios perfiles a mano, `detect_profile` elige
+//! el que más campos llena en la ficha.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use scraper::Selector;
+use crate::soul_parser::{diagnose_living_sheet_with, parse_living_sheet_with, FieldOrigin, LivingSoul};
+
+/// Nombre del perfil incorporado
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Campos de primer nivel que un perfil puede definir (claves de `fields`)
+pub const PROFILE_FIELDS: &[&str] = &[
+    "name",
+    "titles",
+    "archetype",
+    "psyche.core_fear",
+    "psyche.deepest_desire",
+    "psyche.fatal_flaw",
+    "psyche.moral_code",
+    "psyche.breaking_point",
+    "psyche.shadow_self",
+    "scars",
+    "prophecies",
+    "nemesis",
+    "nemesis.encounters",
+    "nemesis.ledger",
+    "bonds",
+    "chronicle",
+    "tension_markers",
+    "raw_attributes",
+];
+
+/// Subcampos de elementos repetidos (claves de `item_fields`)
+pub const PROFILE_ITEM_FIELDS: &[&str] = &[
+    "scars.id",
+    "scars.name",
+    "scars.origin_event",
+    "scars.trauma_type",
+    "scars.healed",
+    "scars.narrative_weight",
+    "prophecies.id",
+    "prophecies.text",
+    "prophecies.prophecy_type",
+    "prophecies.fulfilled",
+    "prophecies.progress",
+    "nemesis.name",
+    "nemesis.relationship",
+    "nemesis.conflict_core",
+    "nemesis.threat_level",
+    "bonds.entity_name",
+    "bonds.bond_type",
+    "bonds.strength",
+    "bonds.status",
+];
+
+/// Perfil de selectores para un formato de ficha
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelectorProfile {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub extends: Option<String>,                         // "default": hereda lo no definido
+    #[serde(default)]
+    pub fields: BTreeMap<String, Vec<FieldRule>>,        // Campo -> selectores en orden de prioridad
+    #[serde(default)]
+    pub item_fields: BTreeMap<String, Vec<Extraction>>,  // "scars.name" -> cómo leerlo del elemento
+}
+
+/// Un selector candidato para un campo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldRule {
+    pub selector: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extract: Vec<Extraction>,    // Vacío = regla habitual del campo
+}
+
+/// Cómo sacar el valor de un elemento
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "from", rename_all = "lowercase")]
+pub enum Extraction {
+    Text,                    // Texto interno (recortado, no vacío)
+    Attr { name: String },   // Valor de un atributo
+}
+
+/// Error de un perfil, con la ruta del campo ("fields.name[1].selector")
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileError {
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ProfileError {}
+
+/// Cuántos campos llena un perfil en una ficha concreta
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileScore {
+    pub profile: String,
+    pub filled: usize,
+    pub total: usize,
+}
+
+/// Alma parseada con el perfil elegido automáticamente
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfiledSoul {
+    pub soul: LivingSoul,
+    pub profile: String,
+    pub scores: Vec<ProfileScore>,   // De mejor a peor
+}
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Carga uno o varios perfiles (objeto o lista) y los valida
+pub fn load_selector_profiles(json: &str) -> Result<Vec<SelectorProfile>, Vec<ProfileError>> {
+    let (parsed, is_list) = match serde_json::from_str::<Vec<SelectorProfile>>(json) {
+        Ok(list) => (list, true),
+        Err(_) => serde_json::from_str::<SelectorProfile>(json)
+            .map(|profile| (vec![profile], false))
+            .map_err(|e| vec![ProfileError { path: String::new(), message: format!("JSON de perfil inválido: {}", e) }])?,
+    };
+
+    let mut profiles = Vec::with_capacity(parsed.len());
+    let mut errors = Vec::new();
+    for (idx, profile) in parsed.into_iter().enumerate() {
+        let profile = profile.resolved();
+        let found = profile.validate();
+        if found.is_empty() {
+            profiles.push(profile);
+        } else {
+            errors.extend(found.into_iter().map(|e| ProfileError {
+                path: if is_list { format!("[{}].{}", idx, e.path) } else { e.path },
+                message: e.message,
+            }));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(profiles)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Puntúa cada perfil por los campos de primer nivel que llena, de mejor a peor.
+/// En caso de empate gana el que aparece antes.
+pub fn score_profiles(html: &str, profiles: &[SelectorProfile]) -> Vec<ProfileScore> {
+    let mut scores: Vec<ProfileScore> = profiles.iter()
+        .map(|profile| {
+            let report = diagnose_living_sheet_with(html, profile);
+            let filled = report.fields.iter()
+                .filter(|f| matches!(f.origin, FieldOrigin::Selector) && PROFILE_FIELDS.contains(&f.field.as_str()))
+                .count();
+            ProfileScore { profile: profile.name.clone(), filled, total: PROFILE_FIELDS.len() }
+        })
+        .collect();
+    scores.sort_by_key(|s| std::cmp::Reverse(s.filled));
+    scores
+}
+
+/// El perfil que más campos llena
+pub fn detect_profile<'a>(html: &str, profiles: &'a [SelectorProfile]) -> Option<&'a SelectorProfile> {
+    let best = score_profiles(html, profiles).into_iter().next()?;
+    profiles.iter().find(|p| p.name == best.profile)
+}
+
+/// Parsea con el mejor perfil entre el incorporado y `profiles`
+pub fn parse_living_sheet_auto(html: &str, profiles: &[SelectorProfile]) -> ProfiledSoul {
+    let mut candidates = vec![SelectorProfile::default_profile()];
+    candidates.extend(profiles.iter().filter(|p| p.name != DEFAULT_PROFILE).cloned());
+
+    let scores = score_profiles(html, &candidates);
+    let chosen = candidates.iter()
+        .find(|p| Some(&p.name) == scores.first().map(|s| &s.profile))
+        .unwrap_or(&candidates[0]);
+
+    ProfiledSoul {
+        soul: parse_living_sheet_with(html, chosen),
+        profile: chosen.name.clone(),
+        scores,
+    }
+}
+
+impl SelectorProfile {
+    /// La matriz de selectores de SoulForge
+    pub fn default_profile() -> Self {
+        let value = || vec![attr("data-value"), Extraction::Text];
+        let text = || vec![Extraction::Text];
+
+        let fields = [
+            ("name", rules(&["[data-soul-name]", ".character-name", ".soul-name", "#nombre-personaje", "h1.name"], value())),
+            ("titles", rules(&["[data-titles] li", ".character-titles span", ".soul-titles > *"], text())),
+            ("archetype", rules(&["[data-archetype]", ".character-class", ".soul-archetype"], value())),
+            ("psyche.core_fear", rules(&["[data-fear]", "[data-core-fear]", ".character-fear", ".miedo-principal"], value())),
+            ("psyche.deepest_desire", rules(&["[data-desire]", "[data-deepest-desire]", ".character-desire"], value())),
+            ("psyche.fatal_flaw", rules(&["[data-flaw]", "[data-fatal-flaw]", ".character-flaw"], value())),
+            ("psyche.moral_code", rules(&["[data-moral-code] li", ".moral-code li", ".codigo-moral li"], text())),
+            ("psyche.breaking_point", rules(&["[data-breaking-point]", ".breaking-point", ".punto-quiebre"], text())),
+            ("psyche.shadow_self", rules(&["[data-shadow-self]", ".shadow-self", ".yo-sombra"], text())),
+            ("scars", rules(&["[data-scar]", ".soul-scar", ".cicatriz"], vec![])),
+            ("prophecies", rules(&["[data-prophecy]", ".soul-prophecy", ".profecia"], vec![])),
+            ("nemesis", rules(&["[data-nemesis]", ".soul-nemesis", ".nemesis-section"], vec![])),
+            ("nemesis.encounters", rules(&["[data-encounter]"], text())),
+            ("nemesis.ledger", rules(&["[data-nemesis-encounter]"], vec![])),
+            ("bonds", rules(&["[data-bond]", ".soul-bond", ".vinculo"], vec![])),
+            ("chronicle", rules(&["[data-chronicle-entry]"], vec![])),
+            ("tension_markers", rules(&["[data-tension-markers]"], vec![])),
+            ("raw_attributes", rules(&["[data-soul-attr]"], vec![])),
+        ];
+
+        let item_fields = [
+            ("scars.id", vec![attr("data-scar-id")]),
+            ("scars.name", vec![attr("data-name"), attr("data-scar-name")]),
+            ("scars.origin_event", vec![attr("data-origin")]),
+            ("scars.trauma_type", vec![attr("data-trauma-type")]),
+            ("scars.healed", vec![attr("data-healed")]),
+            ("scars.narrative_weight", vec![attr("data-weight")]),
+            ("prophecies.id", vec![attr("data-prophecy-id")]),
+            ("prophecies.text", text()),
+            ("prophecies.prophecy_type", vec![attr("data-prophecy-type")]),
+            ("prophecies.fulfilled", vec![attr("data-fulfilled")]),
+            ("prophecies.progress", vec![attr("data-progress")]),
+            ("nemesis.name", vec![attr("data-name"), Extraction::Text]),
+            ("nemesis.relationship", vec![attr("data-relationship")]),
+            ("nemesis.conflict_core", vec![attr("data-conflict")]),
+            ("nemesis.threat_level", vec![attr("data-threat")]),
+            ("bonds.entity_name", vec![attr("data-entity"), attr("data-name")]),
+            ("bonds.bond_type", vec![attr("data-bond-type")]),
+            ("bonds.strength", vec![attr("data-strength")]),
+            ("bonds.status", vec![attr("data-status")]),
+        ];
+
+        Self {
+            name: DEFAULT_PROFILE.to_string(),
+            description: "Fichas vivas de SoulForge (atributos data-* y clases propias)".to_string(),
+            extends: None,
+            fields: fields.into_iter().map(|(k, v)| (k.to_string(), v)).collect(),
+            item_fields: item_fields.into_iter().map(|(k, v)| (k.to_string(), v)).collect(),
+        }
+    }
+
+    /// Selectores de un campo de primer nivel
+    pub fn rules(&self, field: &str) -> &[FieldRule] {
+        self.fields.get(field).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Extracciones de un subcampo de elemento repetido
+    pub fn item_rules(&self, field: &str) -> &[Extraction] {
+        self.item_fields.get(field).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Rellena con el perfil incorporado lo que un perfil `extends: "default"` no define
+    pub fn resolved(mut self) -> Self {
+        if self.extends.as_deref() == Some(DEFAULT_PROFILE) {
+            let base = Self::default_profile();
+            for (field, rules) in base.fields {
+                self.fields.entry(field).or_insert(rules);
+            }
+            for (field, rules) in base.item_fields {
+                self.item_fields.entry(field).or_insert(rules);
+            }
+        }
+        self
+    }
+
+    /// Campos desconocidos, CSS inválido, atributos vacíos y herencias imposibles
+    pub fn validate(&self) -> Vec<ProfileError> {
+        let mut errors = Vec::new();
+        let error = |path: String, message: String| ProfileError { path, message };
+
+        if self.name.trim().is_empty() {
+            errors.push(error("name".to_string(), "el perfil necesita un nombre".to_string()));
+        }
+        if let Some(ref base) = self.extends {
+            if base != DEFAULT_PROFILE {
+                errors.push(error("extends".to_string(), format!("solo se puede heredar de '{}' (recibido '{}')", DEFAULT_PROFILE, base)));
+            }
+        }
+
+        for (field, rules) in &self.fields {
+            if !PROFILE_FIELDS.contains(&field.as_str()) {
+                errors.push(error(format!("fields.{}", field), format!("campo desconocido (válidos: {})", PROFILE_FIELDS.join(", "))));
+                continue;
+            }
+            for (idx, rule) in rules.iter().enumerate() {
+                if let Err(e) = Selector::parse(&rule.selector) {
+                    errors.push(error(format!("fields.{}[{}].selector", field, idx), format!("selector CSS inválido '{}': {:?}", rule.selector, e)));
+                }
+                errors.extend(empty_attrs(&rule.extract, &format!("fields.{}[{}].extract", field, idx)));
+            }
+        }
+
+        for (field, extractions) in &self.item_fields {
+            if !PROFILE_ITEM_FIELDS.contains(&field.as_str()) {
+                errors.push(error(format!("item_fields.{}", field), format!("subcampo desconocido (válidos: {})", PROFILE_ITEM_FIELDS.join(", "))));
+                continue;
+            }
+            errors.extend(empty_attrs(extractions, &format!("item_fields.{}", field)));
+        }
+
+        errors
+    }
+}
+
+fn empty_attrs(extractions: &[Extraction], path: &str) -> Vec<ProfileError> {
+    extractions.iter().enumerate()
+        .filter(|(_, e)| matches!(e, Extraction::Attr { name } if name.trim().is_empty()))
+        .map(|(idx, _)| ProfileError { path: format!("{}[{}].name", path, idx), message: "atributo vacío".to_string() })
+        .collect()
+}
+
+fn attr(name: &str) -> Extraction {
+    Extraction::Attr { name: name.to_string() }
+}
+
+fn rules(selectors: &[&str], extract: Vec<Extraction>) -> Vec<FieldRule> {
+    selectors.iter()
+        .map(|css| FieldRule { selector: css.to_string(), extract: extract.clone() })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FOUNDRY_PROFILE: &str = r#"{
+        "name": "foundry",
+        "extends": "default",
+        "fields": {
+            "name": [{ "selector": ".actor-name input", "extract": [{ "from": "attr", "name": "value" }] }],
+            "psyche.core_fear": [{ "selector": "[data-trait='fear']" }],
+            "scars": [{ "selector": ".item.wound" }]
+        },
+        "item_fields": {
+            "scars.name": [{ "from": "attr", "name": "data-item-name" }, { "from": "text" }],
+            "scars.narrative_weight": [{ "from": "attr", "name": "data-severity" }]
+        }
+    }"#;
+
+    #[test]
+    fn foreign_sheet_is_detected_and_parsed() {
+        let profiles = load_selector_profiles(FOUNDRY_PROFILE).unwrap();
+        let html = r#"
+            <div class="actor-name"><input value="Aria Vance"></div>
+            <p data-trait="fear">Perder a su hermana</p>
+            <ol>
+              <li class="item wound" data-item-name="Mano quemada" data-severity="0.8"></li>
+              <li class="item wound">Cicatriz del exilio</li>
+            </ol>
+        "#;
+
+        let result = parse_living_sheet_auto(html, &profiles);
+        assert_eq!(result.profile, "foundry");
+        assert_eq!(result.soul.name, "Aria Vance");
+        assert_eq!(result.soul.psyche.core_fear, "Perder a su hermana");
+        assert_eq!(result.soul.scars.len(), 2);
+        assert_eq!(result.soul.scars[0].name, "Mano quemada");
+        assert!((result.soul.scars[0].narrative_weight - 0.8).abs() < 1e-6);
+        assert_eq!(result.soul.scars[1].name, "Cicatriz del exilio");
+
+        // Una ficha propia sigue eligiendo el perfil incorporado
+        let own = parse_living_sheet_auto(r#"<h1 class="name">Lyra</h1><div data-fear>El olvido</div>"#, &profiles);
+        assert_eq!(own.profile, DEFAULT_PROFILE);
+    }
+
+    #[test]
+    fn invalid_profiles_report_paths() {
+        let errors = load_selector_profiles(r#"{
+            "name": "roto",
+            "fields": { "name": [{ "selector": "h1[" }], "edad": [] },
+            "item_fields": { "scars.name": [{ "from": "attr", "name": "" }] }
+        }"#).unwrap_err();
+        let paths: Vec<&str> = errors.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["fields.edad", "fields.name[0].selector", "item_fields.scars.name[0].name"]);
+    }
+}
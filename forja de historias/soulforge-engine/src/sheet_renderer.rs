@@ -0,0 +1,351 @@
+//! Renderizado de fichas vivas: `LivingSoul` -> HTML canónico
+//!
+//! El HTML usa solo los atributos `data-*` que `parse_living_sheet` lee
+//! primero, de modo que renderizar y volver a parsear devuelve la misma alma.
+//! Única salvedad: el parser siempre copia `core_fear`, `deepest_desire` y
+//! `fatal_flaw` en `raw_attributes`, así que esas tres claves aparecen
+//! aunque el alma original no las tuviera. Los textos se guardan recortados.
+
+use std::fmt::Write;
+use crate::forge_error::{parse_json, ForgeError};
+use crate::soul_parser::*;
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Ficha desde el JSON de un `LivingSoul`
+pub fn render_soul_sheet(soul_json: &str) -> Option<String> {
+    try_render_soul_sheet(soul_json).ok()
+}
+
+/// Como `render_soul_sheet`, con el motivo si el alma no es válida
+pub fn try_render_soul_sheet(soul_json: &str) -> Result<String, ForgeError> {
+    let soul: LivingSoul = parse_json("soul", soul_json)?;
+    Ok(render_living_sheet(&soul))
+}
+
+/// Ficha viva canónica de un alma
+pub fn render_living_sheet(soul: &LivingSoul) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\">");
+    let _ = write!(html, "<title>{}</title></head>\n<body>\n", escape(&soul.name));
+    html.push_str("<article class=\"living-sheet\">\n");
+
+    // === IDENTIDAD ===
+    html.push_str("  <header>\n");
+    let _ = writeln!(html, "    <h1 data-soul-name data-value=\"{0}\">{0}</h1>", escape(&soul.name));
+    let _ = writeln!(html, "    <p data-archetype data-value=\"{0}\">{0}</p>", escape(&soul.archetype));
+    if !soul.titles.is_empty() {
+        html.push_str("    <ul data-titles>\n");
+        for title in &soul.titles {
+            let _ = writeln!(html, "      <li>{}</li>", escape(title));
+        }
+        html.push_str("    </ul>\n");
+    }
+    html.push_str("  </header>\n");
+
+    // === PSIQUE ===
+    let psyche = &soul.psyche;
+    html.push_str("  <section data-psyche>\n");
+    let _ = writeln!(html, "    <p data-fear data-value=\"{0}\">{0}</p>", escape(&psyche.core_fear));
+    let _ = writeln!(html, "    <p data-desire data-value=\"{0}\">{0}</p>", escape(&psyche.deepest_desire));
+    let _ = writeln!(html, "    <p data-flaw data-value=\"{0}\">{0}</p>", escape(&psyche.fatal_flaw));
+    if !psyche.moral_code.is_empty() {
+        html.push_str("    <ul data-moral-code>\n");
+        for code in &psyche.moral_code {
+            let _ = writeln!(html, "      <li>{}</li>", escape(code));
+        }
+        html.push_str("    </ul>\n");
+    }
+    if !psyche.breaking_point.is_empty() {
+        let _ = writeln!(html, "    <p data-breaking-point>{}</p>", escape(&psyche.breaking_point));
+    }
+    if !psyche.shadow_self.is_empty() {
+        let _ = writeln!(html, "    <p data-shadow-self>{}</p>", escape(&psyche.shadow_self));
+    }
+    html.push_str("  </section>\n");
+
+    // === CICATRICES ===
+    if !soul.scars.is_empty() {
+        html.push_str("  <ul class=\"scars\">\n");
+        for scar in &soul.scars {
+            let _ = writeln!(
+                html,
+                "    <li data-scar data-scar-id=\"{}\" data-name=\"{}\" data-origin=\"{}\" \
+                 data-trauma-type=\"{}\" data-healed=\"{}\" data-weight=\"{}\">{}</li>",
+                escape(&scar.id),
+                escape(&scar.name),
+                escape(&scar.origin_event),
+                escape(&trauma_type_value(&scar.trauma_type)),
+                scar.healed,
+                scar.narrative_weight,
+                escape(&scar.name),
+            );
+        }
+        html.push_str("  </ul>\n");
+    }
+
+    // === PROFECÍAS ===
+    if !soul.prophecies.is_empty() {
+        html.push_str("  <ul class=\"prophecies\">\n");
+        for prophecy in &soul.prophecies {
+            let _ = writeln!(
+                html,
+                "    <li data-prophecy data-prophecy-id=\"{}\" data-prophecy-type=\"{}\" \
+                 data-fulfilled=\"{}\" data-progress=\"{}\">{}</li>",
+                escape(&prophecy.id),
+                prophecy_type_value(&prophecy.prophecy_type),
+                prophecy.fulfilled,
+                prophecy.progress,
+                escape(&prophecy.text),
+            );
+        }
+        html.push_str("  </ul>\n");
+    }
+
+    // === NÉMESIS ===
+    if let Some(ref nemesis) = soul.nemesis {
+        let _ = writeln!(
+            html,
+            "  <section data-nemesis data-name=\"{}\" data-relationship=\"{}\" data-conflict=\"{}\" data-threat=\"{}\">",
+            escape(&nemesis.name),
+            escape(&nemesis.relationship),
+            escape(&nemesis.conflict_core),
+            nemesis.threat_level,
+        );
+        if !nemesis.encounters.is_empty() {
+            html.push_str("    <ul>\n");
+            for encounter in &nemesis.encounters {
+                let _ = writeln!(html, "      <li data-encounter>{}</li>", escape(encounter));
+            }
+            html.push_str("    </ul>\n");
+        }
+        if !nemesis.ledger.is_empty() {
+            html.push_str("    <ol data-nemesis-ledger>\n");
+            for encounter in &nemesis.ledger {
+                let _ = writeln!(
+                    html,
+                    "      <li data-nemesis-encounter data-chapter=\"{}\" data-outcome=\"{}\" \
+                     data-upper-hand=\"{}\" data-threat=\"{}\">{}</li>",
+                    encounter.chapter,
+                    encounter_outcome_value(encounter.outcome),
+                    upper_hand_value(encounter.upper_hand),
+                    encounter.threat_level,
+                    escape(&encounter.passage),
+                );
+            }
+            html.push_str("    </ol>\n");
+        }
+        html.push_str("  </section>\n");
+    }
+
+    // === VÍNCULOS ===
+    if !soul.bonds.is_empty() {
+        html.push_str("  <ul class=\"bonds\">\n");
+        for bond in &soul.bonds {
+            let _ = writeln!(
+                html,
+                "    <li data-bond data-entity=\"{}\" data-bond-type=\"{}\" data-strength=\"{}\" data-status=\"{}\">{}</li>",
+                escape(&bond.entity_name),
+                escape(&bond_type_value(&bond.bond_type)),
+                bond.strength,
+                bond_status_value(&bond.status),
+                escape(&bond.entity_name),
+            );
+        }
+        html.push_str("  </ul>\n");
+    }
+
+    // === CRÓNICA ===
+    if !soul.chronicle.is_empty() {
+        html.push_str("  <ol class=\"chronicle\">\n");
+        for entry in &soul.chronicle {
+            let _ = writeln!(
+                html,
+                "    <li data-chronicle-entry data-chapter=\"{}\" data-tension-delta=\"{}\">",
+                entry.chapter, entry.tension_delta,
+            );
+            let _ = writeln!(html, "      <p data-summary>{}</p>", escape(&entry.event_summary));
+            for name in &entry.souls_involved {
+                let _ = writeln!(html, "      <span data-soul-involved>{}</span>", escape(name));
+            }
+            for consequence in &entry.consequences {
+                let _ = writeln!(html, "      <p data-consequence>{}</p>", escape(consequence));
+            }
+            html.push_str("    </li>\n");
+        }
+        html.push_str("  </ol>\n");
+    }
+
+    // === MARCADORES DE TENSIÓN ===
+    let markers = &soul.tension_markers;
+    let _ = writeln!(
+        html,
+        "  <div data-tension-markers data-unresolved-conflicts=\"{}\" data-broken-promises=\"{}\" \
+         data-pending-revenge=\"{}\" data-prophecy-pressure=\"{}\" data-relationship-strain=\"{}\" \
+         data-overall-tension=\"{}\" hidden></div>",
+        markers.unresolved_conflicts,
+        markers.broken_promises,
+        markers.pending_revenge,
+        markers.prophecy_pressure,
+        markers.relationship_strain,
+        markers.overall_tension,
+    );
+
+    // === ATRIBUTOS RAW ===
+    let mut raw: Vec<(&String, &String)> = soul.raw_attributes.iter().collect();
+    raw.sort();
+    for (key, value) in raw {
+        let _ = writeln!(
+            html,
+            "  <span data-soul-attr=\"{}\" data-value=\"{}\" hidden></span>",
+            escape(key),
+            escape(value),
+        );
+    }
+
+    html.push_str("</article>\n</body>\n</html>\n");
+    html
+}
+
+// ============================================================
+// VALORES CANÓNICOS
+// ============================================================
+
+fn trauma_type_value(trauma: &TraumaType) -> String {
+    match trauma {
+        TraumaType::Betrayal => "betrayal".to_string(),
+        TraumaType::Loss => "loss".to_string(),
+        TraumaType::Failure => "failure".to_string(),
+        TraumaType::Violence => "violence".to_string(),
+        TraumaType::Abandonment => "abandonment".to_string(),
+        TraumaType::Corruption => "corruption".to_string(),
+        TraumaType::Custom(s) => s.clone(),
+    }
+}
+
+fn prophecy_type_value(prophecy_type: &ProphecyType) -> &'static str {
+    match prophecy_type {
+        ProphecyType::Doom => "doom",
+        ProphecyType::Glory => "glory",
+        ProphecyType::Ambiguous => "ambiguous",
+        ProphecyType::Choice => "choice",
+    }
+}
+
+fn bond_type_value(bond_type: &BondType) -> String {
+    match bond_type {
+        BondType::Love => "love".to_string(),
+        BondType::Friendship => "friendship".to_string(),
+        BondType::Rivalry => "rivalry".to_string(),
+        BondType::Mentorship => "mentorship".to_string(),
+        BondType::Blood => "blood".to_string(),
+        BondType::Oath => "oath".to_string(),
+        BondType::Debt => "debt".to_string(),
+        BondType::Hatred => "hatred".to_string(),
+        BondType::Complex(s) => s.clone(),
+    }
+}
+
+fn encounter_outcome_value(outcome: EncounterOutcome) -> &'static str {
+    match outcome {
+        EncounterOutcome::Victory => "victory",
+        EncounterOutcome::Escape => "escape",
+        EncounterOutcome::Truce => "truce",
+        EncounterOutcome::Standoff => "standoff",
+    }
+}
+
+fn upper_hand_value(upper_hand: UpperHand) -> &'static str {
+    match upper_hand {
+        UpperHand::Soul => "soul",
+        UpperHand::Nemesis => "nemesis",
+        UpperHand::Neither => "neither",
+    }
+}
+
+fn bond_status_value(status: &BondStatus) -> &'static str {
+    match status {
+        BondStatus::Active => "active",
+        BondStatus::Strained => "strained",
+        BondStatus::Broken => "broken",
+        BondStatus::Dormant => "dormant",
+        BondStatus::Evolving => "evolving",
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.trim().chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conflict_forge::create_soul_inflection_points;
+    use crate::i18n::Language;
+    use crate::soul_evolution::apply_outcome;
+
+    #[test]
+    fn rendered_sheet_parses_back_to_the_same_soul() {
+        let soul = parse_living_sheet(r#"
+            <h1 class="name">Aria "la Roja" Vance</h1>
+            <div data-archetype>Guerrera & exiliada</div>
+            <ul data-titles><li>Espada del Alba</li></ul>
+            <div data-fear>Miedo a <em>repetir</em> la traición</div>
+            <ul class="moral-code"><li>Honor ante todo</li></ul>
+            <div class="breaking-point">Ver morir a Lyra</div>
+            <div data-scar data-name="Marca de Kael" data-trauma-type="betrayal" data-weight="0.9" data-origin="Kael la vendió"></div>
+            <div data-prophecy data-prophecy-type="doom" data-progress="0.25">La espada caerá</div>
+            <div data-nemesis data-name="Kael" data-threat="8" data-conflict="La traición"></div>
+            <div data-bond data-entity="Lyra" data-bond-type="juramento extraño" data-strength="0.8" data-status="strained"></div>
+            <span data-soul-attr="origen" data-value="Norte"></span>
+        "#);
+
+        // Un alma evolucionada: crónica, ids nuevos y marcadores propios
+        let points = create_soul_inflection_points(&soul, Language::Es);
+        let point = &points[0];
+        let mut evolved = apply_outcome(&soul, point, &point.potential_outcomes[0], 4, Language::Es).soul;
+        evolved.nemesis.as_mut().unwrap().ledger.push(NemesisEncounter {
+            chapter: 3,
+            outcome: EncounterOutcome::Escape,
+            upper_hand: UpperHand::Nemesis,
+            passage: "Aria huyó de Kael por los tejados.".to_string(),
+            threat_level: 9,
+        });
+
+        for original in [soul, evolved] {
+            let reparsed = parse_living_sheet(&render_living_sheet(&original));
+            assert_eq!(
+                serde_json::to_value(&reparsed).unwrap(),
+                serde_json::to_value(&original).unwrap(),
+            );
+        }
+    }
+
+    #[test]
+    fn diagnostics_name_selectors_and_defaults() {
+        let report = diagnose_living_sheet(r#"
+            <h1 class="name">Aria Vance</h1>
+            <div data-scar data-name="Marca"></div>
+        "#);
+
+        let name = report.fields.iter().find(|f| f.field == "name").unwrap();
+        assert_eq!(name.selector.as_deref(), Some("h1.name"));
+        assert!(report.defaulted.contains(&"psyche.core_fear".to_string()));
+        assert!(report.defaulted.contains(&"scars[0].narrative_weight".to_string()));
+        assert!(!report.defaulted.contains(&"scars[0].name".to_string()));
+        assert!(report.defaulted.contains(&"nemesis".to_string()));
+    }
+}
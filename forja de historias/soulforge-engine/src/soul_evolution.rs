@@ -0,0 +1,508 @@
+//! Evolución del alma: aplicar el desenlace elegido de un punto de inflexión
+//!
+//! `conflict_forge` propone desenlaces; aquí se aplican sobre el `LivingSoul`
+//! para que el personaje avance capítulo a capítulo: cicatrices que sanan o
+//! se abren, vínculos que se rompen o se refuerzan, profecías que se cumplen,
+//! una nueva entrada en la crónica y los marcadores de tensión recalculados.
+
+use serde::{Deserialize, Serialize};
+use crate::conflict_forge::{ChangeType, ConflictType, InflectionPoint, Outcome};
+use crate::forge_error::{parse_json, ForgeError, ForgeErrorKind};
+use crate::i18n::{terms_in_any, tr, Language};
+use crate::soul_parser::*;
+
+/// Resultado de aplicar un desenlace
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoulEvolution {
+    pub soul: LivingSoul,
+    pub changes: Vec<String>,           // Qué cambió, legible para el autor
+    pub chronicle_entry: ChronicleEntry,
+    pub tension_before: f32,
+    pub tension_after: f32,
+}
+
+/// Ajustes de tensión que no se derivan de cicatrices, profecías ni vínculos
+#[derive(Debug, Clone, Copy, Default)]
+struct MarkerShift {
+    unresolved_conflicts: i32,
+    broken_promises: i32,
+    pending_revenge: i32,
+    prophecy_pressure: f32,
+}
+
+/// Cuánto avanza una profecía cuando el destino la empuja sin cumplirla
+const PROPHECY_ADVANCE: f32 = 0.25;
+/// Cuánto cambia la fuerza de un vínculo al reforzarse
+const BOND_STRENGTH_STEP: f32 = 0.2;
+/// Cuánto pesa más una cicatriz que se profundiza
+const SCAR_DEEPEN_STEP: f32 = 0.15;
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Aplica desde JSON el desenlace `outcome_index` del punto de inflexión.
+/// `None` si algún JSON es inválido o el índice no existe.
+pub fn apply_inflection_outcome(
+    soul_json: &str,
+    point_json: &str,
+    outcome_index: usize,
+    chapter: u32,
+    lang: Language,
+) -> Option<SoulEvolution> {
+    try_apply_inflection_outcome(soul_json, point_json, outcome_index, chapter, lang).ok()
+}
+
+/// Como `apply_inflection_outcome`, con el motivo si algo no es válido
+pub fn try_apply_inflection_outcome(
+    soul_json: &str,
+    point_json: &str,
+    outcome_index: usize,
+    chapter: u32,
+    lang: Language,
+) -> Result<SoulEvolution, ForgeError> {
+    let soul: LivingSoul = parse_json("soul", soul_json)?;
+    let point: InflectionPoint = parse_json("point", point_json)?;
+    let outcome = point.potential_outcomes.get(outcome_index).ok_or_else(|| ForgeError::new(
+        ForgeErrorKind::Invalid,
+        "point",
+        format!("potential_outcomes[{}]", outcome_index),
+        format!("el punto solo tiene {} desenlaces", point.potential_outcomes.len()),
+    ))?;
+    Ok(apply_outcome(&soul, &point, outcome, chapter, lang))
+}
+
+/// Aplica un desenlace sobre un alma ya deserializada y devuelve el alma nueva.
+/// Los cambios de la crónica se redactan en `lang`.
+pub fn apply_outcome(
+    soul: &LivingSoul,
+    point: &InflectionPoint,
+    outcome: &Outcome,
+    chapter: u32,
+    lang: Language,
+) -> SoulEvolution {
+    let mut evolved = soul.clone();
+    let mut shift = MarkerShift::default();
+    let mut involved = vec![soul.name.clone()];
+
+    let impact = &outcome.soul_impact;
+    let element = impact.affected_element.as_str();
+    let change = &impact.change_type;
+    let new_value = impact.new_value.as_deref();
+
+    let changes = match element.split_once('.') {
+        Some(("scars", id)) => apply_to_scar(&mut evolved, id, change, lang),
+        Some(("prophecies", id)) => apply_to_prophecy(&mut evolved, id, change, lang),
+        Some(("bonds", name)) => {
+            involved.push(name.to_string());
+            apply_to_bond(&mut evolved, name, change, &mut shift, lang)
+        },
+        Some(("psyche", field)) => apply_to_psyche(&mut evolved, field, change, new_value, &mut shift, lang),
+        Some(("tension_markers", field)) => apply_to_markers(Some(field), change, &mut shift, lang),
+        _ => match element {
+            "scars" => add_scar(&mut evolved, point, outcome, &mut shift, lang),
+            "nemesis" => {
+                if let Some(nemesis) = &soul.nemesis {
+                    involved.push(nemesis.name.clone());
+                }
+                apply_to_nemesis(&mut evolved, outcome, &mut shift, lang)
+            },
+            "tension_markers" => apply_to_markers(None, change, &mut shift, lang),
+            _ => vec![tr(lang, "evolution.unknown_element", &[("element", &element)])],
+        },
+    };
+
+    evolved.tension_markers = recalculate_tension_markers(soul, &evolved, shift);
+
+    let tension_before = soul.tension_markers.overall_tension;
+    let tension_after = evolved.tension_markers.overall_tension;
+
+    let chronicle_entry = ChronicleEntry {
+        chapter,
+        event_summary: format!("{}: {}", point.title, outcome.description),
+        souls_involved: involved,
+        consequences: changes.clone(),
+        tension_delta: tension_after - tension_before,
+    };
+    evolved.chronicle.push(chronicle_entry.clone());
+
+    SoulEvolution {
+        soul: evolved,
+        changes,
+        chronicle_entry,
+        tension_before,
+        tension_after,
+    }
+}
+
+/// Marcadores de tensión recalculados tras un cambio. Lo que `before` tenía
+/// por encima de lo derivable (promesas rotas, venganzas, conflictos añadidos
+/// a mano) se conserva y se le suma `shift`.
+fn recalculate_tension_markers(before: &LivingSoul, after: &LivingSoul, shift: MarkerShift) -> TensionMarkers {
+    let derived_before = calculate_initial_tension(&before.scars, &before.prophecies, &before.bonds);
+    let derived_after = calculate_initial_tension(&after.scars, &after.prophecies, &after.bonds);
+    let previous = &before.tension_markers;
+
+    let carry = |current: u32, derived: u32, delta: i32| {
+        (current.saturating_sub(derived) as i32 + delta).max(0) as u32
+    };
+
+    let unresolved_conflicts = derived_after.unresolved_conflicts + carry(
+        previous.unresolved_conflicts,
+        derived_before.unresolved_conflicts,
+        shift.unresolved_conflicts,
+    );
+    let broken_promises = carry(previous.broken_promises, 0, shift.broken_promises);
+    let pending_revenge = carry(previous.pending_revenge, 0, shift.pending_revenge);
+    let prophecy_pressure = derived_after.prophecy_pressure
+        + ((previous.prophecy_pressure - derived_before.prophecy_pressure).max(0.0) + shift.prophecy_pressure).max(0.0);
+    let relationship_strain = derived_after.relationship_strain;
+
+    let overall_tension = (unresolved_conflicts as f32 * 0.1
+        + prophecy_pressure
+        + relationship_strain
+        + broken_promises as f32 * 0.05
+        + pending_revenge as f32 * 0.1)
+        .min(1.0);
+
+    TensionMarkers {
+        unresolved_conflicts,
+        broken_promises,
+        pending_revenge,
+        prophecy_pressure,
+        relationship_strain,
+        overall_tension,
+    }
+}
+
+// ============================================================
+// CAMBIOS POR ELEMENTO
+// ============================================================
+
+fn apply_to_scar(soul: &mut LivingSoul, id: &str, change: &ChangeType, lang: Language) -> Vec<String> {
+    let Some(scar) = soul.scars.iter_mut().find(|s| s.id == id) else {
+        return vec![tr(lang, "evolution.scar.missing", &[("id", &id)])];
+    };
+
+    match change {
+        ChangeType::Heal => {
+            scar.healed = true;
+            scar.narrative_weight *= 0.5;
+            vec![tr(lang, "evolution.scar.heal", &[("scar", &scar.name)])]
+        },
+        ChangeType::Transform | ChangeType::Fulfill => {
+            // El dolor convertido en propósito deja de supurar, pero sigue pesando
+            scar.healed = true;
+            scar.narrative_weight *= 0.8;
+            vec![tr(lang, "evolution.scar.transform", &[("scar", &scar.name)])]
+        },
+        ChangeType::Deepen | ChangeType::Break => {
+            scar.healed = false;
+            scar.narrative_weight = (scar.narrative_weight + SCAR_DEEPEN_STEP).min(1.0);
+            vec![tr(lang, "evolution.scar.deepen", &[
+                ("scar", &scar.name), ("weight", &format!("{:.2}", scar.narrative_weight)),
+            ])]
+        },
+        ChangeType::Strengthen => {
+            scar.narrative_weight = (scar.narrative_weight + SCAR_DEEPEN_STEP).min(1.0);
+            vec![tr(lang, "evolution.scar.strengthen", &[("scar", &scar.name)])]
+        },
+    }
+}
+
+/// Una cicatriz nueva nacida del propio punto de inflexión
+fn add_scar(
+    soul: &mut LivingSoul,
+    point: &InflectionPoint,
+    outcome: &Outcome,
+    shift: &mut MarkerShift,
+    lang: Language,
+) -> Vec<String> {
+    let name = outcome.soul_impact.new_value.as_deref()
+        .map(scar_name)
+        .unwrap_or_else(|| outcome.description.clone());
+
+    let mut n = soul.scars.len() + 1;
+    while soul.scars.iter().any(|s| s.id == format!("scar_{}", n)) {
+        n += 1;
+    }
+
+    let trauma_type = match point.conflict_type {
+        ConflictType::NemesisConfrontation => TraumaType::Violence,
+        ConflictType::BondTest => TraumaType::Betrayal,
+        ConflictType::MoralDilemma => TraumaType::Corruption,
+        ConflictType::ProphecyFulfillment | ConflictType::ScarReopening => TraumaType::Loss,
+        ConflictType::FearFacing
+        | ConflictType::InternalStruggle
+        | ConflictType::IdentityCrisis => TraumaType::Failure,
+    };
+
+    // Perder ante la némesis deja una cuenta pendiente
+    if matches!(point.conflict_type, ConflictType::NemesisConfrontation) {
+        shift.pending_revenge += 1;
+    }
+
+    soul.scars.push(SoulScar {
+        id: format!("scar_{}", n),
+        name: name.clone(),
+        origin_event: format!("{}: {}", point.title, outcome.description),
+        trauma_type,
+        healed: false,
+        narrative_weight: point.intensity.clamp(0.0, 1.0),
+    });
+
+    vec![tr(lang, "evolution.scar.new", &[("scar", &name)])]
+}
+
+/// "Nueva cicatriz: La Huida" -> "La Huida"; "Nueva cicatriz de derrota" -> "Cicatriz de derrota";
+/// "新たな傷：敗北" -> "敗北"
+fn scar_name(value: &str) -> String {
+    if let Some((_, name)) = value.split_once([':', '：']) {
+        return name.trim().to_string();
+    }
+    let value = value.trim();
+    let name = terms_in_any("evolution.new_scar_prefix")
+        .find_map(|prefix| value.strip_prefix(prefix.as_str()))
+        .unwrap_or(value)
+        .trim();
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn apply_to_prophecy(soul: &mut LivingSoul, id: &str, change: &ChangeType, lang: Language) -> Vec<String> {
+    let Some(prophecy) = soul.prophecies.iter_mut().find(|p| p.id == id) else {
+        return vec![tr(lang, "evolution.prophecy.missing", &[("id", &id)])];
+    };
+
+    match change {
+        ChangeType::Fulfill => {
+            prophecy.fulfilled = true;
+            prophecy.progress = 1.0;
+            vec![tr(lang, "evolution.prophecy.fulfill", &[("prophecy", &prophecy.text)])]
+        },
+        ChangeType::Transform | ChangeType::Break => {
+            // Destino reescrito: deja de ser inevitable y pasa a depender de decisiones
+            prophecy.prophecy_type = ProphecyType::Choice;
+            prophecy.progress = (prophecy.progress * 0.5).min(0.99);
+            vec![tr(lang, "evolution.prophecy.rewrite", &[("prophecy", &prophecy.text)])]
+        },
+        ChangeType::Deepen | ChangeType::Strengthen => {
+            prophecy.progress = (prophecy.progress + PROPHECY_ADVANCE).min(1.0);
+            if prophecy.progress >= 1.0 {
+                prophecy.fulfilled = true;
+                vec![tr(lang, "evolution.prophecy.advance_fulfill", &[("prophecy", &prophecy.text)])]
+            } else {
+                vec![tr(lang, "evolution.prophecy.advance", &[
+                    ("prophecy", &prophecy.text), ("progress", &format!("{:.0}", prophecy.progress * 100.0)),
+                ])]
+            }
+        },
+        ChangeType::Heal => {
+            prophecy.progress = (prophecy.progress - PROPHECY_ADVANCE).max(0.0);
+            vec![tr(lang, "evolution.prophecy.recede", &[
+                ("prophecy", &prophecy.text), ("progress", &format!("{:.0}", prophecy.progress * 100.0)),
+            ])]
+        },
+    }
+}
+
+fn apply_to_bond(
+    soul: &mut LivingSoul,
+    name: &str,
+    change: &ChangeType,
+    shift: &mut MarkerShift,
+    lang: Language,
+) -> Vec<String> {
+    let Some(bond) = soul.bonds.iter_mut().find(|b| b.entity_name == name) else {
+        return vec![tr(lang, "evolution.bond.missing", &[("entity", &name)])];
+    };
+
+    match change {
+        ChangeType::Strengthen | ChangeType::Fulfill => {
+            bond.status = BondStatus::Active;
+            bond.strength = (bond.strength + BOND_STRENGTH_STEP).min(1.0);
+            vec![tr(lang, "evolution.bond.strengthen", &[
+                ("entity", &name), ("strength", &format!("{:.2}", bond.strength)),
+            ])]
+        },
+        ChangeType::Break => {
+            bond.status = BondStatus::Broken;
+            bond.strength *= 0.5;
+            // Romper un juramento o un amor es también romper una promesa
+            if matches!(bond.bond_type, BondType::Oath | BondType::Love) {
+                shift.broken_promises += 1;
+            }
+            vec![tr(lang, "evolution.bond.break", &[("entity", &name)])]
+        },
+        ChangeType::Transform => {
+            bond.status = BondStatus::Evolving;
+            vec![tr(lang, "evolution.bond.transform", &[
+                ("entity", &name), ("bond_type", &format!("{:?}", bond.bond_type)),
+            ])]
+        },
+        ChangeType::Deepen => {
+            bond.status = match bond.status {
+                BondStatus::Active | BondStatus::Dormant | BondStatus::Evolving => BondStatus::Strained,
+                _ => BondStatus::Broken,
+            };
+            vec![tr(lang, "evolution.bond.strain", &[
+                ("entity", &name), ("status", &format!("{:?}", bond.status)),
+            ])]
+        },
+        ChangeType::Heal => {
+            bond.status = match bond.status {
+                BondStatus::Broken => BondStatus::Strained,
+                _ => BondStatus::Active,
+            };
+            vec![tr(lang, "evolution.bond.mend", &[
+                ("entity", &name), ("status", &format!("{:?}", bond.status)),
+            ])]
+        },
+    }
+}
+
+fn apply_to_nemesis(soul: &mut LivingSoul, outcome: &Outcome, shift: &mut MarkerShift, lang: Language) -> Vec<String> {
+    let Some(nemesis) = soul.nemesis.as_mut() else {
+        return vec![tr(lang, "evolution.nemesis.missing", &[])];
+    };
+    nemesis.encounters.push(outcome.description.clone());
+    let name = nemesis.name.clone();
+
+    // La reconciliación se reconoce en cualquier idioma del desenlace
+    let value = outcome.soul_impact.new_value.as_deref().unwrap_or_default().to_lowercase();
+    let becomes_ally = terms_in_any("evolution.ally_markers").any(|marker| value.contains(marker.as_str()));
+    match outcome.soul_impact.change_type {
+        ChangeType::Transform if becomes_ally => {
+            // La némesis pasa a ser un vínculo: rivalidad en evolución
+            let relationship = outcome.soul_impact.new_value.clone().unwrap_or_default();
+            soul.nemesis = None;
+            soul.bonds.push(SoulBond {
+                entity_name: name.clone(),
+                bond_type: BondType::Complex(relationship),
+                strength: 0.4,
+                status: BondStatus::Evolving,
+            });
+            shift.pending_revenge -= 1;
+            vec![tr(lang, "evolution.nemesis.ally", &[("nemesis", &name)])]
+        },
+        ChangeType::Transform | ChangeType::Heal | ChangeType::Break | ChangeType::Fulfill => {
+            nemesis.threat_level = 1;
+            shift.pending_revenge -= 1;
+            vec![tr(lang, "evolution.nemesis.defeat", &[("nemesis", &name)])]
+        },
+        ChangeType::Deepen | ChangeType::Strengthen => {
+            nemesis.threat_level = (nemesis.threat_level + 1).min(10);
+            vec![tr(lang, "evolution.nemesis.stronger", &[("nemesis", &name), ("threat", &nemesis.threat_level)])]
+        },
+    }
+}
+
+fn apply_to_psyche(
+    soul: &mut LivingSoul,
+    field: &str,
+    change: &ChangeType,
+    new_value: Option<&str>,
+    shift: &mut MarkerShift,
+    lang: Language,
+) -> Vec<String> {
+    match (field, change) {
+        ("core_fear", ChangeType::Transform | ChangeType::Heal | ChangeType::Fulfill) => {
+            let fear = std::mem::take(&mut soul.psyche.core_fear);
+            soul.raw_attributes.insert("overcome_fear".to_string(), fear.clone());
+            vec![tr(lang, "evolution.fear.overcome", &[("name", &soul.name), ("fear", &fear)])]
+        },
+        ("core_fear", _) => {
+            shift.unresolved_conflicts += 1;
+            vec![tr(lang, "evolution.fear.consume", &[("fear", &soul.psyche.core_fear)])]
+        },
+        ("moral_code", ChangeType::Transform) => {
+            match new_value.filter(|v| !soul.psyche.moral_code.iter().any(|r| r == v)) {
+                Some(rule) => {
+                    soul.psyche.moral_code.push(rule.to_string());
+                    vec![tr(lang, "evolution.moral.evolve_rule", &[("rule", &rule)])]
+                },
+                None => vec![tr(lang, "evolution.moral.evolve", &[])],
+            }
+        },
+        ("moral_code", ChangeType::Break) => {
+            shift.broken_promises += 1;
+            match soul.psyche.moral_code.first().cloned() {
+                Some(rule) => {
+                    soul.psyche.moral_code.remove(0);
+                    vec![tr(lang, "evolution.moral.abandon", &[("name", &soul.name), ("rule", &rule)])]
+                },
+                None => vec![],
+            }
+        },
+        ("moral_code", _) => {
+            vec![tr(lang, "evolution.moral.reaffirm", &[("name", &soul.name)])]
+        },
+        _ => vec![tr(lang, "evolution.psyche.unsupported", &[("field", &field)])],
+    }
+}
+
+fn apply_to_markers(field: Option<&str>, change: &ChangeType, shift: &mut MarkerShift, lang: Language) -> Vec<String> {
+    let sign = match change {
+        ChangeType::Heal | ChangeType::Fulfill => -1,
+        _ => 1,
+    };
+
+    match field {
+        Some("prophecy_pressure") => {
+            shift.prophecy_pressure += 0.2 * sign as f32;
+            vec![tr(lang, if sign > 0 { "evolution.markers.pressure_up" } else { "evolution.markers.pressure_down" }, &[])]
+        },
+        _ => {
+            shift.unresolved_conflicts += sign;
+            vec![tr(lang, if sign > 0 { "evolution.markers.conflict_up" } else { "evolution.markers.conflict_down" }, &[])]
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conflict_forge::create_soul_inflection_points;
+
+    fn soul() -> LivingSoul {
+        parse_living_sheet(r#"
+            <h1 class="name">Aria Vance</h1>
+            <div data-fear>Ser abandonada</div>
+            <div data-scar data-name="La Traición" data-trauma-type="betrayal" data-weight="0.9"></div>
+        "#)
+    }
+
+    #[test]
+    fn test_healing_a_scar_updates_markers_and_chronicle() {
+        let soul = soul();
+        let points = create_soul_inflection_points(&soul, Language::Es);
+        let point = points.iter()
+            .find(|p| matches!(p.conflict_type, ConflictType::ScarReopening))
+            .expect("punto de cicatriz");
+        let heal = &point.potential_outcomes[0];
+
+        let evolution = apply_outcome(&soul, point, heal, 4, Language::Es);
+        assert!(evolution.soul.scars.iter().all(|s| s.healed));
+        assert_eq!(evolution.soul.tension_markers.unresolved_conflicts, 0);
+        assert!(evolution.tension_after < evolution.tension_before);
+        assert_eq!(evolution.soul.chronicle.last().map(|e| e.chapter), Some(4));
+    }
+
+    #[test]
+    fn test_fleeing_fear_adds_a_scar() {
+        let soul = soul();
+        let points = create_soul_inflection_points(&soul, Language::Es);
+        let point = points.iter()
+            .find(|p| matches!(p.conflict_type, ConflictType::FearFacing))
+            .expect("punto de miedo");
+
+        let evolution = apply_outcome(&soul, point, &point.potential_outcomes[2], 2, Language::Es);
+        let scar = evolution.soul.scars.last().unwrap();
+        assert_eq!(scar.name, "La Huida");
+        assert!(!scar.healed);
+        assert_eq!(evolution.soul.tension_markers.unresolved_conflicts, 2);
+    }
+}
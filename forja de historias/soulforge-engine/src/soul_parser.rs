@@ -1,7 +1,8 @@
-use scraper::{Html, Selector};
+use scraper::{ElementRef, Html, Selector};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use wasm_bindgen::prelude::*;
+use crate::selector_profile::{Extraction, SelectorProfile};
+use crate::text_analysis::fold;
 
 /// El Alma Viva - Representación completa de un personaje
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -87,6 +88,33 @@ pub struct Nemesis {
     pub conflict_core: String,       // La raíz del conflicto
     pub threat_level: u8,            // 1-10
     pub encounters: Vec<String>,     // Historial de encuentros
+    #[serde(default)]
+    pub ledger: Vec<NemesisEncounter>, // Encuentros leídos de la narrativa
+}
+
+/// Un encuentro con la némesis detectado en el texto
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NemesisEncounter {
+    pub chapter: u32,
+    pub outcome: EncounterOutcome,
+    pub upper_hand: UpperHand,
+    pub passage: String,             // Oración que decide el encuentro
+    pub threat_level: u8,            // Amenaza tras el encuentro
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EncounterOutcome {
+    Victory,    // Uno se impone al otro
+    Escape,     // Uno huye del otro
+    Truce,      // Tregua o reconciliación
+    Standoff,   // Choque sin desenlace claro
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UpperHand {
+    Soul,
+    Nemesis,
+    Neither,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -132,170 +160,302 @@ pub struct TensionMarkers {
 // ============================================================
 
 pub fn parse_living_sheet(html: &str) -> LivingSoul {
+    parse_living_sheet_with(html, &SelectorProfile::default_profile())
+}
+
+/// Parseo con un perfil de selectores concreto (ver `selector_profile`)
+pub fn parse_living_sheet_with(html: &str, profile: &SelectorProfile) -> LivingSoul {
+    parse_sheet(html, &SelectorMatrix::from_profile(profile), &mut SheetTrace::default())
+}
+
+/// Parseo en modo diagnóstico: además del alma, qué selector llenó cada
+/// campo y qué campos quedaron con su valor por defecto
+pub fn diagnose_living_sheet(html: &str) -> SheetDiagnostics {
+    diagnose_living_sheet_with(html, &SelectorProfile::default_profile())
+}
+
+/// Diagnóstico con un perfil de selectores concreto
+pub fn diagnose_living_sheet_with(html: &str, profile: &SelectorProfile) -> SheetDiagnostics {
+    let mut trace = SheetTrace::default();
+    let soul = parse_sheet(html, &SelectorMatrix::from_profile(profile), &mut trace);
+    let defaulted = trace.fields.iter()
+        .filter(|f| matches!(f.origin, FieldOrigin::Default))
+        .map(|f| f.field.clone())
+        .collect();
+    SheetDiagnostics { soul, fields: trace.fields, defaulted }
+}
+
+fn parse_sheet(html: &str, selectors: &SelectorMatrix, trace: &mut SheetTrace) -> LivingSoul {
     let document = Html::parse_document(html);
     let mut raw_attrs: HashMap<String, String> = HashMap::new();
     
-    // === SELECTORES ADAPTATIVOS ===
-    // Estos selectores buscan patrones comunes en fichas HTML
-    let selectors = SelectorMatrix::new();
-    
     // Extracción de datos básicos
-    let name = extract_text(&document, &selectors.name)
+    let name = extract_text(&document, "name", &selectors.name, trace)
         .unwrap_or_else(|| "Alma Sin Nombre".to_string());
     
-    let titles = extract_list(&document, &selectors.titles);
-    let archetype = extract_text(&document, &selectors.archetype)
+    let titles = extract_list(&document, "titles", &selectors.titles, " ", trace);
+    let archetype = extract_text(&document, "archetype", &selectors.archetype, trace)
         .unwrap_or_default();
     
     // === PARSEO DE PSIQUE ===
-    let psyche = parse_psyche(&document, &selectors, &mut raw_attrs);
+    let psyche = parse_psyche(&document, selectors, &mut raw_attrs, trace);
     
     // === PARSEO DE CICATRICES ===
-    let scars = parse_scars(&document, &selectors);
+    let scars = parse_scars(&document, selectors, trace);
     
     // === PARSEO DE PROFECÍAS ===
-    let prophecies = parse_prophecies(&document, &selectors);
+    let prophecies = parse_prophecies(&document, selectors, trace);
     
     // === PARSEO DE NÉMESIS ===
-    let nemesis = parse_nemesis(&document, &selectors);
+    let nemesis = parse_nemesis(&document, selectors, trace);
     
     // === PARSEO DE VÍNCULOS ===
-    let bonds = parse_bonds(&document, &selectors);
+    let bonds = parse_bonds(&document, selectors, trace);
+    
+    // === CRÓNICA Y MARCADORES (fichas renderizadas por el motor) ===
+    let chronicle = parse_chronicle(&document, selectors, trace);
+    let tension_markers = parse_tension_markers(&document, selectors, &scars, &prophecies, &bonds, trace);
     
     // === EXTRACCIÓN RAW COMPLETA ===
-    extract_all_data_attributes(&document, &mut raw_attrs);
+    extract_all_data_attributes(&document, selectors, &mut raw_attrs, trace);
     
     LivingSoul {
         name,
         titles,
         archetype,
         psyche,
+        tension_markers,
         scars,
         prophecies,
         nemesis,
         bonds,
-        chronicle: Vec::new(),
-        tension_markers: calculate_initial_tension(&scars, &prophecies, &bonds),
+        chronicle,
         raw_attributes: raw_attrs,
     }
 }
 
+// ============================================================
+// DIAGNÓSTICO DE FICHAS
+// ============================================================
+
+/// Resultado de `diagnose_living_sheet`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SheetDiagnostics {
+    pub soul: LivingSoul,
+    pub fields: Vec<FieldDiagnostic>,
+    pub defaulted: Vec<String>,      // Campos sin marcado que los llene
+}
+
+/// De dónde salió el valor de un campo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldDiagnostic {
+    pub field: String,               // "psyche.core_fear", "scars[0].narrative_weight"
+    pub origin: FieldOrigin,
+    pub selector: Option<String>,    // Selector CSS (y atributo) que lo llenó
+    pub matches: usize,              // Elementos que encajaron con ese selector
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum FieldOrigin {
+    Selector,   // Leído del marcado
+    Default,    // Ningún selector encajó: valor por defecto
+    Derived,    // Calculado a partir de otros campos
+}
+
+/// Registro interno de qué selector llenó cada campo
+#[derive(Default)]
+struct SheetTrace {
+    fields: Vec<FieldDiagnostic>,
+}
+
+impl SheetTrace {
+    fn filled(&mut self, field: impl Into<String>, selector: impl Into<String>, matches: usize) {
+        self.push(field, FieldOrigin::Selector, Some(selector.into()), matches);
+    }
+
+    fn defaulted(&mut self, field: impl Into<String>) {
+        self.push(field, FieldOrigin::Default, None, 0);
+    }
+
+    fn derived(&mut self, field: impl Into<String>) {
+        self.push(field, FieldOrigin::Derived, None, 0);
+    }
+
+    fn push(&mut self, field: impl Into<String>, origin: FieldOrigin, selector: Option<String>, matches: usize) {
+        self.fields.push(FieldDiagnostic { field: field.into(), origin, selector, matches });
+    }
+
+    /// Subcampo de un elemento ya seleccionado; registra si faltaba
+    fn extract(&mut self, field: String, element: &ElementRef, css: &str, rules: &[Extraction]) -> Option<String> {
+        match extract_value(element, rules, " ") {
+            Some((value, source)) => {
+                self.filled(field, describe_source(css, source), 1);
+                Some(value)
+            },
+            None => {
+                self.defaulted(field);
+                None
+            },
+        }
+    }
+}
+
 // ============================================================
 // MATRIZ DE SELECTORES ADAPTATIVA
 // ============================================================
 
+/// Selector compilado junto a su texto CSS (para los diagnósticos)
+struct SheetSelector {
+    css: String,
+    selector: Selector,
+    extract: Vec<Extraction>,
+}
+
+/// Un perfil compilado. Los selectores inválidos se ignoran aquí;
+/// `SelectorProfile::validate` los informa.
 struct SelectorMatrix {
-    name: Vec<Selector>,
-    titles: Vec<Selector>,
-    archetype: Vec<Selector>,
-    fears: Vec<Selector>,
-    desires: Vec<Selector>,
-    flaws: Vec<Selector>,
-    scars: Vec<Selector>,
-    prophecies: Vec<Selector>,
-    nemesis: Vec<Selector>,
-    bonds: Vec<Selector>,
+    name: Vec<SheetSelector>,
+    titles: Vec<SheetSelector>,
+    archetype: Vec<SheetSelector>,
+    fears: Vec<SheetSelector>,
+    desires: Vec<SheetSelector>,
+    flaws: Vec<SheetSelector>,
+    moral_code: Vec<SheetSelector>,
+    breaking_point: Vec<SheetSelector>,
+    shadow_self: Vec<SheetSelector>,
+    scars: Vec<SheetSelector>,
+    prophecies: Vec<SheetSelector>,
+    nemesis: Vec<SheetSelector>,
+    encounters: Vec<SheetSelector>,
+    ledger: Vec<SheetSelector>,
+    bonds: Vec<SheetSelector>,
+    chronicle: Vec<SheetSelector>,
+    tension_markers: Vec<SheetSelector>,
+    soul_attrs: Vec<SheetSelector>,
+    items: HashMap<String, Vec<Extraction>>,
 }
 
 impl SelectorMatrix {
-    fn new() -> Self {
+    fn from_profile(profile: &SelectorProfile) -> Self {
+        let field = |name: &str| -> Vec<SheetSelector> {
+            profile.rules(name).iter()
+                .filter_map(|rule| Selector::parse(&rule.selector).ok().map(|selector| SheetSelector {
+                    css: rule.selector.clone(),
+                    selector,
+                    extract: rule.extract.clone(),
+                }))
+                .collect()
+        };
+
         Self {
-            // Múltiples selectores por campo = mayor compatibilidad
-            name: vec![
-                Selector::parse("[data-soul-name]").unwrap(),
-                Selector::parse(".character-name").unwrap(),
-                Selector::parse(".soul-name").unwrap(),
-                Selector::parse("#nombre-personaje").unwrap(),
-                Selector::parse("h1.name").unwrap(),
-            ],
-            titles: vec![
-                Selector::parse("[data-titles] li").unwrap(),
-                Selector::parse(".character-titles span").unwrap(),
-                Selector::parse(".soul-titles > *").unwrap(),
-            ],
-            archetype: vec![
-                Selector::parse("[data-archetype]").unwrap(),
-                Selector::parse(".character-class").unwrap(),
-                Selector::parse(".soul-archetype").unwrap(),
-            ],
-            fears: vec![
-                Selector::parse("[data-fear]").unwrap(),
-                Selector::parse("[data-core-fear]").unwrap(),
-                Selector::parse(".character-fear").unwrap(),
-                Selector::parse(".miedo-principal").unwrap(),
-            ],
-            desires: vec![
-                Selector::parse("[data-desire]").unwrap(),
-                Selector::parse("[data-deepest-desire]").unwrap(),
-                Selector::parse(".character-desire").unwrap(),
-            ],
-            flaws: vec![
-                Selector::parse("[data-flaw]").unwrap(),
-                Selector::parse("[data-fatal-flaw]").unwrap(),
-                Selector::parse(".character-flaw").unwrap(),
-            ],
-            scars: vec![
-                Selector::parse("[data-scar]").unwrap(),
-                Selector::parse(".soul-scar").unwrap(),
-                Selector::parse(".cicatriz").unwrap(),
-            ],
-            prophecies: vec![
-                Selector::parse("[data-prophecy]").unwrap(),
-                Selector::parse(".soul-prophecy").unwrap(),
-                Selector::parse(".profecia").unwrap(),
-            ],
-            nemesis: vec![
-                Selector::parse("[data-nemesis]").unwrap(),
-                Selector::parse(".soul-nemesis").unwrap(),
-                Selector::parse(".nemesis-section").unwrap(),
-            ],
-            bonds: vec![
-                Selector::parse("[data-bond]").unwrap(),
-                Selector::parse(".soul-bond").unwrap(),
-                Selector::parse(".vinculo").unwrap(),
-            ],
+            name: field("name"),
+            titles: field("titles"),
+            archetype: field("archetype"),
+            fears: field("psyche.core_fear"),
+            desires: field("psyche.deepest_desire"),
+            flaws: field("psyche.fatal_flaw"),
+            moral_code: field("psyche.moral_code"),
+            breaking_point: field("psyche.breaking_point"),
+            shadow_self: field("psyche.shadow_self"),
+            scars: field("scars"),
+            prophecies: field("prophecies"),
+            nemesis: field("nemesis"),
+            encounters: field("nemesis.encounters"),
+            ledger: field("nemesis.ledger"),
+            bonds: field("bonds"),
+            chronicle: field("chronicle"),
+            tension_markers: field("tension_markers"),
+            soul_attrs: field("raw_attributes"),
+            items: profile.item_fields.iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect(),
         }
     }
+
+    fn item(&self, field: &str) -> &[Extraction] {
+        self.items.get(field).map(Vec::as_slice).unwrap_or(&[])
+    }
 }
 
 // ============================================================
 // FUNCIONES DE EXTRACCIÓN
 // ============================================================
 
-fn extract_text(doc: &Html, selectors: &[Selector]) -> Option<String> {
+/// Primera extracción que da valor: el atributo tal cual, o el texto
+/// recortado si no está vacío. Devuelve también el atributo usado.
+fn extract_value(element: &ElementRef, rules: &[Extraction], separator: &str) -> Option<(String, Option<String>)> {
+    rules.iter().find_map(|rule| match rule {
+        Extraction::Attr { name } => element.value().attr(name)
+            .map(|value| (value.to_string(), Some(name.clone()))),
+        Extraction::Text => {
+            let text = element.text().collect::<Vec<_>>().join(separator).trim().to_string();
+            (!text.is_empty()).then_some((text, None))
+        },
+    })
+}
+
+fn describe_source(css: &str, attr: Option<String>) -> String {
+    match attr {
+        Some(name) => format!("{} @{}", css, name),
+        None => css.to_string(),
+    }
+}
+
+/// Campo de texto: primer selector que encaja y da valor.
+/// Sin reglas propias se lee `data-value` y luego el texto interno.
+fn extract_text(doc: &Html, field: &str, selectors: &[SheetSelector], trace: &mut SheetTrace) -> Option<String> {
+    let default_rules = [Extraction::Attr { name: "data-value".to_string() }, Extraction::Text];
     for sel in selectors {
-        if let Some(el) = doc.select(sel).next() {
-            // Primero buscar data-value, luego texto interno
-            if let Some(val) = el.value().attr("data-value") {
-                return Some(val.trim().to_string());
-            }
-            let text: String = el.text().collect::<Vec<_>>().join(" ");
-            let cleaned = text.trim().to_string();
-            if !cleaned.is_empty() {
-                return Some(cleaned);
+        let mut matched = doc.select(&sel.selector);
+        if let Some(el) = matched.next() {
+            let rules = if sel.extract.is_empty() { &default_rules[..] } else { &sel.extract[..] };
+            if let Some((value, source)) = extract_value(&el, rules, " ") {
+                trace.filled(field, describe_source(&sel.css, source), 1 + matched.count());
+                return Some(value.trim().to_string());
             }
         }
     }
+    trace.defaulted(field);
     None
 }
 
-fn extract_list(doc: &Html, selectors: &[Selector]) -> Vec<String> {
+/// Campo de lista: todos los elementos del primer selector que dé algún valor
+fn extract_list(
+    doc: &Html,
+    field: &str,
+    selectors: &[SheetSelector],
+    separator: &str,
+    trace: &mut SheetTrace,
+) -> Vec<String> {
     for sel in selectors {
-        let items: Vec<String> = doc.select(sel)
-            .map(|el| el.text().collect::<Vec<_>>().join(" ").trim().to_string())
-            .filter(|s| !s.is_empty())
+        let items: Vec<String> = doc.select(&sel.selector)
+            .filter_map(|el| extract_list_item(&el, sel, separator))
             .collect();
         if !items.is_empty() {
+            trace.filled(field, sel.css.as_str(), items.len());
             return items;
         }
     }
+    trace.defaulted(field);
     Vec::new()
 }
 
-fn parse_psyche(doc: &Html, sel: &SelectorMatrix, raw: &mut HashMap<String, String>) -> SoulPsyche {
-    let core_fear = extract_text(doc, &sel.fears).unwrap_or_default();
-    let deepest_desire = extract_text(doc, &sel.desires).unwrap_or_default();
-    let fatal_flaw = extract_text(doc, &sel.flaws).unwrap_or_default();
+fn extract_list_item(element: &ElementRef, sel: &SheetSelector, separator: &str) -> Option<String> {
+    let rules = if sel.extract.is_empty() { &[Extraction::Text][..] } else { &sel.extract[..] };
+    extract_value(element, rules, separator)
+        .map(|(value, _)| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn parse_psyche(
+    doc: &Html,
+    sel: &SelectorMatrix,
+    raw: &mut HashMap<String, String>,
+    trace: &mut SheetTrace,
+) -> SoulPsyche {
+    let core_fear = extract_text(doc, "psyche.core_fear", &sel.fears, trace).unwrap_or_default();
+    let deepest_desire = extract_text(doc, "psyche.deepest_desire", &sel.desires, trace).unwrap_or_default();
+    let fatal_flaw = extract_text(doc, "psyche.fatal_flaw", &sel.flaws, trace).unwrap_or_default();
     
     raw.insert("core_fear".to_string(), core_fear.clone());
     raw.insert("deepest_desire".to_string(), deepest_desire.clone());
@@ -305,33 +465,33 @@ fn parse_psyche(doc: &Html, sel: &SelectorMatrix, raw: &mut HashMap<String, Stri
         core_fear,
         deepest_desire,
         fatal_flaw,
-        moral_code: extract_moral_code(doc),
-        breaking_point: extract_breaking_point(doc),
-        shadow_self: extract_shadow_self(doc),
+        moral_code: extract_list(doc, "psyche.moral_code", &sel.moral_code, "", trace),
+        breaking_point: extract_text(doc, "psyche.breaking_point", &sel.breaking_point, trace).unwrap_or_default(),
+        shadow_self: extract_text(doc, "psyche.shadow_self", &sel.shadow_self, trace).unwrap_or_default(),
     }
 }
 
-fn parse_scars(doc: &Html, sel: &SelectorMatrix) -> Vec<SoulScar> {
+fn parse_scars(doc: &Html, sel: &SelectorMatrix, trace: &mut SheetTrace) -> Vec<SoulScar> {
     let mut scars = Vec::new();
     
     for selector in &sel.scars {
-        for (idx, element) in doc.select(selector).enumerate() {
+        let elements: Vec<ElementRef> = doc.select(&selector.selector).collect();
+        if !elements.is_empty() {
+            trace.filled("scars", selector.css.as_str(), elements.len());
+        }
+        for (idx, element) in elements.into_iter().enumerate() {
+            let css = selector.css.as_str();
+            let n = scars.len();
+            let mut read = |name: &str| {
+                trace.extract(format!("scars[{}].{}", n, name), &element, css, sel.item(&format!("scars.{}", name)))
+            };
             let scar = SoulScar {
-                id: format!("scar_{}", idx),
-                name: element.value().attr("data-name")
-                    .or_else(|| element.value().attr("data-scar-name"))
-                    .map(|s| s.to_string())
-                    .unwrap_or_else(|| format!("Cicatriz {}", idx + 1)),
-                origin_event: element.value().attr("data-origin")
-                    .map(|s| s.to_string())
-                    .unwrap_or_default(),
-                trauma_type: parse_trauma_type(
-                    element.value().attr("data-trauma-type").unwrap_or("custom")
-                ),
-                healed: element.value().attr("data-healed")
-                    .map(|s| s == "true")
-                    .unwrap_or(false),
-                narrative_weight: element.value().attr("data-weight")
+                id: read("id").unwrap_or_else(|| format!("scar_{}", idx)),
+                name: read("name").unwrap_or_else(|| format!("Cicatriz {}", idx + 1)),
+                origin_event: read("origin_event").unwrap_or_default(),
+                trauma_type: parse_trauma_type(&read("trauma_type").unwrap_or_else(|| "custom".to_string())),
+                healed: read("healed").map(|s| s == "true").unwrap_or(false),
+                narrative_weight: read("narrative_weight")
                     .and_then(|s| s.parse().ok())
                     .unwrap_or(0.5),
             };
@@ -339,26 +499,33 @@ fn parse_scars(doc: &Html, sel: &SelectorMatrix) -> Vec<SoulScar> {
         }
     }
     
+    if scars.is_empty() {
+        trace.defaulted("scars");
+    }
     scars
 }
 
-fn parse_prophecies(doc: &Html, sel: &SelectorMatrix) -> Vec<Prophecy> {
+fn parse_prophecies(doc: &Html, sel: &SelectorMatrix, trace: &mut SheetTrace) -> Vec<Prophecy> {
     let mut prophecies = Vec::new();
     
     for selector in &sel.prophecies {
-        for (idx, element) in doc.select(selector).enumerate() {
-            let text: String = element.text().collect::<Vec<_>>().join(" ");
+        let elements: Vec<ElementRef> = doc.select(&selector.selector).collect();
+        if !elements.is_empty() {
+            trace.filled("prophecies", selector.css.as_str(), elements.len());
+        }
+        for (idx, element) in elements.into_iter().enumerate() {
+            let css = selector.css.as_str();
+            let n = prophecies.len();
+            let mut read = |name: &str| {
+                trace.extract(format!("prophecies[{}].{}", n, name), &element, css, sel.item(&format!("prophecies.{}", name)))
+            };
             
             let prophecy = Prophecy {
-                id: format!("prophecy_{}", idx),
-                text: text.trim().to_string(),
-                prophecy_type: parse_prophecy_type(
-                    element.value().attr("data-prophecy-type").unwrap_or("ambiguous")
-                ),
-                fulfilled: element.value().attr("data-fulfilled")
-                    .map(|s| s == "true")
-                    .unwrap_or(false),
-                progress: element.value().attr("data-progress")
+                id: read("id").unwrap_or_else(|| format!("prophecy_{}", idx)),
+                text: read("text").map(|s| s.trim().to_string()).unwrap_or_default(),
+                prophecy_type: parse_prophecy_type(&read("prophecy_type").unwrap_or_else(|| "ambiguous".to_string())),
+                fulfilled: read("fulfilled").map(|s| s == "true").unwrap_or(false),
+                progress: read("progress")
                     .and_then(|s| s.parse().ok())
                     .unwrap_or(0.0),
             };
@@ -366,53 +533,91 @@ fn parse_prophecies(doc: &Html, sel: &SelectorMatrix) -> Vec<Prophecy> {
         }
     }
     
+    if prophecies.is_empty() {
+        trace.defaulted("prophecies");
+    }
     prophecies
 }
 
-fn parse_nemesis(doc: &Html, sel: &SelectorMatrix) -> Option<Nemesis> {
+fn parse_nemesis(doc: &Html, sel: &SelectorMatrix, trace: &mut SheetTrace) -> Option<Nemesis> {
     for selector in &sel.nemesis {
-        if let Some(element) = doc.select(selector).next() {
+        let mut matched = doc.select(&selector.selector);
+        if let Some(element) = matched.next() {
+            let css = selector.css.as_str();
+            trace.filled("nemesis", css, 1 + matched.count());
+
+            let encounters = sel.encounters.iter()
+                .map(|enc| element.select(&enc.selector)
+                    .filter_map(|el| extract_list_item(&el, enc, " "))
+                    .collect::<Vec<_>>())
+                .find(|items| !items.is_empty())
+                .unwrap_or_default();
+
+            let mut read = |name: &str| {
+                trace.extract(format!("nemesis.{}", name), &element, css, sel.item(&format!("nemesis.{}", name)))
+            };
             return Some(Nemesis {
-                name: element.value().attr("data-name")
-                    .map(|s| s.to_string())
-                    .unwrap_or_else(|| {
-                        element.text().collect::<Vec<_>>().join(" ").trim().to_string()
-                    }),
-                relationship: element.value().attr("data-relationship")
-                    .unwrap_or("desconocida")
-                    .to_string(),
-                conflict_core: element.value().attr("data-conflict")
-                    .unwrap_or("")
-                    .to_string(),
-                threat_level: element.value().attr("data-threat")
+                name: read("name").unwrap_or_default(),
+                relationship: read("relationship").unwrap_or_else(|| "desconocida".to_string()),
+                conflict_core: read("conflict_core").unwrap_or_default(),
+                threat_level: read("threat_level")
                     .and_then(|s| s.parse().ok())
                     .unwrap_or(5),
-                encounters: Vec::new(),
+                encounters,
+                ledger: parse_ledger(&element, sel, trace),
             });
         }
     }
+    trace.defaulted("nemesis");
     None
 }
 
-fn parse_bonds(doc: &Html, sel: &SelectorMatrix) -> Vec<SoulBond> {
+/// Encuentros con la némesis guardados en la ficha (ver `nemesis_ledger`)
+fn parse_ledger(nemesis: &ElementRef, sel: &SelectorMatrix, trace: &mut SheetTrace) -> Vec<NemesisEncounter> {
+    for selector in &sel.ledger {
+        let elements: Vec<ElementRef> = nemesis.select(&selector.selector).collect();
+        if elements.is_empty() {
+            continue;
+        }
+        trace.filled("nemesis.ledger", selector.css.as_str(), elements.len());
+        return elements.iter()
+            .map(|element| {
+                let attr = |name: &str| element.value().attr(name).unwrap_or_default();
+                NemesisEncounter {
+                    chapter: attr("data-chapter").parse().unwrap_or(0),
+                    outcome: parse_encounter_outcome(attr("data-outcome")),
+                    upper_hand: parse_upper_hand(attr("data-upper-hand")),
+                    passage: element.text().collect::<Vec<_>>().join(" ").trim().to_string(),
+                    threat_level: attr("data-threat").parse().unwrap_or(5),
+                }
+            })
+            .collect();
+    }
+    trace.defaulted("nemesis.ledger");
+    Vec::new()
+}
+
+fn parse_bonds(doc: &Html, sel: &SelectorMatrix, trace: &mut SheetTrace) -> Vec<SoulBond> {
     let mut bonds = Vec::new();
     
     for selector in &sel.bonds {
-        for element in doc.select(selector) {
+        let elements: Vec<ElementRef> = doc.select(&selector.selector).collect();
+        if !elements.is_empty() {
+            trace.filled("bonds", selector.css.as_str(), elements.len());
+        }
+        for element in elements {
+            let css = selector.css.as_str();
+            let n = bonds.len();
+            let mut read = |name: &str| {
+                trace.extract(format!("bonds[{}].{}", n, name), &element, css, sel.item(&format!("bonds.{}", name)))
+            };
             let bond = SoulBond {
-                entity_name: element.value().attr("data-entity")
-                    .or_else(|| element.value().attr("data-name"))
-                    .map(|s| s.to_string())
-                    .unwrap_or_default(),
-                bond_type: parse_bond_type(
-                    element.value().attr("data-bond-type").unwrap_or("complex")
-                ),
-                strength: element.value().attr("data-strength")
+                entity_name: read("entity_name").unwrap_or_default(),
+                bond_type: parse_bond_type(&read("bond_type").unwrap_or_else(|| "complex".to_string())),
+                strength: read("strength")
                     .and_then(|s| s.parse().ok())
                     .unwrap_or(0.5),
-                status: parse_bond_status(
-                    element.value().attr("data-status").unwrap_or("active")
-                ),
+                status: parse_bond_status(&read("status").unwrap_or_else(|| "active".to_string())),
             };
             if !bond.entity_name.is_empty() {
                 bonds.push(bond);
@@ -420,15 +625,95 @@ fn parse_bonds(doc: &Html, sel: &SelectorMatrix) -> Vec<SoulBond> {
         }
     }
     
+    if bonds.is_empty() {
+        trace.defaulted("bonds");
+    }
     bonds
 }
 
+/// Entradas de crónica: `data-chapter`, `data-tension-delta` y los hijos
+/// `[data-summary]`, `[data-soul-involved]` y `[data-consequence]`
+fn parse_chronicle(doc: &Html, sel: &SelectorMatrix, trace: &mut SheetTrace) -> Vec<ChronicleEntry> {
+    let summary_sel = Selector::parse("[data-summary]").unwrap();
+    let soul_sel = Selector::parse("[data-soul-involved]").unwrap();
+    let consequence_sel = Selector::parse("[data-consequence]").unwrap();
+    let texts = |element: &ElementRef, selector: &Selector| -> Vec<String> {
+        element.select(selector)
+            .map(|el| el.text().collect::<Vec<_>>().join(" ").trim().to_string())
+            .collect()
+    };
+
+    for selector in &sel.chronicle {
+        let elements: Vec<ElementRef> = doc.select(&selector.selector).collect();
+        if elements.is_empty() {
+            continue;
+        }
+        trace.filled("chronicle", selector.css.as_str(), elements.len());
+        return elements.iter()
+            .map(|element| ChronicleEntry {
+                chapter: element.value().attr("data-chapter")
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(0),
+                event_summary: texts(element, &summary_sel).into_iter().next().unwrap_or_default(),
+                souls_involved: texts(element, &soul_sel),
+                consequences: texts(element, &consequence_sel),
+                tension_delta: element.value().attr("data-tension-delta")
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(0.0),
+            })
+            .collect();
+    }
+    trace.defaulted("chronicle");
+    Vec::new()
+}
+
+/// Marcadores guardados en la ficha; lo que falte se calcula como siempre
+fn parse_tension_markers(
+    doc: &Html,
+    sel: &SelectorMatrix,
+    scars: &[SoulScar],
+    prophecies: &[Prophecy],
+    bonds: &[SoulBond],
+    trace: &mut SheetTrace,
+) -> TensionMarkers {
+    let mut markers = calculate_initial_tension(scars, prophecies, bonds);
+
+    let found = sel.tension_markers.iter()
+        .find_map(|s| doc.select(&s.selector).next().map(|el| (s, el)));
+    let Some((selector, element)) = found else {
+        trace.derived("tension_markers");
+        return markers;
+    };
+    trace.filled("tension_markers", selector.css.as_str(), 1);
+
+    let attr = |name: &str| element.value().attr(name);
+    if let Some(v) = attr("data-unresolved-conflicts").and_then(|s| s.parse().ok()) {
+        markers.unresolved_conflicts = v;
+    }
+    if let Some(v) = attr("data-broken-promises").and_then(|s| s.parse().ok()) {
+        markers.broken_promises = v;
+    }
+    if let Some(v) = attr("data-pending-revenge").and_then(|s| s.parse().ok()) {
+        markers.pending_revenge = v;
+    }
+    if let Some(v) = attr("data-prophecy-pressure").and_then(|s| s.parse().ok()) {
+        markers.prophecy_pressure = v;
+    }
+    if let Some(v) = attr("data-relationship-strain").and_then(|s| s.parse().ok()) {
+        markers.relationship_strain = v;
+    }
+    if let Some(v) = attr("data-overall-tension").and_then(|s| s.parse().ok()) {
+        markers.overall_tension = v;
+    }
+    markers
+}
+
 // ============================================================
 // HELPERS DE PARSEO
 // ============================================================
 
-fn parse_trauma_type(s: &str) -> TraumaType {
-    match s.to_lowercase().as_str() {
+pub(crate) fn parse_trauma_type(s: &str) -> TraumaType {
+    match fold(s).as_str() {
         "betrayal" | "traicion" => TraumaType::Betrayal,
         "loss" | "perdida" => TraumaType::Loss,
         "failure" | "fracaso" => TraumaType::Failure,
@@ -439,8 +724,8 @@ fn parse_trauma_type(s: &str) -> TraumaType {
     }
 }
 
-fn parse_prophecy_type(s: &str) -> ProphecyType {
-    match s.to_lowercase().as_str() {
+pub(crate) fn parse_prophecy_type(s: &str) -> ProphecyType {
+    match fold(s).as_str() {
         "doom" | "condena" | "oscuro" => ProphecyType::Doom,
         "glory" | "gloria" | "luminoso" => ProphecyType::Glory,
         "choice" | "eleccion" | "decision" => ProphecyType::Choice,
@@ -448,8 +733,8 @@ fn parse_prophecy_type(s: &str) -> ProphecyType {
     }
 }
 
-fn parse_bond_type(s: &str) -> BondType {
-    match s.to_lowercase().as_str() {
+pub(crate) fn parse_bond_type(s: &str) -> BondType {
+    match fold(s).as_str() {
         "love" | "amor" => BondType::Love,
         "friendship" | "amistad" => BondType::Friendship,
         "rivalry" | "rivalidad" => BondType::Rivalry,
@@ -462,8 +747,8 @@ fn parse_bond_type(s: &str) -> BondType {
     }
 }
 
-fn parse_bond_status(s: &str) -> BondStatus {
-    match s.to_lowercase().as_str() {
+pub(crate) fn parse_bond_status(s: &str) -> BondStatus {
+    match fold(s).as_str() {
         "active" | "activo" => BondStatus::Active,
         "strained" | "tenso" => BondStatus::Strained,
         "broken" | "roto" => BondStatus::Broken,
@@ -473,71 +758,47 @@ fn parse_bond_status(s: &str) -> BondStatus {
     }
 }
 
-fn extract_moral_code(doc: &Html) -> Vec<String> {
-    let selectors = vec![
-        Selector::parse("[data-moral-code] li").unwrap(),
-        Selector::parse(".moral-code li").unwrap(),
-        Selector::parse(".codigo-moral li").unwrap(),
-    ];
-    for sel in selectors {
-        let items: Vec<String> = doc.select(&sel)
-            .map(|el| el.text().collect::<Vec<_>>().join("").trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect();
-        if !items.is_empty() {
-            return items;
-        }
+pub(crate) fn parse_encounter_outcome(s: &str) -> EncounterOutcome {
+    match fold(s).as_str() {
+        "victory" | "victoria" => EncounterOutcome::Victory,
+        "escape" | "huida" => EncounterOutcome::Escape,
+        "truce" | "tregua" => EncounterOutcome::Truce,
+        _ => EncounterOutcome::Standoff,
     }
-    Vec::new()
 }
 
-fn extract_breaking_point(doc: &Html) -> String {
-    let selectors = vec![
-        Selector::parse("[data-breaking-point]").unwrap(),
-        Selector::parse(".breaking-point").unwrap(),
-        Selector::parse(".punto-quiebre").unwrap(),
-    ];
-    for sel in selectors {
-        if let Some(el) = doc.select(&sel).next() {
-            let text = el.text().collect::<Vec<_>>().join(" ");
-            if !text.trim().is_empty() {
-                return text.trim().to_string();
-            }
-        }
+pub(crate) fn parse_upper_hand(s: &str) -> UpperHand {
+    match fold(s).as_str() {
+        "soul" | "alma" => UpperHand::Soul,
+        "nemesis" => UpperHand::Nemesis,
+        _ => UpperHand::Neither,
     }
-    String::new()
 }
 
-fn extract_shadow_self(doc: &Html) -> String {
-    let selectors = vec![
-        Selector::parse("[data-shadow-self]").unwrap(),
-        Selector::parse(".shadow-self").unwrap(),
-        Selector::parse(".yo-sombra").unwrap(),
-    ];
-    for sel in selectors {
-        if let Some(el) = doc.select(&sel).next() {
-            let text = el.text().collect::<Vec<_>>().join(" ");
-            if !text.trim().is_empty() {
-                return text.trim().to_string();
+fn extract_all_data_attributes(
+    doc: &Html,
+    sel: &SelectorMatrix,
+    raw: &mut HashMap<String, String>,
+    trace: &mut SheetTrace,
+) {
+    for selector in &sel.soul_attrs {
+        let mut count = 0;
+        for el in doc.select(&selector.selector) {
+            if let (Some(key), Some(val)) = (
+                el.value().attr("data-soul-attr"),
+                el.value().attr("data-value")
+            ) {
+                raw.insert(key.to_string(), val.to_string());
+                count += 1;
             }
         }
-    }
-    String::new()
-}
-
-fn extract_all_data_attributes(doc: &Html, raw: &mut HashMap<String, String>) {
-    let universal = Selector::parse("[data-soul-attr]").unwrap();
-    for el in doc.select(&universal) {
-        if let (Some(key), Some(val)) = (
-            el.value().attr("data-soul-attr"),
-            el.value().attr("data-value")
-        ) {
-            raw.insert(key.to_string(), val.to_string());
+        if count > 0 {
+            trace.filled("raw_attributes", selector.css.as_str(), count);
         }
     }
 }
 
-fn calculate_initial_tension(
+pub(crate) fn calculate_initial_tension(
     scars: &[SoulScar],
     prophecies: &[Prophecy],
     bonds: &[SoulBond]
@@ -0,0 +1,599 @@
+use serde::{Deserialize, Serialize};
+use crate::forge_error::{parse_json, ForgeError};
+use crate::i18n::{tr, Language};
+use crate::soul_parser::LivingSoul;
+use crate::tension_engine::*;
+use crate::text_analysis::{fold, AnalyzedText};
+
+/// Un capítulo del manuscrito
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Chapter {
+    pub title: String,
+    pub text: String,
+}
+
+/// Forma objetivo contra la que se compara la curva del manuscrito
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum StoryStructure {
+    ThreeAct,           // Planteamiento, confrontación, resolución
+    Freytag,            // Pirámide: clímax en el centro
+    Kishotenketsu,      // Ki-shō-ten-ketsu: el giro llega en el último cuarto
+    RisingSawtooth,     // Serial: cada entrega sube y suelta, la base no deja de subir
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChapterTension {
+    pub index: usize,
+    pub title: String,
+    pub score: f32,                      // 0.0 - 1.0
+    pub peak_intensity: f32,
+    pub word_count: usize,
+    pub dominant_wave: Option<WaveType>,
+    pub tension_waves: Vec<TensionWave>,
+    pub narrative_pressure: NarrativePressure,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TensionCurveReport {
+    pub chapters: Vec<ChapterTension>,
+    pub curve: Vec<f32>,                 // Curva normalizada (pico = 1.0)
+    pub structure_fits: Vec<StructureFit>,
+    pub best_fit: Option<StoryStructure>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructureFit {
+    pub structure: StoryStructure,
+    pub target_curve: Vec<f32>,
+    pub fit_score: f32,                  // 1.0 = calca la forma
+    pub issues: Vec<CurveIssue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurveIssue {
+    pub chapter_index: usize,
+    pub issue_type: CurveIssueType,
+    pub expected: f32,
+    pub actual: f32,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CurveIssueType {
+    Sag,            // La tensión cae muy por debajo de la forma objetivo
+    EarlyPeak,      // El pico llega antes de lo que pide la estructura
+    LatePeak,       // El pico llega después
+    Overshoot,      // Tensión excesiva donde la estructura pide respiro
+}
+
+/// Palabras que abren un encabezado de capítulo (ya plegadas)
+const CHAPTER_KEYWORDS: &[&str] = &["capitulo", "chapter"];
+
+/// Números escritos que pueden seguir a la palabra de capítulo (ya plegados)
+const CHAPTER_ORDINALS: &[&str] = &[
+    "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve", "diez",
+    "once", "doce", "primero", "segundo", "tercero", "cuarto", "quinto", "sexto",
+    "septimo", "octavo", "noveno", "decimo", "final", "ultimo",
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    "eleven", "twelve", "first", "second", "third", "fourth", "fifth", "sixth",
+    "seventh", "eighth", "ninth", "tenth", "last",
+];
+
+/// Cuánto puede separarse la curva del objetivo antes de señalarlo
+const DEVIATION_THRESHOLD: f32 = 0.3;
+/// Margen (en fracción del manuscrito) para la posición del pico
+const PEAK_TOLERANCE: f32 = 0.15;
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Curva de tensión por capítulo desde JSON, para el manuscrito completo
+pub fn calculate_tension_curve(
+    soul_json: &str,
+    manuscript: &str,
+    structures: &[StoryStructure],
+    lang: Language,
+) -> TensionCurveReport {
+    try_calculate_tension_curve(soul_json, manuscript, structures, lang)
+        .unwrap_or_else(|_| TensionCurveReport::empty())
+}
+
+/// Como `calculate_tension_curve`, pero un alma inválida es un error
+pub fn try_calculate_tension_curve(
+    soul_json: &str,
+    manuscript: &str,
+    structures: &[StoryStructure],
+    lang: Language,
+) -> Result<TensionCurveReport, ForgeError> {
+    let soul: LivingSoul = parse_json("soul", soul_json)?;
+    Ok(analyze_chapters(&soul, &split_manuscript(manuscript, lang), structures, lang))
+}
+
+/// Analiza capítulos ya separados y los compara con las estructuras dadas.
+/// Si `structures` está vacío se comparan todas. Los diagnósticos van en `lang`.
+pub fn analyze_chapters(
+    soul: &LivingSoul,
+    chapters: &[Chapter],
+    structures: &[StoryStructure],
+    lang: Language,
+) -> TensionCurveReport {
+    if chapters.is_empty() {
+        return TensionCurveReport::empty();
+    }
+
+    let chapter_tensions: Vec<ChapterTension> = chapters.iter()
+        .enumerate()
+        .map(|(index, chapter)| chapter_tension(soul, index, chapter, lang))
+        .collect();
+
+    let curve = normalize(&chapter_tensions.iter().map(|c| c.score).collect::<Vec<_>>());
+
+    let candidates: Vec<StoryStructure> = if structures.is_empty() {
+        StoryStructure::all()
+    } else {
+        structures.to_vec()
+    };
+
+    let structure_fits: Vec<StructureFit> = candidates.iter()
+        .map(|s| fit_structure(*s, &curve, &chapter_tensions, lang))
+        .collect();
+
+    let best_fit = structure_fits.iter()
+        .max_by(|a, b| a.fit_score.total_cmp(&b.fit_score))
+        .map(|f| f.structure);
+
+    TensionCurveReport {
+        chapters: chapter_tensions,
+        curve,
+        structure_fits,
+        best_fit,
+    }
+}
+
+/// Separa un manuscrito por encabezados de capítulo
+/// ("Capítulo 3", "Chapter III", "# Título", "第三章"). Los `##` solo abren
+/// capítulo si el manuscrito no tiene ni `#` ni encabezados con palabra de
+/// capítulo: si no, son escenas. Sin encabezados, el texto entero es un solo
+/// capítulo. `lang` da nombre a los capítulos que no lo traen (prólogo,
+/// capítulo único).
+pub fn split_manuscript(manuscript: &str, lang: Language) -> Vec<Chapter> {
+    let mut chapters: Vec<Chapter> = Vec::new();
+    let mut current: Option<Chapter> = None;
+    let mut preamble = String::new();
+    let has_keyword = manuscript.lines().any(is_keyword_heading);
+    let top_level = manuscript.lines()
+        .filter_map(heading_level)
+        .min()
+        .filter(|level| *level == 1 || !has_keyword);
+
+    for line in manuscript.lines() {
+        if is_chapter_heading(line, top_level) {
+            if let Some(done) = current.take() {
+                chapters.push(done);
+            }
+            current = Some(Chapter {
+                title: line.trim().trim_start_matches('#').trim().to_string(),
+                text: String::new(),
+            });
+            continue;
+        }
+
+        let target = match current.as_mut() {
+            Some(chapter) => &mut chapter.text,
+            None => &mut preamble,
+        };
+        target.push_str(line);
+        target.push('\n');
+    }
+
+    if let Some(done) = current.take() {
+        chapters.push(done);
+    }
+
+    if chapters.is_empty() {
+        if !manuscript.trim().is_empty() {
+            chapters.push(Chapter {
+                title: tr(lang, "chapter.single", &[]),
+                text: manuscript.to_string(),
+            });
+        }
+    } else if !preamble.trim().is_empty() {
+        // El texto antes del primer encabezado se trata como prólogo
+        chapters.insert(0, Chapter {
+            title: tr(lang, "chapter.prologue", &[]),
+            text: preamble,
+        });
+    }
+
+    chapters
+}
+
+// ============================================================
+// ESTRUCTURAS OBJETIVO
+// ============================================================
+
+impl StoryStructure {
+    pub fn all() -> Vec<StoryStructure> {
+        vec![
+            StoryStructure::ThreeAct,
+            StoryStructure::Freytag,
+            StoryStructure::Kishotenketsu,
+            StoryStructure::RisingSawtooth,
+        ]
+    }
+
+    pub fn parse(s: &str) -> Option<StoryStructure> {
+        match s.to_lowercase().replace(['_', ' '], "-").as_str() {
+            "three-act" | "threeact" | "tres-actos" => Some(StoryStructure::ThreeAct),
+            "freytag" | "piramide" | "pirámide" => Some(StoryStructure::Freytag),
+            "kishotenketsu" | "kishōtenketsu" => Some(StoryStructure::Kishotenketsu),
+            "rising-sawtooth" | "sawtooth" | "serial" | "sierra" => Some(StoryStructure::RisingSawtooth),
+            _ => None,
+        }
+    }
+
+    /// Curva objetivo muestreada en `chapters` puntos (0.0 - 1.0)
+    pub fn target_curve(&self, chapters: usize) -> Vec<f32> {
+        (0..chapters)
+            .map(|i| {
+                let t = if chapters <= 1 { 1.0 } else { i as f32 / (chapters - 1) as f32 };
+                self.sample(t, chapters)
+            })
+            .collect()
+    }
+
+    /// Posición relativa (0.0 - 1.0) donde la estructura coloca su pico
+    pub fn peak_position(&self) -> f32 {
+        match self {
+            StoryStructure::ThreeAct => 0.9,
+            StoryStructure::Freytag => 0.5,
+            StoryStructure::Kishotenketsu => 0.75,
+            StoryStructure::RisingSawtooth => 1.0,
+        }
+    }
+
+    fn sample(&self, t: f32, chapters: usize) -> f32 {
+        match self {
+            StoryStructure::ThreeAct => interpolate(t, &[
+                (0.0, 0.2), (0.25, 0.5), (0.5, 0.6), (0.75, 0.75), (0.9, 1.0), (1.0, 0.4),
+            ]),
+            StoryStructure::Freytag => interpolate(t, &[
+                (0.0, 0.15), (0.2, 0.35), (0.5, 1.0), (0.8, 0.5), (1.0, 0.2),
+            ]),
+            StoryStructure::Kishotenketsu => interpolate(t, &[
+                (0.0, 0.2), (0.25, 0.3), (0.5, 0.4), (0.7, 0.45), (0.75, 0.95), (0.9, 0.6), (1.0, 0.35),
+            ]),
+            StoryStructure::RisingSawtooth => {
+                // Un diente cada ~3 capítulos; cada diente termina más alto que el anterior
+                let teeth = (chapters as f32 / 3.0).max(2.0);
+                let phase = (t * teeth).fract();
+                let tooth = if t >= 1.0 { 1.0 } else { phase };
+                (0.2 + 0.6 * t + 0.2 * tooth).min(1.0)
+            },
+        }
+    }
+}
+
+fn interpolate(t: f32, keyframes: &[(f32, f32)]) -> f32 {
+    for pair in keyframes.windows(2) {
+        let (t0, v0) = pair[0];
+        let (t1, v1) = pair[1];
+        if t <= t1 {
+            let span = (t1 - t0).max(f32::EPSILON);
+            return v0 + (v1 - v0) * ((t - t0) / span).clamp(0.0, 1.0);
+        }
+    }
+    keyframes.last().map(|k| k.1).unwrap_or(0.0)
+}
+
+// ============================================================
+// ANÁLISIS POR CAPÍTULO
+// ============================================================
+
+fn chapter_tension(soul: &LivingSoul, index: usize, chapter: &Chapter, lang: Language) -> ChapterTension {
+    let text = AnalyzedText::new(&chapter.text);
+    let analysis = calculate_analyzed_tension(soul, &text, lang);
+    let word_count = text.tokens().len();
+
+    // Densidad de ondas por cada 100 palabras, saturada a 1.0
+    let total: f32 = analysis.tension_waves.iter().map(|w| w.intensity).sum();
+    let density = total / (word_count.max(1) as f32 / 100.0).max(1.0);
+    let score = 1.0 - (-density).exp();
+
+    let peak_intensity = analysis.tension_waves.iter()
+        .map(|w| w.intensity)
+        .fold(0.0, f32::max);
+
+    ChapterTension {
+        index,
+        title: chapter.title.clone(),
+        score,
+        peak_intensity,
+        word_count,
+        dominant_wave: dominant_wave(&analysis.tension_waves),
+        tension_waves: analysis.tension_waves,
+        narrative_pressure: analysis.narrative_pressure,
+    }
+}
+
+fn dominant_wave(waves: &[TensionWave]) -> Option<WaveType> {
+    let kinds = [
+        WaveType::FearApproach,
+        WaveType::DesireConflict,
+        WaveType::ScarResonance,
+        WaveType::ProphecyPulse,
+        WaveType::NemesisShadow,
+        WaveType::BondStrain,
+    ];
+
+    kinds.into_iter()
+        .map(|kind| {
+            let weight: f32 = waves.iter()
+                .filter(|w| std::mem::discriminant(&w.wave_type) == std::mem::discriminant(&kind))
+                .map(|w| w.intensity)
+                .sum();
+            (kind, weight)
+        })
+        .filter(|(_, weight)| *weight > 0.0)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(kind, _)| kind)
+}
+
+fn normalize(scores: &[f32]) -> Vec<f32> {
+    let max = scores.iter().cloned().fold(0.0, f32::max);
+    if max <= f32::EPSILON {
+        return vec![0.0; scores.len()];
+    }
+    scores.iter().map(|s| s / max).collect()
+}
+
+// ============================================================
+// COMPARACIÓN CON LA FORMA OBJETIVO
+// ============================================================
+
+fn fit_structure(
+    structure: StoryStructure,
+    curve: &[f32],
+    chapters: &[ChapterTension],
+    lang: Language,
+) -> StructureFit {
+    let target = structure.target_curve(curve.len());
+    let mut issues = Vec::new();
+
+    let mean_error: f32 = curve.iter()
+        .zip(&target)
+        .map(|(a, e)| (a - e).abs())
+        .sum::<f32>() / curve.len().max(1) as f32;
+
+    for (i, (actual, expected)) in curve.iter().zip(&target).enumerate() {
+        let chapter = &chapters[i];
+        if expected - actual > DEVIATION_THRESHOLD {
+            issues.push(CurveIssue {
+                chapter_index: i,
+                issue_type: CurveIssueType::Sag,
+                expected: *expected,
+                actual: *actual,
+                description: tr(lang, "curve.sag", &[
+                    ("chapter", &chapter.title),
+                    ("expected", &percent(*expected)),
+                    ("actual", &percent(*actual)),
+                    ("hint", &sag_hint(chapter, lang)),
+                ]),
+            });
+        } else if actual - expected > DEVIATION_THRESHOLD {
+            issues.push(CurveIssue {
+                chapter_index: i,
+                issue_type: CurveIssueType::Overshoot,
+                expected: *expected,
+                actual: *actual,
+                description: tr(lang, "curve.overshoot", &[
+                    ("chapter", &chapter.title), ("actual", &percent(*actual)), ("expected", &percent(*expected)),
+                ]),
+            });
+        }
+    }
+
+    if let Some(issue) = peak_issue(structure, curve, &target, chapters, lang) {
+        issues.push(issue);
+    }
+
+    StructureFit {
+        structure,
+        target_curve: target,
+        fit_score: (1.0 - mean_error).max(0.0),
+        issues,
+    }
+}
+
+fn peak_issue(
+    structure: StoryStructure,
+    curve: &[f32],
+    target: &[f32],
+    chapters: &[ChapterTension],
+    lang: Language,
+) -> Option<CurveIssue> {
+    if curve.len() < 3 {
+        return None;
+    }
+
+    let peak_index = curve.iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(i, _)| i)?;
+    let position = peak_index as f32 / (curve.len() - 1) as f32;
+    let expected = structure.peak_position();
+    let chapter = &chapters[peak_index];
+
+    let issue_type = if position < expected - PEAK_TOLERANCE {
+        CurveIssueType::EarlyPeak
+    } else if position > expected + PEAK_TOLERANCE {
+        CurveIssueType::LatePeak
+    } else {
+        return None;
+    };
+
+    let key = match issue_type {
+        CurveIssueType::EarlyPeak => "curve.early_peak",
+        _ => "curve.late_peak",
+    };
+    let description = tr(lang, key, &[
+        ("chapter", &chapter.title), ("position", &percent(position)), ("expected", &percent(expected)),
+    ]);
+
+    Some(CurveIssue {
+        chapter_index: peak_index,
+        issue_type,
+        expected: target[peak_index],
+        actual: curve[peak_index],
+        description,
+    })
+}
+
+fn sag_hint(chapter: &ChapterTension, lang: Language) -> String {
+    match chapter.dominant_wave {
+        None => tr(lang, "curve.sag_hint_empty", &[]),
+        Some(ref wave) => tr(lang, "curve.sag_hint_single", &[
+            ("wave", &format!("{:?}", wave)), ("count", &chapter.tension_waves.len()),
+        ]),
+    }
+}
+
+/// 0.42 -> "42"
+fn percent(value: f32) -> String {
+    format!("{:.0}", value * 100.0)
+}
+
+/// Un encabezado con palabra de capítulo siempre abre capítulo; uno Markdown
+/// sin ella, solo si es del nivel que hace de capítulo (`top_level`)
+fn is_chapter_heading(line: &str, top_level: Option<usize>) -> bool {
+    is_keyword_heading(line) || heading_level(line).is_some_and(|level| Some(level) == top_level)
+}
+
+/// "Capítulo 3", "Chapter III", "## Capítulo tres: la huida", "第三章".
+/// La palabra sola no basta: "Capítulo tras capítulo, Aria dudaba." es prosa
+fn is_keyword_heading(line: &str) -> bool {
+    let trimmed = line.trim();
+    if trimmed.is_empty() || trimmed.chars().count() > 80 {
+        return false;
+    }
+    let title = trimmed.trim_start_matches('#').trim();
+    if title.starts_with('第') && title.contains('章') {
+        return true;
+    }
+
+    let mut words = title.split_whitespace();
+    let keyword = words.next().map(fold).unwrap_or_default();
+    let number = words.next()
+        .map(|w| w.trim_end_matches(|c: char| !c.is_alphanumeric()))
+        .unwrap_or_default();
+    CHAPTER_KEYWORDS.contains(&keyword.as_str())
+        && !number.is_empty()
+        && (number.chars().all(|c| c.is_ascii_digit())
+            || number.chars().all(|c| "IVXLCDM".contains(c))
+            || CHAPTER_ORDINALS.contains(&fold(number).as_str()))
+}
+
+/// "## Título" -> 2. `#etiqueta` y `#!` no son encabezados
+fn heading_level(line: &str) -> Option<usize> {
+    let trimmed = line.trim();
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    let rest = &trimmed[level..];
+    (level > 0 && (rest.is_empty() || rest.starts_with(char::is_whitespace))).then_some(level)
+}
+
+impl TensionCurveReport {
+    fn empty() -> Self {
+        Self {
+            chapters: vec![],
+            curve: vec![],
+            structure_fits: vec![],
+            best_fit: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_manuscript_by_headings() {
+        let manuscript = "Nota previa\nCapítulo 1\nUno.\n# Dos\nDos.\n## Escena\nSigue.\n第三章\n三。\n#etiqueta\n";
+        let chapters = split_manuscript(manuscript, Language::Es);
+
+        let titles: Vec<&str> = chapters.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, vec!["Prólogo", "Capítulo 1", "Dos", "第三章"]);
+        assert_eq!(chapters[2].text.trim(), "Dos.\n## Escena\nSigue.");
+    }
+
+    #[test]
+    fn test_target_curves_peak_where_expected() {
+        for structure in StoryStructure::all() {
+            let curve = structure.target_curve(21);
+            let peak = curve.iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .map(|(i, _)| i as f32 / 20.0)
+                .unwrap();
+            assert!((peak - structure.peak_position()).abs() <= PEAK_TOLERANCE, "{:?}", structure);
+        }
+    }
+
+    #[test]
+    fn test_manuscript_with_early_climax_sags_and_peaks_early() {
+        let soul = crate::soul_parser::parse_living_sheet(r#"
+            <h1 class="name">Aria</h1>
+            <div data-fear>La oscuridad eterna</div>
+            <div data-wound>El abandono de su madre</div>
+            <ul class="moral-code"><li>Nunca matar</li></ul>
+        "#);
+        let manuscript = "\
+# El asalto
+Aria temía la oscuridad. La oscuridad eterna la cercaba y gritó de miedo.
+## Escena
+Su madre la abandonó en la oscuridad. Aria mató al guardia, temblando de terror.
+# El camino
+Aria caminó por el bosque y comió pan junto al río.
+## Escena
+El sol brillaba sobre el campo.
+# La posada
+Aria durmió en la posada. Por la mañana desayunó con calma.
+# El mercado
+Aria compró manzanas y charló con el herrero.
+# El regreso
+Aria volvió a casa al atardecer y leyó un libro.
+";
+        // Las escenas `##` no abren capítulos
+        let chapters = split_manuscript(manuscript, Language::Es);
+        let titles: Vec<&str> = chapters.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, vec!["El asalto", "El camino", "La posada", "El mercado", "El regreso"]);
+
+        let report = analyze_chapters(&soul, &chapters, &[StoryStructure::ThreeAct], Language::Es);
+        let issues = &report.structure_fits[0].issues;
+        assert!(issues.iter().any(|i| matches!(i.issue_type, CurveIssueType::EarlyPeak) && i.chapter_index == 0));
+        // El cuarto capítulo, donde los tres actos piden subir, se hunde
+        assert!(issues.iter().any(|i| matches!(i.issue_type, CurveIssueType::Sag) && i.chapter_index == 3));
+    }
+
+    #[test]
+    fn test_chapter_keyword_needs_a_number() {
+        let manuscript = "Capítulo 1\nCapítulo tras capítulo, Aria dudaba.\nChapter III\nThree.\n";
+        let titles: Vec<String> = split_manuscript(manuscript, Language::Es).into_iter().map(|c| c.title).collect();
+        assert_eq!(titles, vec!["Capítulo 1", "Chapter III"]);
+    }
+
+    #[test]
+    fn test_scene_headings_under_keyword_chapters() {
+        let manuscript = "Capítulo 1\nUno.\n## Escena\nSigue.\nCapítulo dos\nDos.\n## Otra escena\nFin.\n";
+        let chapters = split_manuscript(manuscript, Language::Es);
+        let titles: Vec<&str> = chapters.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, vec!["Capítulo 1", "Capítulo dos"]);
+        assert_eq!(chapters[0].text.trim(), "Uno.\n## Escena\nSigue.");
+
+        // Sin `#` ni palabra de capítulo, los `##` son los capítulos
+        let titles: Vec<String> = split_manuscript("## Uno\nA.\n## Dos\nB.\n", Language::Es)
+            .into_iter().map(|c| c.title).collect();
+        assert_eq!(titles, vec!["Uno", "Dos"]);
+    }
+}
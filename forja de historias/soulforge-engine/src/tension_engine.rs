@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
-use regex::Regex;
+use crate::forge_error::{parse_json, ForgeError};
+use crate::i18n::{tr, Language};
 use crate::soul_parser::LivingSoul;
+use crate::text_analysis::{keywords, AnalyzedText};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TensionAnalysis {
@@ -13,7 +15,7 @@ pub struct TensionAnalysis {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TensionWave {
-    pub position: usize,         // Posición en el texto
+    pub position: usize,         // Posición en el texto (caracteres)
     pub intensity: f32,          // 0.0 - 1.0
     pub wave_type: WaveType,
     pub source: String,          // Qué elemento del alma lo causó
@@ -45,23 +47,34 @@ pub struct NarrativePressure {
     pub suggested_catharsis: Vec<String>,
 }
 
-pub fn calculate_tension(soul_json: &str, narrative: &str) -> TensionAnalysis {
-    let soul: LivingSoul = match serde_json::from_str(soul_json) {
-        Ok(s) => s,
-        Err(_) => return TensionAnalysis::empty(),
-    };
-    
+pub fn calculate_tension(soul_json: &str, narrative: &str, lang: Language) -> TensionAnalysis {
+    try_calculate_tension(soul_json, narrative, lang).unwrap_or_else(|_| TensionAnalysis::empty())
+}
+
+/// Como `calculate_tension`, pero un alma inválida es un error y no un análisis vacío
+pub fn try_calculate_tension(soul_json: &str, narrative: &str, lang: Language) -> Result<TensionAnalysis, ForgeError> {
+    let soul: LivingSoul = parse_json("soul", soul_json)?;
+    Ok(calculate_soul_tension(&soul, narrative, lang))
+}
+
+/// Análisis de tensión sobre un alma ya deserializada; las sugerencias van en `lang`
+pub fn calculate_soul_tension(soul: &LivingSoul, narrative: &str, lang: Language) -> TensionAnalysis {
+    calculate_analyzed_tension(soul, &AnalyzedText::new(narrative), lang)
+}
+
+/// Análisis de tensión sobre un texto ya segmentado.
+/// Las posiciones de las ondas son offsets en caracteres.
+pub fn calculate_analyzed_tension(soul: &LivingSoul, text: &AnalyzedText, lang: Language) -> TensionAnalysis {
     let mut waves: Vec<TensionWave> = Vec::new();
     let mut triggered: Vec<TriggeredElement> = Vec::new();
-    let narrative_lower = narrative.to_lowercase();
     
     // === ANÁLISIS DE MIEDO ===
     if !soul.psyche.core_fear.is_empty() {
-        let fear_keywords = extract_keywords(&soul.psyche.core_fear);
+        let fear_keywords = keywords(&soul.psyche.core_fear);
         for keyword in &fear_keywords {
-            for (pos, _) in narrative_lower.match_indices(keyword) {
+            for found in text.find(keyword) {
                 waves.push(TensionWave {
-                    position: pos,
+                    position: found.char_start,
                     intensity: 0.7,
                     wave_type: WaveType::FearApproach,
                     source: format!("core_fear:{}", keyword),
@@ -69,7 +82,7 @@ pub fn calculate_tension(soul_json: &str, narrative: &str) -> TensionAnalysis {
                 triggered.push(TriggeredElement {
                     element_type: "fear".to_string(),
                     element_id: "core_fear".to_string(),
-                    trigger_phrase: extract_context(narrative, pos, 30),
+                    trigger_phrase: text.context(found.char_start, 30),
                     resonance_strength: 0.7,
                 });
             }
@@ -78,11 +91,11 @@ pub fn calculate_tension(soul_json: &str, narrative: &str) -> TensionAnalysis {
     
     // === ANÁLISIS DE CICATRICES ===
     for scar in &soul.scars {
-        let scar_keywords = extract_keywords(&scar.origin_event);
+        let scar_keywords = keywords(&scar.origin_event);
         for keyword in &scar_keywords {
-            for (pos, _) in narrative_lower.match_indices(keyword) {
+            for found in text.find(keyword) {
                 waves.push(TensionWave {
-                    position: pos,
+                    position: found.char_start,
                     intensity: scar.narrative_weight,
                     wave_type: WaveType::ScarResonance,
                     source: format!("scar:{}", scar.id),
@@ -90,7 +103,7 @@ pub fn calculate_tension(soul_json: &str, narrative: &str) -> TensionAnalysis {
                 triggered.push(TriggeredElement {
                     element_type: "scar".to_string(),
                     element_id: scar.id.clone(),
-                    trigger_phrase: extract_context(narrative, pos, 30),
+                    trigger_phrase: text.context(found.char_start, 30),
                     resonance_strength: scar.narrative_weight,
                 });
             }
@@ -100,16 +113,16 @@ pub fn calculate_tension(soul_json: &str, narrative: &str) -> TensionAnalysis {
     // === ANÁLISIS DE PROFECÍAS ===
     for prophecy in &soul.prophecies {
         if !prophecy.fulfilled {
-            let prophecy_keywords = extract_keywords(&prophecy.text);
+            let prophecy_keywords = keywords(&prophecy.text);
             for keyword in &prophecy_keywords {
-                for (pos, _) in narrative_lower.match_indices(keyword) {
+                for found in text.find(keyword) {
                     let intensity = match prophecy.prophecy_type {
                         crate::soul_parser::ProphecyType::Doom => 0.9,
                         crate::soul_parser::ProphecyType::Choice => 0.6,
                         _ => 0.4,
                     };
                     waves.push(TensionWave {
-                        position: pos,
+                        position: found.char_start,
                         intensity,
                         wave_type: WaveType::ProphecyPulse,
                         source: format!("prophecy:{}", prophecy.id),
@@ -121,13 +134,13 @@ pub fn calculate_tension(soul_json: &str, narrative: &str) -> TensionAnalysis {
     
     // === ANÁLISIS DE NÉMESIS ===
     if let Some(ref nemesis) = soul.nemesis {
-        let nemesis_keywords = extract_keywords(&nemesis.name);
+        let nemesis_keywords = keywords(&nemesis.name);
         nemesis_keywords.iter()
-            .chain(extract_keywords(&nemesis.conflict_core).iter())
+            .chain(keywords(&nemesis.conflict_core).iter())
             .for_each(|keyword| {
-                for (pos, _) in narrative_lower.match_indices(keyword) {
+                for found in text.find(keyword) {
                     waves.push(TensionWave {
-                        position: pos,
+                        position: found.char_start,
                         intensity: 0.85,
                         wave_type: WaveType::NemesisShadow,
                         source: format!("nemesis:{}", nemesis.name),
@@ -142,11 +155,11 @@ pub fn calculate_tension(soul_json: &str, narrative: &str) -> TensionAnalysis {
             crate::soul_parser::BondStatus::Strained | 
             crate::soul_parser::BondStatus::Broken
         ) {
-            let bond_keywords = extract_keywords(&bond.entity_name);
+            let bond_keywords = keywords(&bond.entity_name);
             for keyword in &bond_keywords {
-                for (pos, _) in narrative_lower.match_indices(keyword) {
+                for found in text.find(keyword) {
                     waves.push(TensionWave {
-                        position: pos,
+                        position: found.char_start,
                         intensity: 0.55,
                         wave_type: WaveType::BondStrain,
                         source: format!("bond:{}", bond.entity_name),
@@ -156,11 +169,22 @@ pub fn calculate_tension(soul_json: &str, narrative: &str) -> TensionAnalysis {
         }
     }
     
+    summarize_tension(soul, waves, triggered, text.char_len(), lang)
+}
+
+/// Ondas y elementos disparados -> análisis completo (presión, score, escalada)
+pub(crate) fn summarize_tension(
+    soul: &LivingSoul,
+    mut waves: Vec<TensionWave>,
+    triggered: Vec<TriggeredElement>,
+    text_len: usize,
+    lang: Language,
+) -> TensionAnalysis {
     // Ordenar ondas por posición
     waves.sort_by_key(|w| w.position);
     
     // Calcular presión narrativa
-    let pressure = calculate_narrative_pressure(&waves, narrative.len());
+    let pressure = calculate_narrative_pressure(&waves, text_len, lang);
     
     // Score general
     let overall = if waves.is_empty() {
@@ -176,28 +200,11 @@ pub fn calculate_tension(soul_json: &str, narrative: &str) -> TensionAnalysis {
         tension_waves: waves,
         triggered_elements: triggered,
         narrative_pressure: pressure,
-        recommended_escalation: suggest_escalation(&soul, overall),
+        recommended_escalation: suggest_escalation(soul, overall, lang),
     }
 }
 
-fn extract_keywords(text: &str) -> Vec<String> {
-    let stopwords = ["el", "la", "de", "en", "que", "y", "a", "los", "las", 
-                     "un", "una", "the", "of", "and", "to", "in", "for"];
-    
-    text.to_lowercase()
-        .split(|c: char| !c.is_alphanumeric())
-        .filter(|w| w.len() > 3 && !stopwords.contains(w))
-        .map(|s| s.to_string())
-        .collect()
-}
-
-fn extract_context(text: &str, pos: usize, radius: usize) -> String {
-    let start = pos.saturating_sub(radius);
-    let end = (pos + radius).min(text.len());
-    text.get(start..end).unwrap_or("").to_string()
-}
-
-fn calculate_narrative_pressure(waves: &[TensionWave], text_len: usize) -> NarrativePressure {
+pub(crate) fn calculate_narrative_pressure(waves: &[TensionWave], text_len: usize, lang: Language) -> NarrativePressure {
     if waves.is_empty() {
         return NarrativePressure {
             building: false,
@@ -227,31 +234,24 @@ fn calculate_narrative_pressure(waves: &[TensionWave], text_len: usize) -> Narra
         peak_predicted_at: if building { Some(text_len + 500) } else { None },
         release_needed: total_tension > 2.0,
         suggested_catharsis: if total_tension > 2.0 {
-            vec![
-                "Escena de catarsis emocional".to_string(),
-                "Momento de victoria menor".to_string(),
-                "Revelación que alivia tensión".to_string(),
-            ]
+            ["catharsis.emotional", "catharsis.small_victory", "catharsis.revelation"]
+                .iter()
+                .map(|key| tr(lang, key, &[]))
+                .collect()
         } else {
             vec![]
         },
     }
 }
 
-fn suggest_escalation(soul: &LivingSoul, tension: f32) -> Option<String> {
+fn suggest_escalation(soul: &LivingSoul, tension: f32, lang: Language) -> Option<String> {
     if tension < 0.4 {
         // Tensión baja: sugerir aproximación al conflicto
         if let Some(ref nemesis) = soul.nemesis {
-            return Some(format!(
-                "La tensión es baja. Considera introducir una señal de {}.",
-                nemesis.name
-            ));
+            return Some(tr(lang, "escalation.nemesis", &[("nemesis", &nemesis.name)]));
         }
         if !soul.prophecies.is_empty() {
-            return Some(
-                "La tensión es baja. Un presagio de la profecía aumentaría el interés."
-                .to_string()
-            );
+            return Some(tr(lang, "escalation.prophecy", &[]));
         }
     }
     None
@@ -0,0 +1,426 @@
+//! Capa compartida de análisis de texto
+//!
+//! Todos los analizadores del motor buscan palabras clave a través de aquí:
+//! segmentación Unicode por palabras (UAX #29), plegado de acentos y
+//! mayúsculas, y un stemming ligero español/inglés. Las posiciones que se
+//! devuelven son offsets en caracteres, no en bytes, para que sigan siendo
+//! válidas en texto japonés o con acentos.
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Una palabra del texto original
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Token {
+    pub text: String,           // Tal como aparece en el texto
+    pub folded: String,         // Minúsculas y sin acentos
+    pub stem: String,           // Raíz ligera sobre `folded`
+    pub char_start: usize,
+    pub char_end: usize,
+}
+
+/// Una coincidencia de término (palabra o frase) dentro del texto
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TermMatch {
+    pub char_start: usize,
+    pub char_end: usize,
+    pub token_index: usize,
+    pub token_count: usize,
+}
+
+/// Una oración del texto (UAX #29), en offsets de caracteres
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sentence {
+    pub char_start: usize,
+    pub char_end: usize,
+}
+
+/// Texto segmentado listo para búsquedas repetidas
+#[derive(Debug, Clone)]
+pub struct AnalyzedText {
+    chars: Vec<char>,
+    tokens: Vec<Token>,
+    sentences: Vec<Sentence>,
+}
+
+const STOPWORDS: &[&str] = &[
+    "el", "la", "lo", "de", "del", "en", "que", "y", "a", "al", "los", "las",
+    "un", "una", "unos", "unas", "su", "sus", "se", "por", "para", "con", "sin",
+    "como", "pero", "mas", "muy", "ya", "este", "esta", "ese", "esa", "aquel",
+    "the", "of", "and", "to", "in", "for", "a", "an", "his", "her", "their",
+    "with", "that", "this", "from", "was", "were", "is", "are", "be",
+];
+
+/// Sufijos que el stemmer ligero recorta, del más largo al más corto
+const SUFFIXES: &[&str] = &[
+    "amientos", "imientos", "amiento", "imiento", "aciones", "iciones",
+    "ciones", "mente", "ieron", "aron", "ando", "iendo", "ados", "adas",
+    "idos", "idas", "ness", "ment", "cion", "sion", "aria", "eria", "iera",
+    "aban", "ian", "aba", "ado", "ada", "ido", "ida", "ing", "ara", "ar",
+    "er", "ir", "ia", "io", "or", "ed", "al", "ly", "es", "s", "a", "o", "e",
+];
+
+/// Longitud mínima que debe conservar una raíz
+const MIN_STEM_CHARS: usize = 3;
+
+/// Sufijos que solo se recortan en palabras largas: "moral", "morado" y
+/// "mentir" no deben quedarse en "mor" y "ment"
+const LONG_WORD_SUFFIXES: &[&str] = &["ados", "adas", "ado", "ada", "al", "ir"];
+const MIN_LONG_WORD_CHARS: usize = 7;
+
+/// Formas irregulares o demasiado cortas para el stemmer, con la raíz a la
+/// que pertenecen. Recortarlas daría "mur", "mor" o "tem", que chocan con
+/// "muro", "moral" o "tema".
+const INFLECTIONS: &[(&str, &[&str])] = &[
+    ("muert", &[
+        "morir", "muere", "mueren", "muero", "murio", "murieron", "moria", "morian",
+        "morira", "moriran", "muriendo", "moribundo", "moribunda",
+    ]),
+    ("mied", &[
+        "temer", "teme", "temen", "temo", "temia", "temian", "temio", "temieron",
+        "temiendo", "temor", "temores", "temeroso", "temerosa", "pavor",
+    ]),
+];
+
+/// Familias de conceptos: raíces distintas que narrativamente significan lo mismo
+const CONCEPT_FAMILIES: &[&[&str]] = &[
+    &["mied", "terr", "panic", "fear", "afraid", "dread"],
+    &["trai", "betray", "traitor"],
+    &["odi", "hat", "hatred"],
+    &["venganz", "veng", "revenge", "aveng"],
+    &["muert", "death", "dead", "died"],
+    &["culp", "guilt"],
+    &["abandon"],
+];
+
+impl AnalyzedText {
+    pub fn new(text: &str) -> Self {
+        Self {
+            chars: text.chars().collect(),
+            tokens: tokenize(text),
+            sentences: split_sentences(text),
+        }
+    }
+
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    pub fn chars(&self) -> &[char] {
+        &self.chars
+    }
+
+    pub fn sentences(&self) -> &[Sentence] {
+        &self.sentences
+    }
+
+    /// La oración que contiene el offset `char_pos`
+    pub fn sentence_at(&self, char_pos: usize) -> Option<Sentence> {
+        self.sentences.iter()
+            .find(|s| s.char_start <= char_pos && char_pos < s.char_end)
+            .copied()
+    }
+
+    /// Índices de los tokens que caen dentro de `[char_start, char_end)`
+    pub fn token_range(&self, char_start: usize, char_end: usize) -> std::ops::Range<usize> {
+        let first = self.tokens.partition_point(|t| t.char_end <= char_start);
+        let last = self.tokens.partition_point(|t| t.char_start < char_end);
+        first..last.max(first)
+    }
+
+    /// Longitud del texto en caracteres
+    pub fn char_len(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// Todas las apariciones de `term` respetando límites de palabra.
+    /// Un término de varias palabras debe aparecer como secuencia contigua.
+    pub fn find(&self, term: &str) -> Vec<TermMatch> {
+        let needle = tokenize(term);
+        if needle.is_empty() || needle.len() > self.tokens.len() {
+            return Vec::new();
+        }
+
+        (0..=self.tokens.len() - needle.len())
+            .filter(|&start| {
+                needle.iter()
+                    .zip(&self.tokens[start..])
+                    .all(|(n, t)| tokens_match(n, t))
+            })
+            .map(|start| {
+                let last = &self.tokens[start + needle.len() - 1];
+                TermMatch {
+                    char_start: self.tokens[start].char_start,
+                    char_end: last.char_end,
+                    token_index: start,
+                    token_count: needle.len(),
+                }
+            })
+            .collect()
+    }
+
+    pub fn contains(&self, term: &str) -> bool {
+        !self.find(term).is_empty()
+    }
+
+    /// Offset en caracteres de la primera aparición de `term`
+    pub fn first(&self, term: &str) -> Option<usize> {
+        self.find(term).first().map(|m| m.char_start)
+    }
+
+    /// Fragmento de `radius` caracteres a cada lado de `char_pos`
+    pub fn context(&self, char_pos: usize, radius: usize) -> String {
+        let start = char_pos.saturating_sub(radius).min(self.chars.len());
+        let end = (char_pos + radius).min(self.chars.len());
+        self.chars[start..end].iter().collect()
+    }
+
+    /// Texto entre dos offsets de caracteres
+    pub fn slice(&self, char_start: usize, char_end: usize) -> String {
+        let start = char_start.min(self.chars.len());
+        let end = char_end.clamp(start, self.chars.len());
+        self.chars[start..end].iter().collect()
+    }
+}
+
+/// Segmenta `text` en palabras con offsets en caracteres
+pub fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut char_pos = 0;
+    let mut byte_pos = 0;
+
+    for (byte_start, word) in text.split_word_bound_indices() {
+        char_pos += text[byte_pos..byte_start].chars().count();
+        byte_pos = byte_start;
+
+        let len = word.chars().count();
+        if word.chars().any(|c| c.is_alphanumeric()) {
+            let folded = fold(word);
+            tokens.push(Token {
+                text: word.to_string(),
+                stem: stem(&folded),
+                folded,
+                char_start: char_pos,
+                char_end: char_pos + len,
+            });
+        }
+    }
+
+    tokens
+}
+
+/// Segmenta `text` en oraciones con offsets en caracteres.
+/// Los saltos de línea también cierran oración (diálogos con raya, listas).
+pub fn split_sentences(text: &str) -> Vec<Sentence> {
+    let mut sentences = Vec::new();
+    let mut char_pos = 0;
+
+    for line in text.split_inclusive('\n') {
+        for piece in line.split_sentence_bounds() {
+            let len = piece.chars().count();
+            if !piece.trim().is_empty() {
+                sentences.push(Sentence {
+                    char_start: char_pos,
+                    char_end: char_pos + len,
+                });
+            }
+            char_pos += len;
+        }
+    }
+
+    sentences
+}
+
+/// Minúsculas y sin diacríticos latinos ("Traición" -> "traicion").
+/// Las marcas de kana (dakuten) se conservan.
+pub fn fold(text: &str) -> String {
+    text.nfd()
+        .filter(|c| !(is_combining_mark(*c) && ('\u{0300}'..='\u{036F}').contains(c)))
+        .flat_map(|c| c.to_lowercase())
+        .nfc()
+        .collect()
+}
+
+/// Stemming ligero español/inglés sobre una palabra ya plegada.
+/// Palabras no latinas (kanji, kana...) se devuelven tal cual.
+pub fn stem(folded: &str) -> String {
+    if !folded.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return folded.to_string();
+    }
+
+    if let Some((root, _)) = INFLECTIONS.iter().find(|(_, forms)| forms.contains(&folded)) {
+        return root.to_string();
+    }
+
+    let mut word = folded.to_string();
+    // Dos pasadas: "traicionaria" -> "traicion" -> "trai"
+    for _ in 0..2 {
+        let Some(suffix) = SUFFIXES.iter()
+            .find(|s| {
+                word.ends_with(*s)
+                    && word.len() - s.len() >= MIN_STEM_CHARS
+                    && (word.len() >= MIN_LONG_WORD_CHARS || !LONG_WORD_SUFFIXES.contains(s))
+            })
+        else {
+            break;
+        };
+        word.truncate(word.len() - suffix.len());
+    }
+    word
+}
+
+/// Palabras clave significativas de `text`: palabras latinas de más de tres
+/// letras que no son stopwords, y secuencias CJK contiguas como una frase.
+/// Cada frase CJK aporta también sus tramos de kanji o katakana (lo que
+/// queda entre partículas y okurigana), para que "永遠の暗闇" encuentre
+/// "暗闇を恐れた"; un solo kanji ("闇") ya es una palabra clave.
+pub fn keywords(text: &str) -> Vec<String> {
+    let tokens = tokenize(text);
+    let mut result: Vec<String> = Vec::new();
+    let mut cjk_run: Vec<&Token> = Vec::new();
+
+    let join = |tokens: &[&Token]| -> String { tokens.iter().map(|t| t.text.as_str()).collect() };
+    let flush = |run: &mut Vec<&Token>, result: &mut Vec<String>| {
+        let phrase = join(run);
+        if run.len() >= 2 || phrase.chars().count() >= 2 || phrase.chars().any(is_kanji) {
+            result.push(phrase.clone());
+        }
+        for part in run.split(|t| t.text.chars().all(is_hiragana)).map(join) {
+            if part != phrase && (part.chars().count() >= 2 || part.chars().any(is_kanji)) {
+                result.push(part);
+            }
+        }
+        run.clear();
+    };
+
+    for token in &tokens {
+        if is_cjk(&token.text) {
+            let contiguous = cjk_run.last().map(|t| t.char_end == token.char_start).unwrap_or(true);
+            if !contiguous {
+                flush(&mut cjk_run, &mut result);
+            }
+            cjk_run.push(token);
+            continue;
+        }
+        flush(&mut cjk_run, &mut result);
+
+        if token.folded.chars().count() > 3 && !is_stopword(&token.folded) {
+            result.push(token.folded.clone());
+        }
+    }
+    flush(&mut cjk_run, &mut result);
+
+    result.dedup();
+    result
+}
+
+pub fn is_stopword(folded: &str) -> bool {
+    STOPWORDS.contains(&folded)
+}
+
+fn tokens_match(needle: &Token, token: &Token) -> bool {
+    needle.folded == token.folded
+        || needle.stem == token.stem
+        || same_concept(&needle.stem, &token.stem)
+}
+
+fn same_concept(a: &str, b: &str) -> bool {
+    CONCEPT_FAMILIES.iter()
+        .any(|family| family.contains(&a) && family.contains(&b))
+}
+
+fn is_cjk(word: &str) -> bool {
+    word.chars().any(is_cjk_char)
+}
+
+fn is_kanji(c: char) -> bool {
+    matches!(c as u32, 0x3005 | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF)
+}
+
+fn is_hiragana(c: char) -> bool {
+    matches!(c as u32, 0x3040..=0x309F)
+}
+
+pub(crate) fn is_cjk_char(c: char) -> bool {
+    matches!(c as u32,
+        0x3040..=0x30FF     // Hiragana, Katakana
+        | 0x3400..=0x4DBF   // CJK Ext. A
+        | 0x4E00..=0x9FFF   // CJK unificado
+        | 0xF900..=0xFAFF
+        | 0xFF66..=0xFF9F   // Katakana de ancho medio
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_boundaries() {
+        let text = AnalyzedText::new("Volvió a mirar el horizonte.");
+        assert!(!text.contains("ira"));
+        assert!(AnalyzedText::new("Su ira no tenía fin").contains("ira"));
+    }
+
+    #[test]
+    fn test_accents_stems_and_concepts() {
+        let text = AnalyzedText::new("Sus miedos volvieron. Temía la TRAICION de su hermano.");
+        assert!(text.contains("miedo"));
+        assert_eq!(text.find("miedo").len(), 2);
+        assert!(text.contains("traición"));
+        assert!(text.contains("traicionó"));
+    }
+
+    #[test]
+    fn test_no_false_friends() {
+        for (text, term) in [
+            ("El muro era alto", "muerte"),
+            ("Seguía su código moral", "muerte"),
+            ("La mora estaba madura", "muerte"),
+            ("Vestía de morado", "muerte"),
+            ("El tema era otro", "miedo"),
+            ("Cenaron pavo", "miedo"),
+            ("Tenía la mente clara", "mentira"),
+        ] {
+            assert!(!AnalyzedText::new(text).contains(term), "'{}' no debería contener '{}'", text, term);
+        }
+
+        let text = AnalyzedText::new("Murió sin temor. Sus mentiras lo llenaban de pavor.");
+        assert!(text.contains("muerte"));
+        assert_eq!(text.find("miedo").len(), 2);
+        assert!(text.contains("mentira"));
+    }
+
+    #[test]
+    fn test_char_offsets_in_japanese() {
+        let text = AnalyzedText::new("彼は裏切りを恐れていた。");
+        let found = text.find("恐れ");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].char_start, 6);
+        assert_eq!(text.slice(found[0].char_start, found[0].char_end), "恐れ");
+    }
+
+    #[test]
+    fn test_keywords_group_cjk_runs() {
+        assert_eq!(keywords("El miedo a la traición"), vec!["miedo", "traicion"]);
+        assert_eq!(keywords("見捨てられること"), vec!["見捨てられること", "見捨"]);
+    }
+
+    #[test]
+    fn test_japanese_keywords_reach_inflected_text() {
+        let fires = |term: &str, text: &str| {
+            let text = AnalyzedText::new(text);
+            keywords(term).iter().any(|k| text.contains(k))
+        };
+
+        assert_eq!(keywords("闇"), vec!["闇"]);
+        assert!(fires("闇", "アリアは闇の中を歩いた。"));
+        assert!(fires("永遠の暗闇", "アリアは暗闇を恐れた。"));
+        assert!(fires("独りになること", "アリアは独りになった。"));
+        assert!(!fires("永遠の暗闇", "アリアは光の中を歩いた。"));
+        // Las partículas sueltas no son palabras clave
+        assert!(!keywords("アリアは闇の中を歩いた").iter().any(|k| k == "は" || k == "の"));
+    }
+}
@@ -0,0 +1,309 @@
+//! Bindings wasm-bindgen del motor
+
+use wasm_bindgen::prelude::*;
+
+use crate::forge_error::{self, ForgeError, ForgeErrorKind};
+use crate::i18n::Language;
+use crate::{alma_bridge, json_schema, conflict_forge, destiny_director, destiny_simulator, ensemble_tension, manuscript_auditor, markdown_sheet, narrative_session, nemesis_ledger, prophecy_tracker, selector_profile, sheet_renderer, soul_evolution, soul_parser, story_structure, tension_engine, world_authoring, world_mechanics, world_seed};
+
+/// Inicialización del Motor SoulForge
+#[wasm_bindgen(start)]
+pub fn ignite_forge() {
+    #[cfg(feature = "console_error_panic_hook")]
+    console_error_panic_hook::set_once();
+    
+    web_sys::console::log_1(&"⚔️ SoulForge Engine Ignited".into());
+}
+
+/// API Principal - Forja completa desde HTML
+#[wasm_bindgen]
+pub fn forge_soul_from_html(html_content: &str) -> Result<JsValue, JsValue> {
+    let soul = soul_parser::parse_living_sheet(html_content);
+    to_js(Ok(soul))
+}
+
+/// Forja desde una ficha Markdown con front matter YAML/TOML
+#[wasm_bindgen]
+pub fn forge_soul_from_markdown(markdown_content: &str) -> Result<JsValue, JsValue> {
+    let soul = markdown_sheet::parse_markdown_sheet(markdown_content);
+    to_js(Ok(soul))
+}
+
+/// Forja desde HTML o Markdown, detectando el formato por el contenido
+#[wasm_bindgen]
+pub fn forge_soul_from_sheet(sheet_content: &str) -> Result<JsValue, JsValue> {
+    let soul = markdown_sheet::parse_soul_sheet(sheet_content);
+    to_js(Ok(soul))
+}
+
+/// Forja con perfiles de selectores propios (`profiles_json`: un perfil o una lista).
+/// Se elige el perfil que más campos llena, incluido el incorporado.
+#[wasm_bindgen]
+pub fn forge_soul_with_profiles(html_content: &str, profiles_json: &str) -> Result<JsValue, JsValue> {
+    let profiles = selector_profile::load_selector_profiles(profiles_json)
+        .map_err(first_error)?;
+    let result = selector_profile::parse_living_sheet_auto(html_content, &profiles);
+    to_js(Ok(result))
+}
+
+/// Diagnóstico de una ficha: selector que llenó cada campo y campos por defecto
+#[wasm_bindgen]
+pub fn diagnose_sheet_html(html_content: &str) -> Result<JsValue, JsValue> {
+    let report = soul_parser::diagnose_living_sheet(html_content);
+    to_js(Ok(report))
+}
+
+/// HTML canónico de un alma (lo inverso de `forge_soul_from_html`)
+#[wasm_bindgen]
+pub fn render_soul_html(soul_json: &str) -> Result<String, JsValue> {
+    sheet_renderer::try_render_soul_sheet(soul_json).map_err(js_error)
+}
+
+/// Forja directa desde el JSON de un Alma del servidor
+#[wasm_bindgen]
+pub fn forge_soul_from_alma(alma_json: &str) -> Result<JsValue, JsValue> {
+    to_js(alma_bridge::soul_from_alma_json(alma_json).map_err(|e| ForgeError::from_json("alma", &e)))
+}
+
+/// Análisis de tensión en tiempo real
+#[wasm_bindgen]
+pub fn analyze_narrative_tension(soul_json: &str, narrative_text: &str, lang: Option<String>) -> Result<JsValue, JsValue> {
+    to_js(tension_engine::try_calculate_tension(soul_json, narrative_text, language(lang)?))
+}
+
+/// Tensión de una escena coral. `souls_json` es una lista de almas (o una sola).
+#[wasm_bindgen]
+pub fn analyze_ensemble_tension(souls_json: &str, narrative_text: &str, lang: Option<String>) -> Result<JsValue, JsValue> {
+    to_js(ensemble_tension::try_calculate_ensemble_tension(souls_json, narrative_text, language(lang)?))
+}
+
+/// Curva de tensión por capítulo frente a estructuras objetivo.
+/// `structures` es una lista separada por comas ("three-act,freytag"); vacía = todas.
+#[wasm_bindgen]
+pub fn analyze_tension_curve(soul_json: &str, manuscript: &str, structures: &str, lang: Option<String>) -> Result<JsValue, JsValue> {
+    let targets: Vec<story_structure::StoryStructure> = structures
+        .split(',')
+        .filter_map(story_structure::StoryStructure::parse)
+        .collect();
+    to_js(story_structure::try_calculate_tension_curve(soul_json, manuscript, &targets, language(lang)?))
+}
+
+/// Auditoría de coherencia
+#[wasm_bindgen]
+pub fn audit_coherence(soul_json: &str, new_content: &str, lang: Option<String>) -> Result<JsValue, JsValue> {
+    to_js(destiny_director::try_check_violations(soul_json, new_content, language(lang)?))
+}
+
+/// Continuidad del manuscrito completo: muertes, partidas, lugares y fechas.
+/// `souls_json` es un alma o una lista de almas.
+#[wasm_bindgen]
+pub fn audit_manuscript_continuity(souls_json: &str, manuscript: &str, lang: Option<String>) -> Result<JsValue, JsValue> {
+    to_js(manuscript_auditor::try_audit_manuscript(souls_json, manuscript, language(lang)?))
+}
+
+/// Progreso de las profecías capítulo a capítulo: evidencias, cumplimientos
+/// y contradicciones con profecías ya cumplidas
+#[wasm_bindgen]
+pub fn track_prophecy_progress(souls_json: &str, manuscript: &str, lang: Option<String>) -> Result<JsValue, JsValue> {
+    to_js(prophecy_tracker::try_track_prophecies(souls_json, manuscript, language(lang)?))
+}
+
+/// Encuentros con la némesis capítulo a capítulo: desenlace, ventaja,
+/// amenaza resultante y avisos de ausencia
+#[wasm_bindgen]
+pub fn track_nemesis_encounters(souls_json: &str, manuscript: &str, lang: Option<String>) -> Result<JsValue, JsValue> {
+    to_js(nemesis_ledger::try_track_nemeses(souls_json, manuscript, language(lang)?))
+}
+
+/// Generación de puntos de inflexión
+#[wasm_bindgen]
+pub fn generate_inflection_points(soul_json: &str, lang: Option<String>) -> Result<JsValue, JsValue> {
+    to_js(conflict_forge::try_create_inflection_points(soul_json, language(lang)?))
+}
+
+/// Aplica el desenlace elegido de un punto de inflexión y devuelve el alma evolucionada
+#[wasm_bindgen]
+pub fn evolve_soul(soul_json: &str, point_json: &str, outcome_index: usize, chapter: u32, lang: Option<String>) -> Result<JsValue, JsValue> {
+    to_js(soul_evolution::try_apply_inflection_outcome(soul_json, point_json, outcome_index, chapter, language(lang)?))
+}
+
+/// Monte Carlo de destinos: `runs` historias de `rounds` puntos de inflexión
+#[wasm_bindgen]
+pub fn simulate_destinies(soul_json: &str, runs: u32, rounds: u32, seed: u64, lang: Option<String>) -> Result<JsValue, JsValue> {
+    to_js(destiny_simulator::try_simulate_destiny(soul_json, runs, rounds, seed, language(lang)?))
+}
+
+/// Carga y valida un mundo de autoría. `format` es "json" o "toml".
+/// Devuelve `{ world, errors }`: `world` es null si hay errores.
+#[wasm_bindgen]
+pub fn load_world_file(content: &str, format: &str) -> Result<JsValue, JsValue> {
+    #[derive(serde::Serialize)]
+    struct WorldLoad {
+        world: Option<world_seed::WorldSeed>,
+        errors: Vec<world_authoring::WorldSeedError>,
+    }
+
+    let result = match world_authoring::WorldFormat::from_extension(format) {
+        Some(format) => world_authoring::load_world_seed(content, format),
+        None => Err(vec![world_authoring::WorldSeedError {
+            path: String::new(),
+            message: format!("formato de mundo no soportado '{}'", format),
+        }]),
+    };
+    let load = match result {
+        Ok(world) => WorldLoad { world: Some(world), errors: vec![] },
+        Err(errors) => WorldLoad { world: None, errors },
+    };
+    to_js(Ok(load))
+}
+
+/// JSON Schema de `WorldSeed` (como texto, listo para el validador del editor)
+#[wasm_bindgen]
+pub fn world_seed_json_schema() -> String {
+    world_authoring::world_seed_schema().to_string()
+}
+
+/// JSON Schema por nombre: "soul", "world", "coherence" o "tension"
+#[wasm_bindgen]
+pub fn engine_json_schema(name: &str) -> Option<String> {
+    json_schema::engine_schema(name).map(|schema| schema.to_string())
+}
+
+/// Leyes del mundo aplicadas como números sobre los atributos del alma
+#[wasm_bindgen]
+pub fn world_attributes_for_soul(soul_json: &str, world_json: &str) -> Result<JsValue, JsValue> {
+    to_js(world_mechanics::try_resolve_world_attributes(soul_json, world_json))
+}
+
+/// Afinidad con las facciones tras recorrer la crónica del alma
+#[wasm_bindgen]
+pub fn faction_standing_for_soul(soul_json: &str, world_json: &str) -> Result<JsValue, JsValue> {
+    to_js(world_mechanics::try_track_faction_standing(soul_json, world_json))
+}
+
+/// Sesión incremental para editores en vivo: almas y mundos deserializados una
+/// sola vez, texto editado por rangos (índices UTF-16 de JS) y diffs de hallazgos.
+/// Los métodos que cambian la sesión devuelven el `SessionDiff`; un JSON inválido lanza `ForgeError`.
+#[wasm_bindgen(js_name = NarrativeSession)]
+pub struct WasmNarrativeSession {
+    inner: narrative_session::NarrativeSession,
+}
+
+#[wasm_bindgen(js_class = NarrativeSession)]
+impl WasmNarrativeSession {
+    #[wasm_bindgen(constructor)]
+    pub fn new(lang: Option<String>) -> Result<WasmNarrativeSession, JsValue> {
+        Ok(Self { inner: narrative_session::NarrativeSession::new(language(lang)?) })
+    }
+
+    /// Cambia el idioma de los hallazgos y reanaliza el texto
+    pub fn set_language(&mut self, lang: &str) -> Result<JsValue, JsValue> {
+        let lang = language(Some(lang.to_string()))?;
+        session_diff(self.inner.set_language(lang))
+    }
+
+    /// Añade un alma desde el JSON de un `LivingSoul`
+    pub fn add_soul(&mut self, soul_json: &str) -> Result<JsValue, JsValue> {
+        let soul = forge_error::parse_json("soul", soul_json).map_err(js_error)?;
+        session_diff(self.inner.add_soul(soul))
+    }
+
+    /// Añade un alma desde una ficha HTML o Markdown
+    pub fn add_sheet(&mut self, sheet_content: &str) -> Result<JsValue, JsValue> {
+        let soul = markdown_sheet::parse_soul_sheet(sheet_content);
+        session_diff(self.inner.add_soul(soul))
+    }
+
+    pub fn remove_soul(&mut self, name: &str) -> Result<JsValue, JsValue> {
+        session_diff(self.inner.remove_soul(name))
+    }
+
+    /// Añade un mundo desde el JSON de un `WorldSeed`
+    pub fn add_world(&mut self, world_json: &str) -> Result<JsValue, JsValue> {
+        let world = world_authoring::world_seed_from_json(world_json)
+                .map_err(first_error)?;
+        session_diff(self.inner.add_world(world))
+    }
+
+    pub fn remove_world(&mut self, id: &str) -> Result<JsValue, JsValue> {
+        session_diff(self.inner.remove_world(id))
+    }
+
+    pub fn set_text(&mut self, text: &str) -> Result<JsValue, JsValue> {
+        session_diff(self.inner.set_text(text))
+    }
+
+    /// Sustituye `[start, end)` por `replacement`; los índices son los de
+    /// JavaScript (unidades UTF-16)
+    pub fn edit(&mut self, start: usize, end: usize, replacement: &str) -> Result<JsValue, JsValue> {
+        session_diff(self.inner.edit_utf16(start, end, replacement))
+    }
+
+    pub fn text(&self) -> String {
+        self.inner.text().to_string()
+    }
+
+    pub fn findings(&self) -> Result<JsValue, JsValue> {
+        to_js(Ok(self.inner.findings()))
+    }
+
+    /// Un `TensionAnalysis` por alma, sin volver a recorrer el texto
+    pub fn tension(&self) -> Result<JsValue, JsValue> {
+        to_js(Ok(self.inner.tension()))
+    }
+
+    /// Un `CoherenceReport` por alma, sin volver a recorrer el texto
+    pub fn coherence(&self) -> Result<JsValue, JsValue> {
+        to_js(Ok(self.inner.coherence()))
+    }
+}
+
+fn session_diff(diff: narrative_session::SessionDiff) -> Result<JsValue, JsValue> {
+    to_js(Ok(diff))
+}
+
+// ============================================================
+// ERRORES
+// ============================================================
+
+/// Código de idioma opcional ("es", "en", "jp"/"ja"); sin código = español
+fn language(code: Option<String>) -> Result<Language, JsValue> {
+    match code.as_deref().map(str::trim).filter(|code| !code.is_empty()) {
+        None => Ok(Language::default()),
+        Some(code) => Language::parse(code)
+            .ok_or_else(|| js_error(ForgeError::new(ForgeErrorKind::Invalid, "lang", "", format!("idioma no soportado '{}'", code)))),
+    }
+}
+
+/// Resultado del motor -> valor JS, o `ForgeError` lanzado como excepción.
+/// Si el resultado no se puede pasar a JS también es un `ForgeError`, nunca null
+fn to_js<T: serde::Serialize>(result: Result<T, ForgeError>) -> Result<JsValue, JsValue> {
+    let value = result.map_err(js_error)?;
+    serde_wasm_bindgen::to_value(&value)
+        .map_err(|error| js_error(ForgeError::new(ForgeErrorKind::Invalid, "output", "", error.to_string())))
+}
+
+/// Primer error de una validación como `ForgeError`; sin errores que
+/// contar, un array vacío en lugar de null
+fn first_error<E: Into<ForgeError>>(errors: Vec<E>) -> JsValue {
+    errors.into_iter().next()
+        .map(|error| js_error(error.into()))
+        .unwrap_or_else(|| js_sys::Array::new().into())
+}
+
+/// `ForgeError` como `Error` de JS (`name` = "ForgeError") con `kind`,
+/// `input`, `path` y `reason` como propiedades
+fn js_error(error: ForgeError) -> JsValue {
+    let js = js_sys::Error::new(&error.to_string());
+    js.set_name("ForgeError");
+    let fields = [
+        ("kind", format!("{:?}", error.kind)),
+        ("input", error.input),
+        ("path", error.path),
+        ("reason", error.message),
+    ];
+    for (key, value) in fields {
+        let _ = js_sys::Reflect::set(&js, &JsValue::from_str(key), &JsValue::from_str(&value));
+    }
+    js.into()
+}
@@ -0,0 +1,408 @@
+//! Autoría de mundos: `WorldSeed` desde JSON o TOML con validación completa
+//!
+//! `parse_world_seed` lee HTML y rellena con valores por defecto lo que falte.
+//! Aquí el archivo es la fuente de verdad: la estructura se deserializa con la
+//! ruta exacta del campo que falla y después se comprueban las reglas que el
+//! tipo no puede expresar (ids repetidos, stats desconocidos, facciones
+//! contradictorias, rangos). `world_seed_schema` publica el mismo contrato
+//! como JSON Schema para los editores.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt;
+use crate::text_analysis::fold;
+use crate::world_seed::*;
+
+/// Stats que una ley puede bonificar o penalizar: nombre canónico y alias
+pub const WORLD_STATS: &[(&str, &[&str])] = &[
+    ("fuerza", &["strength"]),
+    ("agilidad", &["agility", "destreza", "dexterity"]),
+    ("resistencia", &["endurance", "constitucion", "constitution"]),
+    ("intelecto", &["intellect", "inteligencia", "intelligence"]),
+    ("voluntad", &["willpower", "will"]),
+    ("carisma", &["charisma"]),
+    ("percepcion", &["perception"]),
+    ("magia", &["magic", "poder", "power"]),
+    ("sigilo", &["stealth"]),
+    ("suerte", &["luck"]),
+];
+
+/// Ritmos narrativos admitidos en `narrative_tone.pace`
+pub const NARRATIVE_PACES: &[&str] = &["slow", "medium", "fast", "chaotic"];
+
+/// Formato de origen de un mundo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorldFormat {
+    Json,
+    Toml,
+}
+
+impl WorldFormat {
+    /// Formato a partir de la extensión del archivo ("json", "toml")
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_lowercase().as_str() {
+            "json" => Some(WorldFormat::Json),
+            "toml" => Some(WorldFormat::Toml),
+            _ => None,
+        }
+    }
+}
+
+/// Error de autoría con la ruta del campo ("world_laws[2].effect.Buff.stat").
+/// La ruta vacía señala el documento completo.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorldSeedError {
+    pub path: String,
+    pub message: String,
+}
+
+impl WorldSeedError {
+    fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { path: path.into(), message: message.into() }
+    }
+}
+
+impl fmt::Display for WorldSeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl std::error::Error for WorldSeedError {}
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Carga y valida un mundo. Devuelve todos los errores encontrados, no solo el primero
+/// (salvo los de sintaxis y estructura, que impiden seguir).
+pub fn load_world_seed(content: &str, format: WorldFormat) -> Result<WorldSeed, Vec<WorldSeedError>> {
+    let document = match format {
+        WorldFormat::Json => serde_json::from_str::<Value>(content)
+            .map_err(|e| WorldSeedError::new("", format!("JSON inválido: {}", e))),
+        WorldFormat::Toml => toml::from_str::<Value>(content)
+            .map_err(|e| WorldSeedError::new("", format!("TOML inválido: {}", e.message()))),
+    }
+    .map_err(|e| vec![e])?;
+
+    let world: WorldSeed = serde_path_to_error::deserialize(document).map_err(|e| {
+        let path = e.path().to_string();
+        let path = if path == "." { String::new() } else { path };
+        vec![WorldSeedError::new(path, e.into_inner().to_string())]
+    })?;
+
+    let errors = validate_world_seed(&world);
+    if errors.is_empty() {
+        Ok(world)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Atajo para `load_world_seed` con JSON
+pub fn world_seed_from_json(json: &str) -> Result<WorldSeed, Vec<WorldSeedError>> {
+    load_world_seed(json, WorldFormat::Json)
+}
+
+/// Atajo para `load_world_seed` con TOML
+pub fn world_seed_from_toml(toml: &str) -> Result<WorldSeed, Vec<WorldSeedError>> {
+    load_world_seed(toml, WorldFormat::Toml)
+}
+
+/// Reglas semánticas de un mundo ya estructurado
+pub fn validate_world_seed(world: &WorldSeed) -> Vec<WorldSeedError> {
+    let mut errors = Vec::new();
+
+    // === LEYES ===
+    let mut seen_ids: HashMap<&str, usize> = HashMap::new();
+    for (idx, law) in world.world_laws.iter().enumerate() {
+        if law.id.trim().is_empty() {
+            errors.push(WorldSeedError::new(format!("world_laws[{}].id", idx), "el id no puede estar vacío"));
+        } else if let Some(first) = seen_ids.insert(law.id.as_str(), idx) {
+            seen_ids.insert(law.id.as_str(), first);
+            errors.push(WorldSeedError::new(
+                format!("world_laws[{}].id", idx),
+                format!("id '{}' duplicado (ya usado en world_laws[{}])", law.id, first),
+            ));
+        }
+
+        let effect_path = format!("world_laws[{}].effect", idx);
+        match &law.effect {
+            LawEffect::Buff { stat, modifier } | LawEffect::Debuff { stat, modifier } => {
+                let variant = if matches!(law.effect, LawEffect::Buff { .. }) { "Buff" } else { "Debuff" };
+                if canonical_stat(stat).is_none() {
+                    errors.push(WorldSeedError::new(
+                        format!("{}.{}.stat", effect_path, variant),
+                        format!("stat desconocido '{}' (válidos: {})", stat, stat_names().join(", ")),
+                    ));
+                }
+                if !modifier.is_finite() || *modifier < 0.0 {
+                    errors.push(WorldSeedError::new(
+                        format!("{}.{}.modifier", effect_path, variant),
+                        format!("el modificador debe ser un número positivo (recibido {})", modifier),
+                    ));
+                }
+            },
+            _ => {},
+        }
+    }
+
+    // === FACCIONES ===
+    for (idx, faction) in world.factions.iter().enumerate() {
+        for (reject_idx, rejected) in faction.rejects_archetypes.iter().enumerate() {
+            let folded = fold(rejected.trim());
+            if faction.accepts_archetypes.iter().any(|a| fold(a.trim()) == folded) {
+                errors.push(WorldSeedError::new(
+                    format!("factions[{}].rejects_archetypes[{}]", idx, reject_idx),
+                    format!("'{}' acepta y rechaza a la vez el arquetipo '{}'", faction.name, rejected),
+                ));
+            }
+        }
+    }
+
+    // === RANGOS ===
+    let ranges = [
+        ("environmental_rules.danger_level", world.environmental_rules.danger_level),
+        ("narrative_tone.darkness", world.narrative_tone.darkness),
+        ("narrative_tone.hope", world.narrative_tone.hope),
+        ("narrative_tone.realism", world.narrative_tone.realism),
+    ];
+    for (path, value) in ranges {
+        if !(0.0..=1.0).contains(&value) {
+            errors.push(WorldSeedError::new(path, format!("debe estar entre 0.0 y 1.0 (recibido {})", value)));
+        }
+    }
+
+    if !NARRATIVE_PACES.contains(&world.narrative_tone.pace.as_str()) {
+        errors.push(WorldSeedError::new(
+            "narrative_tone.pace",
+            format!("ritmo '{}' desconocido (válidos: {})", world.narrative_tone.pace, NARRATIVE_PACES.join(", ")),
+        ));
+    }
+
+    errors
+}
+
+/// Nombre canónico de un stat, aceptando alias y sin importar mayúsculas ni acentos
+pub fn canonical_stat(name: &str) -> Option<&'static str> {
+    let folded = fold(name.trim());
+    WORLD_STATS.iter()
+        .find(|(canonical, aliases)| *canonical == folded || aliases.contains(&folded.as_str()))
+        .map(|(canonical, _)| *canonical)
+}
+
+fn stat_names() -> Vec<&'static str> {
+    WORLD_STATS.iter().map(|(canonical, _)| *canonical).collect()
+}
+
+// ============================================================
+// JSON SCHEMA
+// ============================================================
+
+/// JSON Schema (draft 2020-12) de un `WorldSeed` de autoría.
+/// Es el mismo contrato que aplica `load_world_seed`, salvo las reglas entre
+/// campos (ids únicos, facciones contradictorias) que un esquema no expresa.
+pub fn world_seed_schema() -> Value {
+    let stats: Vec<&str> = WORLD_STATS.iter()
+        .flat_map(|(canonical, aliases)| std::iter::once(*canonical).chain(aliases.iter().copied()))
+        .collect();
+    let unit = json!({ "type": "number", "minimum": 0.0, "maximum": 1.0 });
+    let strings = json!({ "type": "array", "items": { "type": "string" } });
+    let stat_effect = json!({
+        "type": "object",
+        "required": ["stat", "modifier"],
+        "properties": {
+            "stat": { "type": "string", "enum": stats },
+            "modifier": { "type": "number", "minimum": 0.0 }
+        }
+    });
+    let variant = |name: &str, body: Value| json!({
+        "type": "object",
+        "required": [name],
+        "properties": { name: body },
+        "additionalProperties": false
+    });
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "WorldSeed",
+        "description": "Mundo Semilla de SoulForge: leyes, facciones, conflictos y tono",
+        "type": "object",
+        "required": ["id", "name", "world_laws", "factions", "environmental_rules", "narrative_tone"],
+        "properties": {
+            "id": { "type": "string", "minLength": 1 },
+            "name": { "type": "string" },
+            "creator": { "type": "string" },
+            "world_laws": { "type": "array", "items": { "$ref": "#/$defs/WorldLaw" } },
+            "factions": { "type": "array", "items": { "$ref": "#/$defs/Faction" } },
+            "active_conflicts": { "type": "array", "items": { "$ref": "#/$defs/GlobalConflict" } },
+            "environmental_rules": { "$ref": "#/$defs/EnvironmentalRules" },
+            "narrative_tone": { "$ref": "#/$defs/NarrativeTone" }
+        },
+        "$defs": {
+            "WorldLaw": {
+                "type": "object",
+                "required": ["id", "name", "description", "affects_archetypes", "effect"],
+                "properties": {
+                    "id": { "type": "string", "minLength": 1 },
+                    "name": { "type": "string" },
+                    "description": { "type": "string" },
+                    "affects_archetypes": strings,
+                    "effect": { "$ref": "#/$defs/LawEffect" }
+                }
+            },
+            "LawEffect": {
+                "oneOf": [
+                    variant("Buff", stat_effect.clone()),
+                    variant("Debuff", stat_effect),
+                    variant("Restriction", json!({
+                        "type": "object",
+                        "required": ["action"],
+                        "properties": { "action": { "type": "string" } }
+                    })),
+                    variant("Enhancement", json!({
+                        "type": "object",
+                        "required": ["ability"],
+                        "properties": { "ability": { "type": "string" } }
+                    })),
+                    variant("SocialModifier", json!({
+                        "type": "object",
+                        "required": ["reputation_change"],
+                        "properties": { "reputation_change": { "type": "integer" } }
+                    }))
+                ]
+            },
+            "Faction": {
+                "type": "object",
+                "required": ["name", "alignment", "accepts_archetypes", "rejects_archetypes"],
+                "properties": {
+                    "name": { "type": "string" },
+                    "alignment": { "type": "string" },
+                    "accepts_archetypes": strings,
+                    "rejects_archetypes": strings,
+                    "benefits": strings,
+                    "obligations": strings
+                }
+            },
+            "GlobalConflict": {
+                "type": "object",
+                "required": ["name", "sides", "stakes", "how_souls_are_affected"],
+                "properties": {
+                    "name": { "type": "string" },
+                    "sides": strings,
+                    "stakes": { "type": "string" },
+                    "how_souls_are_affected": { "type": "string" }
+                }
+            },
+            "EnvironmentalRules": {
+                "type": "object",
+                "required": ["magic_level", "technology_level", "danger_level"],
+                "properties": {
+                    "magic_level": { "enum": ["None", "Low", "Medium", "High", "Mythical"] },
+                    "technology_level": {
+                        "enum": ["Primitive", "Medieval", "Renaissance", "Industrial", "Modern", "Futuristic", "Mixed"]
+                    },
+                    "danger_level": unit,
+                    "special_conditions": strings
+                }
+            },
+            "NarrativeTone": {
+                "type": "object",
+                "required": ["darkness", "hope", "realism", "pace"],
+                "properties": {
+                    "darkness": unit,
+                    "hope": unit,
+                    "realism": unit,
+                    "pace": { "enum": NARRATIVE_PACES }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOML_WORLD: &str = r#"
+id = "world_ceniza"
+name = "Reino de Ceniza"
+
+[[world_laws]]
+id = "sangre_antigua"
+name = "Sangre Antigua"
+description = "Los linajes viejos resisten el fuego"
+affects_archetypes = ["Guerrero"]
+effect = { Buff = { stat = "Strength", modifier = 0.2 } }
+
+[[world_laws]]
+id = "sangre_antigua"
+name = "Eco"
+description = "Duplicada"
+affects_archetypes = ["*"]
+effect = { Debuff = { stat = "cocina", modifier = 0.1 } }
+
+[[factions]]
+name = "Orden del Alba"
+alignment = "luz"
+accepts_archetypes = ["Guerrero", "Sanador"]
+rejects_archetypes = ["sanador"]
+
+[environmental_rules]
+magic_level = "High"
+technology_level = "Medieval"
+danger_level = 1.4
+
+[narrative_tone]
+darkness = 0.8
+hope = 0.3
+realism = 0.5
+pace = "fast"
+"#;
+
+    #[test]
+    fn toml_world_reports_every_rule_with_its_path() {
+        let errors = world_seed_from_toml(TOML_WORLD).unwrap_err();
+        let paths: Vec<&str> = errors.iter().map(|e| e.path.as_str()).collect();
+
+        assert_eq!(paths, vec![
+            "world_laws[1].id",
+            "world_laws[1].effect.Debuff.stat",
+            "factions[0].rejects_archetypes[0]",
+            "environmental_rules.danger_level",
+        ]);
+
+        // El alias "Strength" es un stat válido
+        assert_eq!(canonical_stat("Strength"), Some("fuerza"));
+
+        let fixed = TOML_WORLD
+            .replacen("id = \"sangre_antigua\"\nname = \"Eco\"", "id = \"eco\"\nname = \"Eco\"", 1)
+            .replace("\"cocina\"", "\"magia\"")
+            .replace("rejects_archetypes = [\"sanador\"]", "rejects_archetypes = [\"Nigromante\"]")
+            .replace("danger_level = 1.4", "danger_level = 0.9");
+        let world = world_seed_from_toml(&fixed).unwrap();
+        assert_eq!(world.world_laws.len(), 2);
+        assert!(world.active_conflicts.is_empty());
+    }
+
+    #[test]
+    fn structural_errors_carry_the_field_path() {
+        let json = r#"{
+            "id": "w", "name": "W", "world_laws": [], "factions": [],
+            "environmental_rules": { "magic_level": "Cosmic", "technology_level": "Modern", "danger_level": 0.2 },
+            "narrative_tone": { "darkness": 0.1, "hope": 0.9, "realism": 0.5, "pace": "slow" }
+        }"#;
+        let errors = world_seed_from_json(json).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, "environmental_rules.magic_level");
+
+        // El esquema publicado es el que genera el motor
+        let published: Value = serde_json::from_str(include_str!("../schemas/world_seed.schema.json")).unwrap();
+        assert_eq!(published, world_seed_schema());
+    }
+}
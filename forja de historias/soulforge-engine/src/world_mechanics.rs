@@ -0,0 +1,448 @@
+//! Mecánica de mundo: leyes convertidas en números y afinidad con facciones
+//! que evoluciona con la crónica
+//!
+//! `integrate_soul_into_world` describe en prosa lo que el mundo hace al alma.
+//! Aquí las mismas leyes se aplican sobre un mapa numérico de atributos, y la
+//! afinidad de partida con cada facción se mueve con cada `ChronicleEntry`
+//! (traiciones, alianzas, deshonras, actos heroicos).
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use crate::destiny_director::{CoherenceViolation, ViolationRationale, ViolationSeverity, ViolationType};
+use crate::forge_error::{parse_json, ForgeError};
+use crate::i18n::{tr, Language, TemplateArg};
+use crate::modality::{most_assertive, most_assertive_of};
+use crate::soul_parser::*;
+use crate::text_analysis::AnalyzedText;
+use crate::world_authoring::{canonical_stat, WORLD_STATS};
+use crate::world_seed::*;
+
+/// Valor de partida de cada stat cuando no se indica otro
+pub const BASE_STAT: f32 = 10.0;
+
+/// Multiplicador de una `Enhancement` cuya habilidad es un stat
+const ENHANCEMENT_BONUS: f32 = 0.25;
+
+/// Límite de la afinidad con una facción (en ambos sentidos)
+pub const MAX_STANDING: i32 = 100;
+
+const BETRAYAL_TERMS: &[&str] = &[
+    "traición", "traicionó", "traiciona", "traicionar", "desertó", "deserta",
+    "betrayal", "betrayed", "betrays", "deserted",
+];
+const ALLIANCE_TERMS: &[&str] = &[
+    "alianza", "aliado", "aliada", "pacto", "juramento de lealtad",
+    "alliance", "allied", "ally", "pact",
+];
+const DISGRACE_TERMS: &[&str] = &[
+    "deshonra", "deshonrado", "escándalo", "humillación", "humillado", "reputación dañada",
+    "disgrace", "disgraced", "scandal", "dishonor",
+];
+const HEROISM_TERMS: &[&str] = &[
+    "salvó", "salva", "rescató", "rescata", "héroe", "heroína", "hazaña",
+    "rescued", "saved", "hero", "heroic",
+];
+
+/// Atributos numéricos del alma dentro de un mundo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldAttributes {
+    pub soul_id: String,
+    pub world_id: String,
+    pub attributes: BTreeMap<String, f32>,   // Stat canónico -> valor final
+    pub reputation: i32,                     // Suma de los SocialModifier
+    pub restricted_actions: Vec<String>,
+    pub enhanced_abilities: Vec<String>,
+    pub applied_laws: Vec<AppliedLaw>,
+}
+
+/// Huella numérica de una ley aplicada
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppliedLaw {
+    pub law_id: String,
+    pub target: String,      // Stat, acción, habilidad o "reputacion"
+    pub delta: f32,          // Cambio aplicado (0 para restricciones)
+}
+
+/// Tipo de suceso que mueve la afinidad con una facción
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StandingEvent {
+    Betrayal,
+    Alliance,
+    Disgrace,
+    Heroism,
+}
+
+impl StandingEvent {
+    /// Cambio de afinidad con la facción directamente implicada
+    pub fn delta(self) -> i32 {
+        match self {
+            StandingEvent::Betrayal => -30,
+            StandingEvent::Alliance => 25,
+            StandingEvent::Disgrace => -15,
+            StandingEvent::Heroism => 15,
+        }
+    }
+
+    fn terms(self) -> &'static [&'static str] {
+        match self {
+            StandingEvent::Betrayal => BETRAYAL_TERMS,
+            StandingEvent::Alliance => ALLIANCE_TERMS,
+            StandingEvent::Disgrace => DISGRACE_TERMS,
+            StandingEvent::Heroism => HEROISM_TERMS,
+        }
+    }
+
+    const ALL: [StandingEvent; 4] = [
+        StandingEvent::Betrayal,
+        StandingEvent::Alliance,
+        StandingEvent::Disgrace,
+        StandingEvent::Heroism,
+    ];
+}
+
+/// Cómo llegó el suceso a la facción
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StandingReach {
+    Direct,     // La facción aparece en la entrada: cambio completo
+    Rival,      // Está en el bando contrario de un conflicto: mitad, en sentido opuesto
+    Rumor,      // Ninguna facción nombrada: a todas les llega la mitad
+}
+
+/// Un movimiento de afinidad registrado
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StandingChange {
+    pub chapter: u32,
+    pub faction: String,
+    pub event: StandingEvent,
+    pub reach: StandingReach,
+    pub delta: i32,
+    pub standing_after: i32,
+    pub matched_text: String,
+}
+
+/// Afinidad viva de un alma con las facciones de un mundo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionStanding {
+    pub soul_id: String,
+    pub world_id: String,
+    pub standings: BTreeMap<String, i32>,
+    pub history: Vec<StandingChange>,
+}
+
+// ============================================================
+// API PRINCIPAL
+// ============================================================
+
+/// Atributos desde JSON (alma y mundo serializados)
+pub fn resolve_world_attributes(soul_json: &str, world_json: &str) -> Option<WorldAttributes> {
+    try_resolve_world_attributes(soul_json, world_json).ok()
+}
+
+/// Como `resolve_world_attributes`, con el motivo si algún JSON no es válido
+pub fn try_resolve_world_attributes(soul_json: &str, world_json: &str) -> Result<WorldAttributes, ForgeError> {
+    let soul: LivingSoul = parse_json("soul", soul_json)?;
+    let world: WorldSeed = parse_json("world", world_json)?;
+    Ok(resolve_law_effects(&soul, &world, &base_attributes()))
+}
+
+/// Afinidad desde JSON, aplicando la crónica que ya trae el alma
+pub fn track_faction_standing(soul_json: &str, world_json: &str) -> Option<FactionStanding> {
+    try_track_faction_standing(soul_json, world_json).ok()
+}
+
+/// Como `track_faction_standing`, con el motivo si algún JSON no es válido
+pub fn try_track_faction_standing(soul_json: &str, world_json: &str) -> Result<FactionStanding, ForgeError> {
+    let soul: LivingSoul = parse_json("soul", soul_json)?;
+    let world: WorldSeed = parse_json("world", world_json)?;
+    let mut standing = FactionStanding::new(&soul, &world);
+    standing.apply_chronicle(&world, &soul.chronicle);
+    Ok(standing)
+}
+
+/// Todos los stats canónicos a `BASE_STAT`
+pub fn base_attributes() -> BTreeMap<String, f32> {
+    WORLD_STATS.iter()
+        .map(|(stat, _)| (stat.to_string(), BASE_STAT))
+        .collect()
+}
+
+/// Aplica, en orden, las leyes que alcanzan al arquetipo del alma sobre `base`.
+/// Buff/Debuff multiplican el stat, Enhancement de un stat lo potencia y
+/// SocialModifier se acumula en `reputation`. Los nombres de stat se canonizan.
+pub fn resolve_law_effects(soul: &LivingSoul, world: &WorldSeed, base: &BTreeMap<String, f32>) -> WorldAttributes {
+    let archetype = AnalyzedText::new(&soul.archetype);
+    let mut attributes: BTreeMap<String, f32> = base.iter()
+        .map(|(stat, value)| (canonical_stat(stat).map(str::to_string).unwrap_or_else(|| stat.clone()), *value))
+        .collect();
+    let mut reputation = 0i32;
+    let mut restricted_actions = Vec::new();
+    let mut enhanced_abilities = Vec::new();
+    let mut applied_laws = Vec::new();
+
+    for law in world.world_laws.iter().filter(|law| law_applies(law, &archetype)) {
+        let (target, delta) = match &law.effect {
+            LawEffect::Buff { stat, modifier } => {
+                scale_stat(&mut attributes, stat, 1.0 + modifier)
+            },
+            LawEffect::Debuff { stat, modifier } => {
+                scale_stat(&mut attributes, stat, (1.0 - modifier).max(0.0))
+            },
+            LawEffect::Restriction { action } => {
+                restricted_actions.push(action.clone());
+                (action.clone(), 0.0)
+            },
+            LawEffect::Enhancement { ability } => {
+                enhanced_abilities.push(ability.clone());
+                if canonical_stat(ability).is_some() {
+                    scale_stat(&mut attributes, ability, 1.0 + ENHANCEMENT_BONUS)
+                } else {
+                    (ability.clone(), 0.0)
+                }
+            },
+            LawEffect::SocialModifier { reputation_change } => {
+                reputation += reputation_change;
+                ("reputacion".to_string(), *reputation_change as f32)
+            },
+        };
+
+        applied_laws.push(AppliedLaw { law_id: law.id.clone(), target, delta });
+    }
+
+    WorldAttributes {
+        soul_id: soul.name.clone(),
+        world_id: world.id.clone(),
+        attributes,
+        reputation,
+        restricted_actions,
+        enhanced_abilities,
+        applied_laws,
+    }
+}
+
+/// Pasajes donde el alma hace, de forma afirmativa, una acción que una
+/// `Restriction` del mundo le prohíbe. La severidad se atenúa con el contexto
+/// (negación, hipótesis, diálogo) igual que en la auditoría de coherencia.
+pub fn check_world_law_breaches(soul: &LivingSoul, world: &WorldSeed, content: &str, lang: Language) -> Vec<CoherenceViolation> {
+    let archetype = AnalyzedText::new(&soul.archetype);
+    let text = AnalyzedText::new(content);
+    let mut violations = Vec::new();
+
+    for law in world.world_laws.iter().filter(|law| law_applies(law, &archetype)) {
+        let LawEffect::Restriction { action } = &law.effect else {
+            continue;
+        };
+        let Some((found, context)) = most_assertive(&text, action, &soul.name) else {
+            continue;
+        };
+        let args: [TemplateArg; 5] = [
+            ("name", &soul.name), ("action", action), ("law", &law.name), ("world", &world.name), ("archetype", &soul.archetype),
+        ];
+        let violation = CoherenceViolation {
+            severity: ViolationSeverity::Warning,
+            violation_type: ViolationType::WorldLawBreach,
+            description: tr(lang, "violation.world_law.description", &args),
+            conflicting_element: law.name.clone(),
+            source_reference: format!("world.{}.laws.{}", world.id, law.id),
+            suggestion: tr(lang, "violation.world_law.suggestion", &args),
+            position_hint: Some(found.char_start),
+            rationale: ViolationRationale::default(),
+        };
+        violations.push(violation.in_context(
+            &text,
+            &found,
+            context,
+            tr(lang, "violation.world_law.reason", &args),
+            lang,
+        ));
+    }
+
+    violations
+}
+
+fn scale_stat(attributes: &mut BTreeMap<String, f32>, stat: &str, factor: f32) -> (String, f32) {
+    let key = canonical_stat(stat).map(str::to_string).unwrap_or_else(|| stat.trim().to_string());
+    let value = attributes.entry(key.clone()).or_insert(BASE_STAT);
+    let before = *value;
+    *value *= factor;
+    (key, *value - before)
+}
+
+impl FactionStanding {
+    /// Afinidad de partida (la misma que calcula `integrate_soul_into_world`)
+    pub fn new(soul: &LivingSoul, world: &WorldSeed) -> Self {
+        let archetype = AnalyzedText::new(&soul.archetype);
+        Self {
+            soul_id: soul.name.clone(),
+            world_id: world.id.clone(),
+            standings: world.factions.iter()
+                .map(|f| (f.name.clone(), initial_standing(soul, f, &archetype).clamp(-MAX_STANDING, MAX_STANDING)))
+                .collect(),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn standing(&self, faction: &str) -> Option<i32> {
+        self.standings.get(faction).copied()
+    }
+
+    /// Aplica varias entradas de crónica en orden
+    pub fn apply_chronicle(&mut self, world: &WorldSeed, entries: &[ChronicleEntry]) {
+        for entry in entries {
+            self.apply_entry(world, entry);
+        }
+    }
+
+    /// Lee una entrada de crónica y mueve la afinidad. Los sucesos negados o
+    /// hipotéticos ("no traicionó", "si traicionara") no cuentan.
+    pub fn apply_entry(&mut self, world: &WorldSeed, entry: &ChronicleEntry) -> Vec<StandingChange> {
+        let mut parts = vec![entry.event_summary.clone()];
+        parts.extend(entry.consequences.iter().cloned());
+        let text = AnalyzedText::new(&parts.join(".\n"));
+
+        let named: Vec<&Faction> = world.factions.iter()
+            .filter(|f| text.contains(&f.name) || entry.souls_involved.contains(&f.name))
+            .collect();
+
+        let mut changes = Vec::new();
+        for event in StandingEvent::ALL {
+            let Some((found, context)) = most_assertive_of(&text, event.terms(), &self.soul_id) else {
+                continue;
+            };
+            if !context.is_assertive() {
+                continue;
+            }
+            let matched_text = text.slice(found.char_start, found.char_end);
+
+            let mut targets: Vec<(String, StandingReach, i32)> = Vec::new();
+            if named.is_empty() {
+                for faction in &world.factions {
+                    targets.push((faction.name.clone(), StandingReach::Rumor, event.delta() / 2));
+                }
+            } else {
+                for faction in &named {
+                    targets.push((faction.name.clone(), StandingReach::Direct, event.delta()));
+                    for rival in rivals_of(world, &faction.name) {
+                        if !named.iter().any(|f| f.name == rival) {
+                            targets.push((rival, StandingReach::Rival, -event.delta() / 2));
+                        }
+                    }
+                }
+            }
+
+            for (faction, reach, delta) in targets {
+                let Some(current) = self.standings.get_mut(&faction) else {
+                    continue;
+                };
+                *current = (*current + delta).clamp(-MAX_STANDING, MAX_STANDING);
+                let change = StandingChange {
+                    chapter: entry.chapter,
+                    faction,
+                    event,
+                    reach,
+                    delta,
+                    standing_after: *current,
+                    matched_text: matched_text.clone(),
+                };
+                self.history.push(change.clone());
+                changes.push(change);
+            }
+        }
+
+        changes
+    }
+}
+
+/// Facciones en el bando contrario de algún conflicto activo
+fn rivals_of(world: &WorldSeed, faction: &str) -> Vec<String> {
+    let mut rivals: Vec<String> = Vec::new();
+    for conflict in &world.active_conflicts {
+        if conflict.sides.iter().any(|side| side == faction) {
+            for side in conflict.sides.iter().filter(|side| *side != faction) {
+                if !rivals.contains(side) {
+                    rivals.push(side.clone());
+                }
+            }
+        }
+    }
+    rivals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world_authoring::world_seed_from_json;
+
+    fn world() -> WorldSeed {
+        world_seed_from_json(r#"{
+            "id": "world_ceniza", "name": "Reino de Ceniza",
+            "world_laws": [
+                { "id": "sangre", "name": "Sangre Antigua", "description": "", "affects_archetypes": ["Guerrero"],
+                  "effect": { "Buff": { "stat": "strength", "modifier": 0.2 } } },
+                { "id": "ceniza", "name": "Aire de Ceniza", "description": "", "affects_archetypes": ["*"],
+                  "effect": { "Debuff": { "stat": "percepción", "modifier": 0.5 } } },
+                { "id": "veto", "name": "Veto Arcano", "description": "", "affects_archetypes": ["Mago"],
+                  "effect": { "Restriction": { "action": "conjurar" } } },
+                { "id": "fama", "name": "Fama", "description": "", "affects_archetypes": ["*"],
+                  "effect": { "SocialModifier": { "reputation_change": -5 } } }
+            ],
+            "factions": [
+                { "name": "Orden del Alba", "alignment": "luz", "accepts_archetypes": ["Guerrero"], "rejects_archetypes": [] },
+                { "name": "Culto Ceniciento", "alignment": "oscuro", "accepts_archetypes": [], "rejects_archetypes": [] }
+            ],
+            "active_conflicts": [
+                { "name": "Guerra del Alba", "sides": ["Orden del Alba", "Culto Ceniciento"], "stakes": "", "how_souls_are_affected": "" }
+            ],
+            "environmental_rules": { "magic_level": "Low", "technology_level": "Medieval", "danger_level": 0.7 },
+            "narrative_tone": { "darkness": 0.6, "hope": 0.4, "realism": 0.5, "pace": "medium" }
+        }"#).unwrap()
+    }
+
+    fn soul() -> LivingSoul {
+        let mut soul = parse_living_sheet(r#"<h1 class="name">Aria Vance</h1>"#);
+        soul.archetype = "Guerrero".to_string();
+        soul
+    }
+
+    fn entry(chapter: u32, summary: &str) -> ChronicleEntry {
+        ChronicleEntry {
+            chapter,
+            event_summary: summary.to_string(),
+            souls_involved: vec![],
+            consequences: vec![],
+            tension_delta: 0.0,
+        }
+    }
+
+    #[test]
+    fn laws_resolve_to_numbers() {
+        let attributes = resolve_law_effects(&soul(), &world(), &base_attributes());
+
+        assert!((attributes.attributes["fuerza"] - 12.0).abs() < 1e-4);
+        assert!((attributes.attributes["percepcion"] - 5.0).abs() < 1e-4);
+        assert!((attributes.attributes["carisma"] - BASE_STAT).abs() < 1e-4);
+        assert_eq!(attributes.reputation, -5);
+        // El veto arcano no alcanza a un guerrero
+        assert!(attributes.restricted_actions.is_empty());
+        assert_eq!(attributes.applied_laws.len(), 3);
+    }
+
+    #[test]
+    fn chronicle_moves_faction_standing() {
+        let world = world();
+        let mut standing = FactionStanding::new(&soul(), &world);
+        assert_eq!(standing.standing("Orden del Alba"), Some(20));
+
+        standing.apply_entry(&world, &entry(3, "Aria sella una alianza con la Orden del Alba"));
+        assert_eq!(standing.standing("Orden del Alba"), Some(45));
+        assert_eq!(standing.standing("Culto Ceniciento"), Some(-12));
+
+        // Negado: no cuenta
+        let none = standing.apply_entry(&world, &entry(4, "Aria no traicionó a nadie"));
+        assert!(none.is_empty());
+
+        // Sin facción nombrada: rumor a mitad de fuerza para todas
+        standing.apply_entry(&world, &entry(5, "La deshonra de Aria corre por los caminos"));
+        assert_eq!(standing.standing("Orden del Alba"), Some(38));
+        assert_eq!(standing.history.last().map(|c| c.reach), Some(StandingReach::Rumor));
+    }
+}
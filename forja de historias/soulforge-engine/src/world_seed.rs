@@ -1,14 +1,18 @@
 use serde::{Deserialize, Serialize};
+use crate::i18n::{tr, Language};
 use crate::soul_parser::*;
 use std::collections::HashMap;
+use crate::text_analysis::AnalyzedText;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorldSeed {
     pub id: String,
     pub name: String,
+    #[serde(default)]
     pub creator: String,
     pub world_laws: Vec<WorldLaw>,
     pub factions: Vec<Faction>,
+    #[serde(default)]
     pub active_conflicts: Vec<GlobalConflict>,
     pub environmental_rules: EnvironmentalRules,
     pub narrative_tone: NarrativeTone,
@@ -38,7 +42,9 @@ pub struct Faction {
     pub alignment: String,
     pub accepts_archetypes: Vec<String>,
     pub rejects_archetypes: Vec<String>,
+    #[serde(default)]
     pub benefits: Vec<String>,
+    #[serde(default)]
     pub obligations: Vec<String>,
 }
 
@@ -55,6 +61,7 @@ pub struct EnvironmentalRules {
     pub magic_level: MagicLevel,
     pub technology_level: TechLevel,
     pub danger_level: f32,
+    #[serde(default)]
     pub special_conditions: Vec<String>,
 }
 
@@ -93,40 +100,39 @@ pub struct ActiveWorldEffect {
     pub mechanical_impact: String,
 }
 
-/// Integra un Alma en un Mundo Semilla
-pub fn integrate_soul_into_world(soul: &LivingSoul, world: &WorldSeed) -> WorldSoulInteraction {
+/// Integra un Alma en un Mundo Semilla; efectos y ganchos se redactan en `lang`
+pub fn integrate_soul_into_world(soul: &LivingSoul, world: &WorldSeed, lang: Language) -> WorldSoulInteraction {
     let mut effects: Vec<ActiveWorldEffect> = Vec::new();
     let mut faction_standing: HashMap<String, i32> = HashMap::new();
     let mut world_traits: Vec<String> = Vec::new();
     let mut hooks: Vec<String> = Vec::new();
+    let archetype = AnalyzedText::new(&soul.archetype);
     
     // === APLICAR LEYES DEL MUNDO ===
     for law in &world.world_laws {
-        if law.affects_archetypes.iter()
-            .any(|a| soul.archetype.to_lowercase().contains(&a.to_lowercase()))
-            || law.affects_archetypes.contains(&"*".to_string()) {
+        if law_applies(law, &archetype) {
             
             let effect_desc = match &law.effect {
                 LawEffect::Buff { stat, modifier } => {
-                    format!("✨ Bonificación: {} +{:.0}%", stat, modifier * 100.0)
+                    tr(lang, "world.effect.buff", &[("stat", stat), ("percent", &format!("{:.0}", modifier * 100.0))])
                 },
                 LawEffect::Debuff { stat, modifier } => {
-                    format!("⚠️ Penalización: {} -{:.0}%", stat, modifier * 100.0)
+                    tr(lang, "world.effect.debuff", &[("stat", stat), ("percent", &format!("{:.0}", modifier * 100.0))])
                 },
                 LawEffect::Restriction { action } => {
-                    format!("🚫 Restricción: {} está prohibido/limitado", action)
+                    tr(lang, "world.effect.restriction", &[("action", action)])
                 },
                 LawEffect::Enhancement { ability } => {
-                    format!("⚡ Mejora: {} potenciado", ability)
+                    tr(lang, "world.effect.enhancement", &[("ability", ability)])
                 },
                 LawEffect::SocialModifier { reputation_change } => {
-                    let direction = if *reputation_change > 0 { "mejorada" } else { "dañada" };
-                    format!("👥 Reputación {} por ley del mundo", direction)
+                    let key = if *reputation_change > 0 { "world.effect.reputation_up" } else { "world.effect.reputation_down" };
+                    tr(lang, key, &[])
                 },
             };
             
             effects.push(ActiveWorldEffect {
-                source: format!("Ley: {}", law.name),
+                source: tr(lang, "world.law_source", &[("law", &law.name)]),
                 effect_description: law.description.clone(),
                 mechanical_impact: effect_desc,
             });
@@ -135,94 +141,55 @@ pub fn integrate_soul_into_world(soul: &LivingSoul, world: &WorldSeed) -> WorldS
     
     // === CALCULAR AFINIDAD CON FACCIONES ===
     for faction in &world.factions {
-        let mut standing = 0i32;
-        
-        // Check if archetype is accepted
-        if faction.accepts_archetypes.iter()
-            .any(|a| soul.archetype.to_lowercase().contains(&a.to_lowercase())) {
-            standing += 20;
-        }
-        
-        // Check if archetype is rejected
-        if faction.rejects_archetypes.iter()
-            .any(|a| soul.archetype.to_lowercase().contains(&a.to_lowercase())) {
-            standing -= 30;
-        }
-        
-        // Check moral alignment
-        for code in &soul.psyche.moral_code {
-            if faction.alignment.to_lowercase().contains("luz") 
-                && code.to_lowercase().contains("proteger") {
-                standing += 10;
-            }
-            if faction.alignment.to_lowercase().contains("oscuro") 
-                && code.to_lowercase().contains("proteger") {
-                standing -= 10;
-            }
-        }
-        
+        let standing = initial_standing(soul, faction, &archetype);
         faction_standing.insert(faction.name.clone(), standing);
         
         // Generar hook si hay tensión
         if standing < -10 {
-            hooks.push(format!(
-                "La facción '{}' ve a {} con hostilidad. \
-                Su mera presencia genera conflicto.",
-                faction.name, soul.name
-            ));
+            hooks.push(tr(lang, "world.hook.hostile_faction", &[("faction", &faction.name), ("name", &soul.name)]));
         } else if standing > 20 {
-            hooks.push(format!(
-                "La facción '{}' podría ofrecer refugio o alianza a {}.",
-                faction.name, soul.name
-            ));
+            hooks.push(tr(lang, "world.hook.friendly_faction", &[("faction", &faction.name), ("name", &soul.name)]));
         }
     }
     
     // === APLICAR CONFLICTOS GLOBALES ===
     for conflict in &world.active_conflicts {
-        hooks.push(format!(
-            "El conflicto '{}' afecta a {}: {}",
-            conflict.name, soul.name, conflict.how_souls_are_affected
-        ));
+        hooks.push(tr(lang, "world.hook.conflict", &[
+            ("conflict", &conflict.name), ("name", &soul.name), ("effect", &conflict.how_souls_are_affected),
+        ]));
     }
     
     // === ADAPTAR AL TONO NARRATIVO ===
     if world.narrative_tone.darkness > 0.7 {
-        world_traits.push("Las cicatrices pesan más aquí".to_string());
+        world_traits.push(tr(lang, "world.dark.trait", &[]));
         if !soul.scars.is_empty() {
             effects.push(ActiveWorldEffect {
-                source: "Tono Oscuro del Mundo".to_string(),
-                effect_description: "En este mundo, los traumas resuenan con más fuerza".to_string(),
-                mechanical_impact: "Cicatrices tienen +20% peso narrativo".to_string(),
+                source: tr(lang, "world.dark.source", &[]),
+                effect_description: tr(lang, "world.dark.effect", &[]),
+                mechanical_impact: tr(lang, "world.dark.impact", &[]),
             });
         }
     }
     
     if world.narrative_tone.hope > 0.7 {
-        world_traits.push("La redención es posible aquí".to_string());
+        world_traits.push(tr(lang, "world.hope.trait", &[]));
         effects.push(ActiveWorldEffect {
-            source: "Tono Esperanzador del Mundo".to_string(),
-            effect_description: "Hay luz incluso en la oscuridad".to_string(),
-            mechanical_impact: "Cicatrices pueden sanarse con más facilidad".to_string(),
+            source: tr(lang, "world.hope.source", &[]),
+            effect_description: tr(lang, "world.hope.effect", &[]),
+            mechanical_impact: tr(lang, "world.hope.impact", &[]),
         });
     }
     
     // === CRUZAR PROFECÍAS CON MUNDO ===
     for prophecy in &soul.prophecies {
         if !prophecy.fulfilled {
-            hooks.push(format!(
-                "La profecía '{}' podría manifestarse de forma única en {}.",
-                prophecy.text, world.name
-            ));
+            hooks.push(tr(lang, "world.hook.prophecy", &[("prophecy", &prophecy.text), ("world", &world.name)]));
         }
     }
     
     // === NÉMESIS EN NUEVO MUNDO ===
     if let Some(ref nemesis) = soul.nemesis {
-        hooks.push(format!(
-            "¿Existe {} en {}? ¿O encontrará un nuevo enemigo que encarne el mismo conflicto?",
-            nemesis.name, world.name
-        ));
+        hooks.push(tr(lang, "world.hook.nemesis", &[("nemesis", &nemesis.name), ("world", &world.name)]));
     }
     
     WorldSoulInteraction {
@@ -235,6 +202,42 @@ pub fn integrate_soul_into_world(soul: &LivingSoul, world: &WorldSeed) -> WorldS
     }
 }
 
+/// ¿La ley alcanza a este arquetipo? ("*" alcanza a todos)
+pub(crate) fn law_applies(law: &WorldLaw, archetype: &AnalyzedText) -> bool {
+    law.affects_archetypes.iter().any(|a| a == "*" || archetype.contains(a))
+}
+
+/// Afinidad de partida con una facción: arquetipo aceptado/rechazado y código moral
+pub(crate) fn initial_standing(soul: &LivingSoul, faction: &Faction, archetype: &AnalyzedText) -> i32 {
+    let mut standing = 0i32;
+    
+    // Check if archetype is accepted
+    if faction.accepts_archetypes.iter()
+        .any(|a| archetype.contains(a)) {
+        standing += 20;
+    }
+    
+    // Check if archetype is rejected
+    if faction.rejects_archetypes.iter()
+        .any(|a| archetype.contains(a)) {
+        standing -= 30;
+    }
+    
+    // Check moral alignment
+    let alignment = AnalyzedText::new(&faction.alignment);
+    for code in &soul.psyche.moral_code {
+        let code = AnalyzedText::new(code);
+        if alignment.contains("luz") && code.contains("proteger") {
+            standing += 10;
+        }
+        if alignment.contains("oscuro") && code.contains("proteger") {
+            standing -= 10;
+        }
+    }
+    
+    standing
+}
+
 /// Parser de Mundo desde HTML
 pub fn parse_world_seed(html: &str) -> WorldSeed {
     use scraper::{Html, Selector};
@@ -313,14 +316,14 @@ fn parse_law_effect(effect_str: Option<&str>) -> LawEffect {
         Some(s) if s.starts_with("buff:") => {
             let parts: Vec<&str> = s[5..].split(':').collect();
             LawEffect::Buff {
-                stat: parts.get(0).unwrap_or(&"unknown").to_string(),
+                stat: parts.first().unwrap_or(&"unknown").to_string(),
                 modifier: parts.get(1).and_then(|x| x.parse().ok()).unwrap_or(0.1),
             }
         },
         Some(s) if s.starts_with("debuff:") => {
             let parts: Vec<&str> = s[7..].split(':').collect();
             LawEffect::Debuff {
-                stat: parts.get(0).unwrap_or(&"unknown").to_string(),
+                stat: parts.first().unwrap_or(&"unknown").to_string(),
                 modifier: parts.get(1).and_then(|x| x.parse().ok()).unwrap_or(0.1),
             }
         },